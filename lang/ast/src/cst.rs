@@ -0,0 +1,126 @@
+use super::*;
+
+/// A non-trivia token with the trivia tokens around it.
+#[derive(Debug, Clone, PartialEq, CtorCall, FieldCall)]
+pub struct TokenReference {
+    /// Trivia tokens from the previous token's trailing trivia
+    /// up to this token.
+    leading_trivia: Vec<Token>,
+    token: Token,
+    /// Trivia tokens after this token in the same line
+    /// (including the line break).
+    trailing_trivia: Vec<Token>,
+}
+
+impl SpannedNode for TokenReference {
+    fn span(&self) -> Span {
+        self.token.span()
+    }
+}
+
+impl TokenReference {
+    /// Gets every token in this token reference in the same order
+    /// as they are in the source code.
+    pub fn tokens(&self) -> impl Iterator<Item = &Token> {
+        self.leading_trivia
+            .iter()
+            .chain(std::iter::once(&self.token))
+            .chain(self.trailing_trivia.iter())
+    }
+}
+
+/// Lossless concrete syntax tree of a source file. Every token has
+/// its trivia attached, so the source code can be printed back
+/// exactly as it is.
+///
+/// It is a layer next to the AST, tokens of the AST can be looked up
+/// with [`Cst::token_at`] to get their trivia.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cst {
+    source: String,
+    tokens: Vec<TokenReference>,
+}
+
+impl Cst {
+    /// Creates a concrete syntax tree from the tokenized source code
+    /// (including trivia tokens). The last token must be the `Eof`
+    /// token, it holds the trivia at the end of the file.
+    pub fn new(source: &str, tokens: Vec<Token>) -> Cst {
+        let mut references = Vec::new();
+        let mut leading_trivia = Vec::new();
+        let mut tokens = tokens.into_iter().peekable();
+
+        while let Some(token) = tokens.next() {
+            if token.ty().is_trivia() {
+                leading_trivia.push(token);
+                continue;
+            }
+
+            // trailing trivia stops at the end of the line
+            let mut trailing_trivia = Vec::new();
+            if !matches!(token.ty(), TokenType::Eof) {
+                while let Some(trivia) = tokens.next_if(|v| v.ty().is_trivia()) {
+                    let span = trivia.span();
+                    let is_line_end = source[span.start..span.end].ends_with('\n');
+                    trailing_trivia.push(trivia);
+                    if is_line_end {
+                        break;
+                    }
+                }
+            }
+
+            references.push(TokenReference::new(
+                std::mem::take(&mut leading_trivia),
+                token,
+                trailing_trivia,
+            ));
+        }
+
+        Cst {
+            source: source.to_string(),
+            tokens: references,
+        }
+    }
+
+    /// Gets the source code where the tree is made from.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Gets every non-trivia token with their trivia.
+    pub fn tokens(&self) -> &[TokenReference] {
+        &self.tokens
+    }
+
+    /// Gets every non-trivia token, it is used to parse the AST.
+    pub fn non_trivia_tokens(&self) -> Vec<Token> {
+        self.tokens.iter().map(|v| v.token().clone()).collect()
+    }
+
+    /// Gets the text of any token from the tree as it is written in
+    /// the source code (including quotes of strings and dashes of comments).
+    pub fn text(&self, token: &Token) -> &str {
+        let span = token.span();
+        &self.source[span.start..span.end]
+    }
+
+    /// Looks for the token reference which the token starts at the
+    /// same position as the span.
+    pub fn token_at(&self, span: Span) -> Option<&TokenReference> {
+        self.tokens
+            .binary_search_by(|v| v.token().span().start.cmp(&span.start))
+            .ok()
+            .map(|idx| &self.tokens[idx])
+    }
+
+    /// Prints the tree back into source code.
+    pub fn print(&self) -> String {
+        let mut output = String::with_capacity(self.source.len());
+        for reference in self.tokens.iter() {
+            for token in reference.tokens() {
+                output.push_str(self.text(token));
+            }
+        }
+        output
+    }
+}
@@ -15,6 +15,7 @@ pub use op::*;
 #[derive(Debug, Clone, PartialEq)]
 pub enum Expr {
     Binary(Binary),
+    IfElse(IfElseExpr),
     Literal(Literal),
     Paren(Box<Expr>),
     Suffixed(Suffixed),
@@ -26,6 +27,7 @@ impl Node for Expr {
     fn as_expr(&self) -> Option<Expr> {
         match self {
             Expr::Binary(node) => node.as_expr(),
+            Expr::IfElse(node) => node.as_expr(),
             Expr::Literal(node) => node.as_expr(),
             Expr::Paren(node) => node.as_expr(),
             Expr::Suffixed(node) => node.as_expr(),
@@ -43,6 +45,7 @@ impl SpannedNode for Expr {
     fn span(&self) -> Span {
         match self {
             Expr::Binary(node) => node.span(),
+            Expr::IfElse(node) => node.span(),
             Expr::Literal(node) => node.span(),
             Expr::Paren(node) => node.span(),
             Expr::Suffixed(node) => node.span(),
@@ -111,6 +114,7 @@ impl SpannedNode for Args {
 pub enum Literal {
     Bool(Token),
     Function(FunctionExpr),
+    InterpolatedStr(InterpolatedStr),
     Name(Token),
     Number(Token),
     Nil(Token),
@@ -134,6 +138,7 @@ impl SpannedNode for Literal {
         match self {
             Literal::Bool(node) => node.span(),
             Literal::Function(node) => node.span(),
+            Literal::InterpolatedStr(node) => node.span(),
             Literal::Name(node) => node.span(),
             Literal::Number(node) => node.span(),
             Literal::Nil(node) => node.span(),
@@ -144,6 +149,34 @@ impl SpannedNode for Literal {
     }
 }
 
+/// String with expressions inside, `` `Hello {name}!` ``.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, FieldCall, CtorCall)]
+pub struct InterpolatedStr {
+    #[exclude]
+    span: Span,
+    /// Text tokens of the string, there is one more
+    /// segment than the expressions between them.
+    segments: Vec<Token>,
+    exprs: Vec<Expr>,
+}
+
+impl Node for InterpolatedStr {
+    fn as_expr(&self) -> Option<Expr> {
+        Some(Expr::Literal(Literal::InterpolatedStr(self.clone())))
+    }
+
+    fn as_stmt(&self) -> Option<Stmt> {
+        None
+    }
+}
+
+impl SpannedNode for InterpolatedStr {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub enum SuffixKind {
@@ -262,6 +295,49 @@ impl SpannedNode for Binary {
     }
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, FieldCall, CtorCall)]
+pub struct ElseIfExprClause {
+    #[exclude]
+    span: Span,
+    condition: Expr,
+    value: Expr,
+}
+
+impl SpannedNode for ElseIfExprClause {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+/// Conditional expression, `if a then b elseif c then d else e`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, FieldCall, CtorCall)]
+pub struct IfElseExpr {
+    #[exclude]
+    span: Span,
+    condition: Box<Expr>,
+    value: Box<Expr>,
+    elseifs: Vec<ElseIfExprClause>,
+    else_value: Box<Expr>,
+}
+
+impl Node for IfElseExpr {
+    fn as_expr(&self) -> Option<Expr> {
+        Some(Expr::IfElse(self.clone()))
+    }
+
+    fn as_stmt(&self) -> Option<Stmt> {
+        None
+    }
+}
+
+impl SpannedNode for IfElseExpr {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq, FieldCall, CtorCall)]
 pub struct Unary {
@@ -315,6 +391,7 @@ pub struct VaridiacParam {
 pub struct FunctionBody {
     #[exclude]
     span: Span,
+    type_params: Option<Vec<TypeParameter>>,
     params: ParamList,
     varidiac: Option<VaridiacParam>,
     block: Block,
@@ -1,5 +1,6 @@
 extern crate smol_str;
 
+pub mod cst;
 mod exprs;
 mod stmts;
 mod types;
@@ -34,6 +34,11 @@ impl SpannedNode for ReturnStmt {
 pub enum Stmt {
     Break(Token),
     Call(Expr),
+    CompoundAssign(CompoundAssign),
+    Continue(Token),
+    DeclareFunction(DeclareFunction),
+    DeclareLocal(DeclareLocal),
+    DeclareModule(DeclareModule),
     Do(DoStmt),
     FunctionAssign(FunctionAssign),
     GenericFor(GenericFor),
@@ -46,6 +51,9 @@ pub enum Stmt {
     While(WhileStmt),
     TypeDeclaration(TypeDeclaration),
     VarAssign(VarAssign),
+    /// Statement failed to parse, it only exists if the
+    /// parser recovers from errors.
+    Error(Span),
 }
 
 impl Node for Stmt {
@@ -57,6 +65,11 @@ impl Node for Stmt {
         match self {
             Stmt::Break(node) => Some(Stmt::Break(node.clone())),
             Stmt::Call(node) => node.as_stmt(),
+            Stmt::CompoundAssign(node) => node.as_stmt(),
+            Stmt::Continue(node) => Some(Stmt::Continue(node.clone())),
+            Stmt::DeclareFunction(node) => node.as_stmt(),
+            Stmt::DeclareLocal(node) => node.as_stmt(),
+            Stmt::DeclareModule(node) => node.as_stmt(),
             Stmt::Do(node) => node.as_stmt(),
             Stmt::FunctionAssign(node) => node.as_stmt(),
             Stmt::GenericFor(node) => node.as_stmt(),
@@ -69,6 +82,7 @@ impl Node for Stmt {
             Stmt::While(node) => node.as_stmt(),
             Stmt::TypeDeclaration(node) => node.as_stmt(),
             Stmt::VarAssign(node) => node.as_stmt(),
+            Stmt::Error(span) => Some(Stmt::Error(*span)),
         }
     }
 }
@@ -78,6 +92,11 @@ impl SpannedNode for Stmt {
         match self {
             Stmt::Break(node) => node.span(),
             Stmt::Call(node) => node.span(),
+            Stmt::CompoundAssign(node) => node.span(),
+            Stmt::Continue(node) => node.span(),
+            Stmt::DeclareFunction(node) => node.span(),
+            Stmt::DeclareLocal(node) => node.span(),
+            Stmt::DeclareModule(node) => node.span(),
             Stmt::Do(node) => node.span(),
             Stmt::FunctionAssign(node) => node.span(),
             Stmt::GenericFor(node) => node.span(),
@@ -90,6 +109,7 @@ impl SpannedNode for Stmt {
             Stmt::While(node) => node.span(),
             Stmt::VarAssign(node) => node.span(),
             Stmt::TypeDeclaration(node) => node.span(),
+            Stmt::Error(span) => *span,
         }
     }
 }
@@ -262,6 +282,34 @@ impl SpannedNode for VarAssign {
     }
 }
 
+/// Assignment with an operator like `a += 1`, the operator
+/// is applied to the variable and the expression.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, FieldCall, CtorCall)]
+pub struct CompoundAssign {
+    #[exclude]
+    span: Span,
+    name: VarAssignName,
+    op: Binop,
+    expr: Expr,
+}
+
+impl Node for CompoundAssign {
+    fn as_expr(&self) -> Option<Expr> {
+        None
+    }
+
+    fn as_stmt(&self) -> Option<Stmt> {
+        Some(Stmt::CompoundAssign(self.clone()))
+    }
+}
+
+impl SpannedNode for CompoundAssign {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq, FieldCall, CtorCall)]
 pub struct LocalAssignName {
@@ -446,6 +494,9 @@ impl SpannedNode for GenericFor {
 pub struct TypeDeclaration {
     #[exclude]
     span: Span,
+    /// Declared with `export type`, other modules can refer to it
+    /// through the value of `require`.
+    exported: bool,
     name: Token,
     params: Option<Vec<TypeParameter>>,
     typ: TypeInfo,
@@ -466,3 +517,84 @@ impl SpannedNode for TypeDeclaration {
         self.span
     }
 }
+
+/// `declare function name(params) -> T` in declaration files,
+/// the function is declared without its body.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, FieldCall, CtorCall)]
+pub struct DeclareFunction {
+    #[exclude]
+    span: Span,
+    name: Token,
+    typ: TypeCallback,
+}
+
+impl Node for DeclareFunction {
+    fn as_expr(&self) -> Option<Expr> {
+        None
+    }
+
+    fn as_stmt(&self) -> Option<Stmt> {
+        Some(Stmt::DeclareFunction(self.clone()))
+    }
+}
+
+impl SpannedNode for DeclareFunction {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+/// `declare local name: T` in declaration files,
+/// the variable is declared without its value.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, FieldCall, CtorCall)]
+pub struct DeclareLocal {
+    #[exclude]
+    span: Span,
+    name: Token,
+    typ: TypeInfo,
+}
+
+impl Node for DeclareLocal {
+    fn as_expr(&self) -> Option<Expr> {
+        None
+    }
+
+    fn as_stmt(&self) -> Option<Stmt> {
+        Some(Stmt::DeclareLocal(self.clone()))
+    }
+}
+
+impl SpannedNode for DeclareLocal {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+/// `declare module "name": T` in declaration files, `T` is
+/// the type of `require("name")`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, FieldCall, CtorCall)]
+pub struct DeclareModule {
+    #[exclude]
+    span: Span,
+    name: Token,
+    typ: TypeInfo,
+}
+
+impl Node for DeclareModule {
+    fn as_expr(&self) -> Option<Expr> {
+        None
+    }
+
+    fn as_stmt(&self) -> Option<Stmt> {
+        Some(Stmt::DeclareModule(self.clone()))
+    }
+}
+
+impl SpannedNode for DeclareModule {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
@@ -109,13 +109,15 @@ impl SpannedNode for TypeCallbackParameter {
     }
 }
 
-// ( [ [<Name> `:`] <typeinfo> ( [<Name> `:`] <typeinfo> )* ] ) -> <typeinfo>
+// ( [ [<Name> `:`] <typeinfo> ( [<Name> `:`] <typeinfo> )* ] [ `...` `:` <typeinfo> ] ) -> <typeinfo>
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq, FieldCall, CtorCall)]
 pub struct TypeCallback {
     #[exclude]
     span: Span,
+    type_params: Option<Vec<TypeParameter>>,
     parameters: Vec<TypeCallbackParameter>,
+    varidiac: Option<Box<VaridiacParam>>,
     return_type: Box<TypeInfo>,
 }
 
@@ -131,6 +133,9 @@ pub struct TypeReference {
     #[exclude]
     span: Span,
     arguments: Option<Vec<TypeInfo>>,
+    /// Variable that holds a required module like `M` in `M.Config`,
+    /// the type is exported from that module.
+    module: Option<Token>,
     name: Token,
 }
 
@@ -154,15 +159,34 @@ impl SpannedNode for TypeMetatable {
     }
 }
 
+// `typeof` `(` <expr> `)`
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, FieldCall, CtorCall)]
+pub struct TypeOf {
+    #[exclude]
+    span: Span,
+    expr: Box<Expr>,
+}
+
+impl SpannedNode for TypeOf {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub enum TypeInfo {
     Callback(TypeCallback),
     Intersection(TypeIntersection),
+    /// Singleton type of a string, number or boolean literal
+    Literal(Token),
     Reference(TypeReference),
     Metatable(TypeMetatable),
     Table(TypeTable),
     Tuple(TypeTuple),
+    /// Type of the expression, it is not evaluated at runtime
+    TypeOf(TypeOf),
     Union(TypeUnion),
 }
 
@@ -170,10 +194,12 @@ impl SpannedNode for TypeInfo {
     fn span(&self) -> Span {
         match self {
             TypeInfo::Callback(node) => node.span(),
+            TypeInfo::Literal(node) => node.span(),
             TypeInfo::Reference(node) => node.span(),
             TypeInfo::Table(node) => node.span(),
             TypeInfo::Metatable(node) => node.span(),
             TypeInfo::Tuple(node) => node.span(),
+            TypeInfo::TypeOf(node) => node.span(),
             TypeInfo::Intersection(node) => node.span(),
             TypeInfo::Union(node) => node.span(),
         }
@@ -5,6 +5,7 @@ pub trait ExprVisitor<'a> {
 
     fn visit_bool_expr(&mut self, node: &'a Token) -> Self::Output;
     fn visit_function_expr(&mut self, node: &'a FunctionExpr) -> Self::Output;
+    fn visit_interpolated_str_expr(&mut self, node: &'a InterpolatedStr) -> Self::Output;
     fn visit_name_expr(&mut self, node: &'a Token) -> Self::Output;
     fn visit_number_expr(&mut self, node: &'a Token) -> Self::Output;
     fn visit_nil_expr(&mut self, node: &'a Token) -> Self::Output;
@@ -13,6 +14,7 @@ pub trait ExprVisitor<'a> {
     fn visit_varargs_expr(&mut self, node: &'a Token) -> Self::Output;
 
     fn visit_binary_expr(&mut self, node: &'a Binary) -> Self::Output;
+    fn visit_if_else_expr(&mut self, node: &'a IfElseExpr) -> Self::Output;
     fn visit_paren_expr(&mut self, node: &'a Expr) -> Self::Output;
     fn visit_suffixed_expr(&mut self, node: &'a Suffixed) -> Self::Output;
     fn visit_type_assertion_expr(&mut self, node: &'a TypeAssertion) -> Self::Output;
@@ -24,6 +26,7 @@ pub trait ExprVisitor<'a> {
         match node {
             Literal::Bool(node) => self.visit_bool_expr(node),
             Literal::Function(node) => self.visit_function_expr(node),
+            Literal::InterpolatedStr(node) => self.visit_interpolated_str_expr(node),
             Literal::Name(node) => self.visit_name_expr(node),
             Literal::Number(node) => self.visit_number_expr(node),
             Literal::Nil(node) => self.visit_nil_expr(node),
@@ -36,6 +39,7 @@ pub trait ExprVisitor<'a> {
     fn visit_expr(&mut self, node: &'a Expr) -> Self::Output {
         match node {
             Expr::Binary(node) => self.visit_binary_expr(node),
+            Expr::IfElse(node) => self.visit_if_else_expr(node),
             Expr::Literal(node) => self.visit_literal_expr(node),
             Expr::Paren(node) => self.visit_paren_expr(node),
             Expr::Suffixed(node) => self.visit_suffixed_expr(node),
@@ -49,20 +53,24 @@ pub trait TypeVisitor<'a> {
     type Output: 'a;
 
     fn visit_type_callback(&mut self, node: &'a TypeCallback) -> Self::Output;
+    fn visit_type_literal(&mut self, node: &'a Token) -> Self::Output;
     fn visit_type_reference(&mut self, node: &'a TypeReference) -> Self::Output;
     fn visit_type_table(&mut self, node: &'a TypeTable) -> Self::Output;
     fn visit_type_metatable(&mut self, node: &'a TypeMetatable) -> Self::Output;
     fn visit_type_tuple(&mut self, node: &'a TypeTuple) -> Self::Output;
+    fn visit_type_typeof(&mut self, node: &'a TypeOf) -> Self::Output;
     fn visit_type_intersection(&mut self, node: &'a TypeIntersection) -> Self::Output;
     fn visit_type_union(&mut self, node: &'a TypeUnion) -> Self::Output;
 
     fn visit_type_info(&mut self, node: &'a TypeInfo) -> Self::Output {
         match node {
             TypeInfo::Callback(node) => self.visit_type_callback(node),
+            TypeInfo::Literal(node) => self.visit_type_literal(node),
             TypeInfo::Reference(node) => self.visit_type_reference(node),
             TypeInfo::Table(node) => self.visit_type_table(node),
             TypeInfo::Metatable(node) => self.visit_type_metatable(node),
             TypeInfo::Tuple(node) => self.visit_type_tuple(node),
+            TypeInfo::TypeOf(node) => self.visit_type_typeof(node),
             TypeInfo::Intersection(node) => self.visit_type_intersection(node),
             TypeInfo::Union(node) => self.visit_type_union(node),
         }
@@ -73,6 +81,7 @@ pub trait StmtVisitor<'a> {
     type Output: 'a;
 
     fn visit_call_stmt(&mut self, node: &'a Expr) -> Self::Output;
+    fn visit_compound_assign_stmt(&mut self, node: &'a CompoundAssign) -> Self::Output;
     fn visit_do_stmt(&mut self, node: &'a DoStmt) -> Self::Output;
     fn visit_function_assign_stmt(&mut self, node: &'a FunctionAssign) -> Self::Output;
     fn visit_generic_for_stmt(&mut self, node: &'a GenericFor) -> Self::Output;
@@ -84,10 +93,14 @@ pub trait StmtVisitor<'a> {
     fn visit_while_stmt(&mut self, node: &'a WhileStmt) -> Self::Output;
     fn visit_var_assign_stmt(&mut self, node: &'a VarAssign) -> Self::Output;
     fn visit_type_declaration_stmt(&mut self, node: &'a TypeDeclaration) -> Self::Output;
+    fn visit_declare_function_stmt(&mut self, node: &'a DeclareFunction) -> Self::Output;
+    fn visit_declare_local_stmt(&mut self, node: &'a DeclareLocal) -> Self::Output;
+    fn visit_declare_module_stmt(&mut self, node: &'a DeclareModule) -> Self::Output;
 
     fn visit_stmt(&mut self, node: &'a Stmt) -> Self::Output {
         match node {
             Stmt::Call(node) => self.visit_call_stmt(node),
+            Stmt::CompoundAssign(node) => self.visit_compound_assign_stmt(node),
             Stmt::Do(node) => self.visit_do_stmt(node),
             Stmt::FunctionAssign(node) => self.visit_function_assign_stmt(node),
             Stmt::GenericFor(node) => self.visit_generic_for_stmt(node),
@@ -99,6 +112,9 @@ pub trait StmtVisitor<'a> {
             Stmt::While(node) => self.visit_while_stmt(node),
             Stmt::VarAssign(node) => self.visit_var_assign_stmt(node),
             Stmt::TypeDeclaration(node) => self.visit_type_declaration_stmt(node),
+            Stmt::DeclareFunction(node) => self.visit_declare_function_stmt(node),
+            Stmt::DeclareLocal(node) => self.visit_declare_local_stmt(node),
+            Stmt::DeclareModule(node) => self.visit_declare_module_stmt(node),
             _ => unreachable!(),
         }
     }
@@ -108,11 +124,13 @@ pub trait LastStmtVisitor<'a> {
     type Output: 'a;
 
     fn visit_break_stmt(&mut self, node: &'a Token) -> Self::Output;
+    fn visit_continue_stmt(&mut self, node: &'a Token) -> Self::Output;
     fn visit_return_stmt(&mut self, node: &'a ReturnStmt) -> Self::Output;
 
     fn visit_last_stmt(&mut self, node: &'a Stmt) -> Self::Output {
         match node {
             Stmt::Break(node) => self.visit_break_stmt(node),
+            Stmt::Continue(node) => self.visit_continue_stmt(node),
             Stmt::Return(node) => self.visit_return_stmt(node),
             _ => unreachable!(),
         }
@@ -15,6 +15,25 @@ impl<'a, 'b> Analyzer<'a, 'b> {
         right: &variants::Table,
         span: Span,
     ) -> AnalyzeResult {
+        // `{ T }` is a list, so every array member has to be `T`
+        if let Some(element) = list_element(right) {
+            for (key, value) in left.entries.iter() {
+                if !matches!(key, variants::TableFieldKey::None(..)) {
+                    return Err(AnalyzeError::ExcessiveField {
+                        span,
+                        key: utils::table_key_description(&self.ctx, key),
+                    });
+                }
+                self.compare_types_inner(value, element, span)
+                    .map_err(|err| AnalyzeError::InvalidField {
+                        span,
+                        key: utils::table_key_description(&self.ctx, key),
+                        reason: Box::new(err),
+                    })?;
+            }
+            return Ok(());
+        }
+
         let mut counted_indexes = Vec::new();
 
         // check for type similarites?
@@ -51,6 +70,7 @@ impl<'a, 'b> Analyzer<'a, 'b> {
                                 intrinsic_type,
                                 Type::Literal(variants::Literal {
                                     typ: variants::LiteralType::String,
+                                    value: None,
                                     ..
                                 })
                             ) {
@@ -207,11 +227,26 @@ impl<'a, 'b> Analyzer<'a, 'b> {
                 Ok(())
             }
 
+            (Type::Union(union), _) => {
+                for member in union.members.iter() {
+                    if self.compare_types_inner(member, right, span).is_err() {
+                        return Err(AnalyzeError::NotExtendable {
+                            value: leftd,
+                            assertion: rightd,
+                            span,
+                        });
+                    }
+                }
+                Ok(())
+            }
+
             (Type::Function(a), Type::Function(b)) => {
                 for (idx, param) in a.parameters.iter().enumerate() {
-                    let expected = match b.parameters.get(idx) {
-                        Some(ty) => &ty.typ,
-                        None => {
+                    let expected = match (b.parameters.get(idx), &b.varidiac_param) {
+                        (Some(ty), _) => &ty.typ,
+                        // the rest of the arguments are given from the varargs
+                        (None, Some(varidiac)) => &varidiac.typ,
+                        (None, None) => {
                             return Err(AnalyzeError::ExcessiveParameter {
                                 span: param.span,
                                 key: idx + 1,
@@ -245,6 +280,10 @@ impl<'a, 'b> Analyzer<'a, 'b> {
                 Ok(())
             }
 
+            // singleton types can be used as their base type but
+            // not the other way around
+            (Type::Literal(a), Type::Literal(b)) if b.value.is_none() && a.typ == b.typ => Ok(()),
+
             // (_, Type::Ref(_)) => {
             //     let real_type = self.solve_type_ref(&right)?;
             //     self.compare_types_inner(&left, &real_type, span)
@@ -289,3 +328,12 @@ impl<'a, 'b> Analyzer<'a, 'b> {
         }
     }
 }
+
+/// Gets the element type of a list type like `{ number }`.
+fn list_element(table: &variants::Table) -> Option<&Type> {
+    let mut entries = table.entries.iter();
+    match (entries.next(), entries.next()) {
+        (Some((variants::TableFieldKey::None(..), value)), None) => Some(value),
+        _ => None,
+    }
+}
@@ -72,8 +72,26 @@ pub enum AnalyzeError {
         expected_type: String,
     },
 
+    #[error("Cannot infer type parameter {name} from the arguments")]
+    UninferredTypeParameter { span: Span, name: String },
+
+    #[error("Type parameter {name} is inferred as both {first} and {second}")]
+    ConflictingTypeParameter {
+        span: Span,
+        name: String,
+        first: String,
+        second: String,
+    },
+
     #[error("{base} has no parameters")]
     TypeHasNoParameters { span: Span, base: String },
+
+    #[error("{name} does not exist on {target}")]
+    UnavailableOnTarget {
+        span: Span,
+        name: String,
+        target: String,
+    },
 }
 
 impl AnalyzeError {
@@ -94,7 +112,10 @@ impl AnalyzeError {
             AnalyzeError::NoArguments { span, .. } => *span,
             AnalyzeError::MissingTypeArgument { span, .. } => *span,
             AnalyzeError::MissingArgument { span, .. } => *span,
+            AnalyzeError::UninferredTypeParameter { span, .. } => *span,
+            AnalyzeError::ConflictingTypeParameter { span, .. } => *span,
             AnalyzeError::TypeHasNoParameters { span, .. } => *span,
+            AnalyzeError::UnavailableOnTarget { span, .. } => *span,
         }
     }
 }
@@ -0,0 +1,52 @@
+use super::*;
+use salite_ast::{BinopKind, UnopKind};
+
+/// Gets the type that both operands of arithmetic and concatenation
+/// operators must be. Other operators accept any operands.
+fn operand_type(kind: &BinopKind, span: Span) -> Option<Type> {
+    use crate::types::makers;
+    match kind {
+        BinopKind::Add
+        | BinopKind::Subtract
+        | BinopKind::Multiply
+        | BinopKind::Divide
+        | BinopKind::FloorDivision
+        | BinopKind::Modulo
+        | BinopKind::Exponent => Some(makers::number(span)),
+        BinopKind::Concat => Some(Type::Union(variants::Union {
+            span,
+            members: vec![makers::string(span), makers::number(span)],
+        })),
+        _ => None,
+    }
+}
+
+impl<'a, 'b> Validate<'a, 'b> for hir::Binary<'b> {
+    type Output = ();
+
+    fn validate(&self, analyzer: &mut Analyzer<'a, 'b>) -> Result<Self::Output, AnalyzeError> {
+        self.left.validate(analyzer)?;
+        self.right.validate(analyzer)?;
+        if let Some(operand) = operand_type(&self.op, self.span) {
+            analyzer.compare_types(self.left.typ(), &operand, self.left.span())?;
+            analyzer.compare_types(self.right.typ(), &operand, self.right.span())?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, 'b> Validate<'a, 'b> for hir::Unary<'b> {
+    type Output = ();
+
+    fn validate(&self, analyzer: &mut Analyzer<'a, 'b>) -> Result<Self::Output, AnalyzeError> {
+        self.expr.validate(analyzer)?;
+        match self.op {
+            UnopKind::Negate => {
+                let operand = crate::types::makers::number(self.span);
+                analyzer.compare_types(self.expr.typ(), &operand, self.expr.span())
+            }
+            // `#` works on strings and tables with `__len`, `not` on anything
+            UnopKind::Length | UnopKind::Not => Ok(()),
+        }
+    }
+}
@@ -12,7 +12,13 @@ impl<'a, 'b> Validate<'a, 'b> for hir::Function<'b> {
         for (idx, param) in function.parameters.iter().enumerate() {
             param.typ.validate(analyzer)?;
             if let Some(expr) = self.defaults.get(idx).unwrap() {
-                analyzer.compare_types(expr.typ(), &param.typ, self.span)?;
+                // defaulted parameters are `T?`, the default value must be `T`
+                let expected = match &param.typ {
+                    Type::Union(union) => &union.members[0],
+                    typ => typ,
+                };
+                expr.validate(analyzer)?;
+                analyzer.compare_types(expr.typ(), expected, expr.span())?;
             }
         }
         if let Some(param) = &function.varidiac_param {
@@ -1,5 +1,6 @@
 use super::*;
 
+mod binary;
 mod function;
 mod suffixed;
 mod table;
@@ -15,22 +16,71 @@ impl<'a, 'b> Validate<'a, 'b> for hir::Expr<'b> {
 
     fn validate(&self, analyzer: &mut Analyzer<'a, 'b>) -> Result<Self::Output, AnalyzeError> {
         match self {
+            hir::Expr::Binary(node) => node.validate(analyzer),
+            hir::Expr::IfElse(node) => node.validate(analyzer),
+            hir::Expr::InterpolatedStr(node) => node.validate(analyzer),
             hir::Expr::Literal(node) => node.validate(analyzer),
             hir::Expr::TypeAssertion(node) => node.validate(analyzer),
             hir::Expr::Table(node) => node.validate(analyzer),
             hir::Expr::Function(node) => node.validate(analyzer),
             hir::Expr::Suffixed(node) => node.validate(analyzer),
-            hir::Expr::Library(..) => todo!(),
+            hir::Expr::Library(node) => node.validate(analyzer),
+            hir::Expr::Unary(node) => node.validate(analyzer),
         }
     }
 }
 
+impl<'a, 'b> Validate<'a, 'b> for hir::LibraryExpr<'b> {
+    type Output = ();
+
+    fn validate(&self, analyzer: &mut Analyzer<'a, 'b>) -> Result<Self::Output, AnalyzeError> {
+        match self {
+            // the required module is checked on its own
+            hir::LibraryExpr::Require(node) => node.path.validate(analyzer),
+            hir::LibraryExpr::SetMetatable(node) => {
+                node.base_table.validate(analyzer)?;
+                node.metatable.validate(analyzer)
+            }
+        }
+    }
+}
+
+impl<'a, 'b> Validate<'a, 'b> for hir::IfElse<'b> {
+    type Output = ();
+
+    fn validate(&self, analyzer: &mut Analyzer<'a, 'b>) -> Result<Self::Output, AnalyzeError> {
+        for (condition, value) in self.branches.iter() {
+            condition.validate(analyzer)?;
+            value.validate(analyzer)?;
+        }
+        self.else_value.validate(analyzer)
+    }
+}
+
+impl<'a, 'b> Validate<'a, 'b> for hir::InterpolatedStr<'b> {
+    type Output = ();
+
+    fn validate(&self, analyzer: &mut Analyzer<'a, 'b>) -> Result<Self::Output, AnalyzeError> {
+        // any value can be converted into a string
+        for expr in self.exprs.iter() {
+            expr.validate(analyzer)?;
+        }
+        Ok(())
+    }
+}
+
 impl<'a, 'b> Validate<'a, 'b> for hir::Literal<'b> {
     type Output = ();
 
-    fn validate(&self, _: &mut Analyzer<'a, 'b>) -> Result<Self::Output, AnalyzeError> {
+    fn validate(&self, analyzer: &mut Analyzer<'a, 'b>) -> Result<Self::Output, AnalyzeError> {
         // TODO(memothelemo): Do something with other literal expressions
-        Ok(())
+        match self.symbol {
+            Some(symbol) => {
+                let name = global_name(analyzer, symbol);
+                validate_global_access(analyzer, name, self.span)
+            }
+            None => Ok(()),
+        }
     }
 }
 
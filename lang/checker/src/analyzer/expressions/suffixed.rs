@@ -1,4 +1,60 @@
 use super::*;
+use crate::{types::generics, SymbolKind};
+
+/// Checks if the variable is declared in the outermost scope
+/// of the module, where the globals are declared.
+fn is_global(analyzer: &Analyzer, symbol: Id<Symbol>) -> bool {
+    match analyzer.ctx.scopes.iter().next() {
+        Some((_, scope)) => scope.vars.iter().any(|(_, v)| *v == symbol),
+        None => false,
+    }
+}
+
+/// Gets the name of the variable if it is a global variable.
+pub(crate) fn global_name(analyzer: &Analyzer, symbol: Id<Symbol>) -> Option<String> {
+    match &analyzer.ctx.symbols.get(symbol)?.kind {
+        SymbolKind::UnknownVariable(name) => Some(name.to_string()),
+        SymbolKind::BlockVariable(var) if is_global(analyzer, symbol) => Some(var.name.to_string()),
+        _ => None,
+    }
+}
+
+/// Gets the full path of a global variable or one of its members
+/// (like `table.unpack`) from the expression.
+fn global_path(analyzer: &Analyzer, expr: &hir::Expr) -> Option<String> {
+    match expr {
+        hir::Expr::Literal(hir::Literal {
+            symbol: Some(symbol),
+            ..
+        }) => global_name(analyzer, *symbol),
+        hir::Expr::Suffixed(hir::Suffixed {
+            base,
+            kind: hir::SuffixKind::Name(name, ..),
+            ..
+        }) => global_path(analyzer, base).map(|path| format!("{}.{}", path, name)),
+        _ => None,
+    }
+}
+
+/// Makes sure that the standard library member (like `table.unpack`)
+/// read from a global variable exists on the target.
+pub(crate) fn validate_global_access(
+    analyzer: &Analyzer,
+    path: Option<String>,
+    span: Span,
+) -> AnalyzeResult {
+    let target = analyzer.ctx.env.cfg().get().target;
+    match path {
+        Some(path) if !stdlib::is_available(&path, target) => {
+            Err(AnalyzeError::UnavailableOnTarget {
+                span,
+                name: path,
+                target: target.to_string(),
+            })
+        }
+        _ => Ok(()),
+    }
+}
 
 pub(crate) fn validate_suffix_call<'a, 'b>(
     analyzer: &mut Analyzer<'a, 'b>,
@@ -8,6 +64,8 @@ pub(crate) fn validate_suffix_call<'a, 'b>(
     // making sure that the base expression is a callback type
     let function_info = match suffixed.base.typ() {
         Type::Function(info) => info,
+        // globals and any typed values can be called with anything
+        Type::Any(..) => return Ok(()),
         Type::Table(tbl) if tbl.metatable.is_some() => {
             let metatable = tbl.metatable.as_ref().unwrap();
 
@@ -42,8 +100,65 @@ pub(crate) fn validate_suffix_call<'a, 'b>(
         }
     };
 
+    // method calls pass the base as the first parameter implicitly
+    let is_method = matches!(
+        suffixed.base.as_ref(),
+        hir::Expr::Suffixed(hir::Suffixed {
+            kind: hir::SuffixKind::Method(..),
+            ..
+        })
+    );
+
+    // type arguments of generic functions are inferred from the
+    // arguments, then the instantiated signature is checked
+    let instantiated;
+    let function_info = match &function_info.type_parameters {
+        Some(type_params) => {
+            let arg_types = args.iter().map(|v| v.typ()).collect::<Vec<_>>();
+            let inferred =
+                generics::infer_type_arguments(function_info, &arg_types, is_method as usize);
+            if let Some(err) = inferred.error {
+                return Err(match err {
+                    generics::InferError::NotInferred { name } => {
+                        AnalyzeError::UninferredTypeParameter {
+                            span: suffixed.span,
+                            name,
+                        }
+                    }
+                    generics::InferError::Conflict {
+                        name,
+                        first,
+                        second,
+                    } => AnalyzeError::ConflictingTypeParameter {
+                        span: suffixed.span,
+                        name,
+                        // `1` and `"x"` conflict because of their base types
+                        first: utils::type_description(&analyzer.ctx, &first.widen()),
+                        second: utils::type_description(&analyzer.ctx, &second.widen()),
+                    },
+                });
+            }
+            let type_args = inferred.args;
+
+            for param in type_params.iter() {
+                if let (Some(explicit), Some(typ)) = (&param.explicit, type_args.get(&param.name)) {
+                    analyzer.compare_types(typ, explicit, suffixed.span)?;
+                }
+            }
+
+            instantiated = generics::instantiate(function_info, &type_args);
+            &instantiated
+        }
+        None => function_info,
+    };
+
     // checking each by each parameter
-    for (idx, param) in function_info.parameters.iter().enumerate() {
+    for (idx, param) in function_info
+        .parameters
+        .iter()
+        .skip(is_method as usize)
+        .enumerate()
+    {
         let arg = args.get(idx);
         let arg = if let Some(arg) = arg {
             arg.typ()
@@ -69,7 +184,18 @@ impl<'a, 'b> Validate<'a, 'b> for hir::Suffixed<'b> {
     fn validate(&self, analyzer: &mut Analyzer<'a, 'b>) -> Result<Self::Output, AnalyzeError> {
         self.base.validate(analyzer)?;
         match &self.kind {
-            hir::SuffixKind::Call(args) => validate_suffix_call(analyzer, self, args),
+            hir::SuffixKind::Call(args, _) => {
+                for arg in args.iter() {
+                    arg.validate(analyzer)?;
+                }
+                validate_suffix_call(analyzer, self, args)
+            }
+            hir::SuffixKind::Computed(index, ..) => index.validate(analyzer),
+            hir::SuffixKind::Name(name, ..) => {
+                let path = global_path(analyzer, &self.base).map(|v| format!("{}.{}", v, name));
+                validate_global_access(analyzer, path, self.span)
+            }
+            hir::SuffixKind::Method(..) => Ok(()),
         }
     }
 }
@@ -12,6 +12,7 @@ mod checker;
 mod errors;
 mod expressions;
 mod statements;
+pub(crate) mod stdlib;
 mod typess;
 
 pub use checker::*;
@@ -43,3 +43,38 @@ impl<'a, 'b> Validate<'a, 'b> for hir::LocalAssign<'b> {
         Ok(())
     }
 }
+
+impl<'a, 'b> Validate<'a, 'b> for hir::CompoundAssign<'b> {
+    type Output = ();
+
+    fn validate(&self, analyzer: &mut Analyzer<'a, 'b>) -> Result<Self::Output, AnalyzeError> {
+        // the target is the left operand of the value
+        self.value.validate(analyzer)?;
+
+        // the result is assigned back to the target
+        analyzer.compare_types(&self.value.typ, self.target.typ(), self.span)
+    }
+}
+
+impl<'a, 'b> Validate<'a, 'b> for hir::Assign<'b> {
+    type Output = ();
+
+    fn validate(&self, analyzer: &mut Analyzer<'a, 'b>) -> Result<Self::Output, AnalyzeError> {
+        let mut values = Vec::new();
+        for expr in self.exprs.iter() {
+            expr.validate(analyzer)?;
+            for typ in expr.typ().clone().deref_tuples() {
+                values.push((expr.span(), typ));
+            }
+        }
+        for (idx, (target, typ)) in self.targets.iter().enumerate() {
+            target.validate(analyzer)?;
+
+            // targets without a value are assigned to `nil`
+            if let Some((span, value)) = values.get(idx) {
+                analyzer.compare_types(value, typ, *span)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use super::*;
+use crate::types::makers;
+
+impl<'a, 'b> Validate<'a, 'b> for hir::If<'b> {
+    type Output = ();
+
+    fn validate(&self, analyzer: &mut Analyzer<'a, 'b>) -> Result<Self::Output, AnalyzeError> {
+        for (condition, block) in self.branches.iter() {
+            condition.validate(analyzer)?;
+            block.validate(analyzer)?;
+        }
+        if let Some(block) = &self.else_block {
+            block.validate(analyzer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, 'b> Validate<'a, 'b> for hir::Loop<'b> {
+    type Output = ();
+
+    fn validate(&self, analyzer: &mut Analyzer<'a, 'b>) -> Result<Self::Output, AnalyzeError> {
+        self.condition.validate(analyzer)?;
+        self.block.validate(analyzer)
+    }
+}
+
+impl<'a, 'b> Validate<'a, 'b> for hir::NumericFor<'b> {
+    type Output = ();
+
+    fn validate(&self, analyzer: &mut Analyzer<'a, 'b>) -> Result<Self::Output, AnalyzeError> {
+        for expr in [Some(&self.start), Some(&self.end), self.step.as_ref()]
+            .into_iter()
+            .flatten()
+        {
+            expr.validate(analyzer)?;
+            analyzer.compare_types(expr.typ(), &makers::number(expr.span()), expr.span())?;
+        }
+        self.block.validate(analyzer)
+    }
+}
+
+impl<'a, 'b> Validate<'a, 'b> for hir::GenericFor<'b> {
+    type Output = ();
+
+    fn validate(&self, analyzer: &mut Analyzer<'a, 'b>) -> Result<Self::Output, AnalyzeError> {
+        for expr in self.exprs.iter() {
+            expr.validate(analyzer)?;
+        }
+        self.block.validate(analyzer)
+    }
+}
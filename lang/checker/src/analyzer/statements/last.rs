@@ -24,7 +24,7 @@ impl<'a, 'b> Validate<'a, 'b> for hir::LastStmt<'b> {
         match self {
             hir::LastStmt::None => Ok(()),
             hir::LastStmt::Return(node) => node.validate(analyzer),
-            hir::LastStmt::Break(..) => Ok(()),
+            hir::LastStmt::Break(..) | hir::LastStmt::Continue(..) => Ok(()),
         }
     }
 }
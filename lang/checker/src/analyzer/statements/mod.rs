@@ -1,6 +1,7 @@
 use super::*;
 
 mod assign;
+mod control_flow;
 mod last;
 mod typ;
 
@@ -13,10 +14,18 @@ impl<'a, 'b> Validate<'a, 'b> for hir::Stmt<'b> {
 
     fn validate(&self, analyzer: &mut Analyzer<'a, 'b>) -> Result<Self::Output, AnalyzeError> {
         match self {
+            hir::Stmt::Assign(node) => node.validate(analyzer),
+            hir::Stmt::Do(node) => node.validate(analyzer),
+            hir::Stmt::GenericFor(node) => node.validate(analyzer),
+            hir::Stmt::If(node) => node.validate(analyzer),
             hir::Stmt::LocalAssign(node) => node.validate(analyzer),
+            hir::Stmt::NumericFor(node) => node.validate(analyzer),
+            hir::Stmt::Repeat(node) | hir::Stmt::While(node) => node.validate(analyzer),
             hir::Stmt::TypeDeclaration(node) => node.validate(analyzer),
             hir::Stmt::Call(node) => node.validate(analyzer),
-            hir::Stmt::Library(..) => todo!(),
+            hir::Stmt::CompoundAssign(node) => node.validate(analyzer),
+            hir::Stmt::Declare(node) => node.validate(analyzer),
+            hir::Stmt::Library(node) => node.validate(analyzer),
         }
     }
 }
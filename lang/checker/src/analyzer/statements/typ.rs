@@ -23,3 +23,11 @@ impl<'a, 'b> Validate<'a, 'b> for hir::TypeDeclaration<'b> {
         self.value.validate(analyzer)
     }
 }
+
+impl<'a, 'b> Validate<'a, 'b> for hir::Declare<'b> {
+    type Output = ();
+
+    fn validate(&self, analyzer: &mut Analyzer<'a, 'b>) -> Result<Self::Output, AnalyzeError> {
+        self.typ.validate(analyzer)
+    }
+}
@@ -0,0 +1,86 @@
+use salite_common::LuaTarget;
+
+use LuaTarget::*;
+
+/// Standard library members that only exist on some of the
+/// Lua targets. Anything not listed here is assumed to exist
+/// on every target.
+const MEMBERS: &[(&str, &[LuaTarget])] = &[
+    // removed after Lua 5.1
+    ("gcinfo", &[Lua51, Luau]),
+    ("getfenv", &[Lua51, Luau]),
+    ("loadstring", &[Lua51, Luau]),
+    ("math.atan2", &[Lua51, Luau]),
+    ("math.cosh", &[Lua51, Luau]),
+    ("math.frexp", &[Lua51, Luau]),
+    ("math.ldexp", &[Lua51, Luau]),
+    ("math.log10", &[Lua51, Luau]),
+    ("math.pow", &[Lua51, Luau]),
+    ("math.sinh", &[Lua51, Luau]),
+    ("math.tanh", &[Lua51, Luau]),
+    ("module", &[Lua51]),
+    ("newproxy", &[Lua51, Luau]),
+    ("setfenv", &[Lua51, Luau]),
+    ("table.getn", &[Lua51, Luau]),
+    ("table.maxn", &[Lua51, Luau]),
+    ("table.setn", &[Lua51]),
+    ("unpack", &[Lua51, Luau]),
+    // added after Lua 5.1
+    ("coroutine.close", &[Lua54, Luau]),
+    ("coroutine.isyieldable", &[Lua53, Lua54, Luau]),
+    ("math.maxinteger", &[Lua53, Lua54]),
+    ("math.mininteger", &[Lua53, Lua54]),
+    ("math.tointeger", &[Lua53, Lua54]),
+    ("math.type", &[Lua53, Lua54]),
+    ("math.ult", &[Lua53, Lua54]),
+    ("rawlen", &[Lua53, Lua54, Luau]),
+    ("string.pack", &[Lua53, Lua54]),
+    ("string.packsize", &[Lua53, Lua54]),
+    ("string.unpack", &[Lua53, Lua54]),
+    ("table.move", &[Lua53, Lua54, Luau]),
+    ("table.pack", &[Lua53, Lua54, Luau]),
+    ("table.unpack", &[Lua53, Lua54, Luau]),
+    ("utf8", &[Lua53, Lua54, Luau]),
+    ("warn", &[Lua54]),
+    // not in Luau, it has no access to the filesystem
+    ("dofile", &[Lua51, Lua53, Lua54]),
+    ("io", &[Lua51, Lua53, Lua54]),
+    ("load", &[Lua51, Lua53, Lua54]),
+    ("loadfile", &[Lua51, Lua53, Lua54]),
+    ("os.execute", &[Lua51, Lua53, Lua54]),
+    ("os.exit", &[Lua51, Lua53, Lua54]),
+    ("os.getenv", &[Lua51, Lua53, Lua54]),
+    ("os.remove", &[Lua51, Lua53, Lua54]),
+    ("os.rename", &[Lua51, Lua53, Lua54]),
+    ("os.setlocale", &[Lua51, Lua53, Lua54]),
+    ("os.tmpname", &[Lua51, Lua53, Lua54]),
+    ("package", &[Lua51, Lua53, Lua54]),
+    // Luau only
+    ("bit32", &[Luau]),
+    ("math.clamp", &[Luau]),
+    ("math.noise", &[Luau]),
+    ("math.round", &[Luau]),
+    ("math.sign", &[Luau]),
+    ("string.split", &[Luau]),
+    ("table.clear", &[Luau]),
+    ("table.clone", &[Luau]),
+    ("table.create", &[Luau]),
+    ("table.find", &[Luau]),
+    ("table.freeze", &[Luau]),
+    ("table.isfrozen", &[Luau]),
+    ("typeof", &[Luau]),
+];
+
+/// Checks if the standard library member (like `table.unpack`)
+/// exists on the target. Members of a library that does not exist
+/// on the target (like `utf8.char`) do not exist either.
+pub(crate) fn is_available(path: &str, target: LuaTarget) -> bool {
+    MEMBERS.iter().all(|(member, targets)| {
+        let matches = path == *member
+            || path
+                .strip_prefix(member)
+                .map(|v| v.starts_with('.'))
+                .unwrap_or(false);
+        !matches || targets.contains(&target)
+    })
+}
@@ -1,27 +1,45 @@
 mod scope;
 mod symbol;
 
-use std::{path::PathBuf, sync::Arc};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
-use id_arena::Arena;
+use id_arena::{Arena, Id};
 use salite_ast::Node;
-use salite_common::{memory::SafePtr, Config as ProjectCfg};
+use salite_common::{dictionary::Dictionary, memory::SafePtr, Config as ProjectCfg};
 
 pub use scope::*;
 pub use symbol::*;
 
-use crate::{hir, Diagnostic, DiagnosticLevel, Transformer};
+use crate::{hir, types::Type, Diagnostic, DiagnosticLevel, Resolver, Transformer};
 
 #[derive(Debug)]
 pub struct ModuleResult<'a, 'b> {
     pub ctx: Arc<ModuleContext<'a, 'b>>,
     pub file: hir::File<'b>,
+    /// The type of what the module returns, it is given
+    /// from `require` calls of the other modules.
+    pub(crate) return_type: Option<Type>,
 }
 
 #[derive(Debug)]
 pub struct EnvContext<'a, 'b> {
     pub(crate) cfg: &'a ProjectCfg,
     pub(crate) modules: Vec<(PathBuf, ModuleResult<'a, 'b>)>,
+    /// Parsed files that can be required before they are added.
+    pub(crate) sources: Vec<(PathBuf, &'b salite_ast::File)>,
+    /// Modules that are being transformed, a module requiring one
+    /// of them is a require cycle.
+    pub(crate) loading: Vec<PathBuf>,
+}
+
+fn same_file(a: &Path, b: &Path) -> bool {
+    match (std::fs::canonicalize(a), std::fs::canonicalize(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
 }
 
 unsafe impl<'a, 'b> std::marker::Send for EnvContext<'a, 'b> {}
@@ -45,22 +63,40 @@ impl<'a, 'b> EnvContext<'a, 'b> {
         Self {
             cfg,
             modules: Vec::new(),
+            sources: Vec::new(),
+            loading: Vec::new(),
         }
     }
 
+    /// Registers a parsed file so it can be transformed once
+    /// it is required by the other module.
+    pub fn add_source(&mut self, path: PathBuf, file: &'b salite_ast::File) {
+        self.sources.push((path, file));
+    }
+
+    /// Transforms the parsed file into a module, it is only
+    /// transformed once if it is already required by the other module.
     pub fn add_module(
         &mut self,
         path: PathBuf,
         file: &'b salite_ast::File,
     ) -> &ModuleResult<'a, 'b> {
+        if self.get_module_result(&path).is_some() {
+            return self.get_module_result(&path).unwrap();
+        }
+
         let ptr = SafePtr::from_ptr(self as *mut _);
         let mut ctx = ModuleContext::new(*file.declaration(), ptr, Some(path.clone()));
+        self.loading.push(path.clone());
         let file = Transformer::transform(SafePtr::from_ptr(&mut ctx as *mut _), file);
+        self.loading.pop();
+        let return_type = Resolver::module_return_type(&mut ctx, &file);
         self.modules.push((
             path.clone(),
             ModuleResult {
                 ctx: Arc::new(ctx),
                 file,
+                return_type,
             },
         ));
 
@@ -84,12 +120,138 @@ impl<'a, 'b> EnvContext<'a, 'b> {
         }
         None
     }
+
+    /// Gets the module of a source file, the path of the module
+    /// doesn't have to be the same as long as it is the same file.
+    pub fn get_module_by_file(&self, path: &Path) -> Option<&ModuleResult<'a, 'b>> {
+        self.modules
+            .iter()
+            .find(|(entry_path, _)| same_file(entry_path, path))
+            .map(|(_, result)| result)
+    }
+
+    /// Gets the required module, it is transformed first if it isn't
+    /// yet. Modules that are being transformed (require cycles) and
+    /// files that aren't registered give nothing.
+    pub(crate) fn load_module(&mut self, path: &Path) -> Option<&ModuleResult<'a, 'b>> {
+        if self.loading.iter().any(|v| same_file(v, path)) {
+            return None;
+        }
+        if self.get_module_by_file(path).is_none() {
+            let (path, file) = self
+                .sources
+                .iter()
+                .find(|(entry_path, _)| same_file(entry_path, path))
+                .map(|(path, file)| (path.clone(), *file))?;
+            self.add_module(path, file);
+        }
+        self.get_module_by_file(path)
+    }
+
+    /// Gets the paths of every registered declaration file.
+    pub(crate) fn declaration_paths(&self) -> Vec<PathBuf> {
+        self.sources
+            .iter()
+            .filter(|(_, file)| *file.declaration())
+            .map(|(path, _)| path.clone())
+            .collect()
+    }
+
+    /// Finds the source file of `require(name)` called from a module.
+    ///
+    /// Names starting with `./` or `../` are relative to the module,
+    /// the rest are relative to 'sourceDir' entry (like `nested.mod`).
+    /// Declaration files and `init` files of directories are also
+    /// looked up.
+    pub fn find_module_path(&self, from: Option<&Path>, name: &str) -> Option<PathBuf> {
+        let base = if name.starts_with("./") || name.starts_with("../") {
+            from?.parent()?.join(name)
+        } else {
+            let source_dir = &self.cfg.get().source_dir;
+            let root = match self.cfg.path() {
+                Some(cfg_path) => cfg_path.parent()?.join(source_dir),
+                None => source_dir.clone(),
+            };
+            root.join(name.replace('.', "/"))
+        };
+
+        let file_name = base.file_name()?.to_string_lossy().to_string();
+        let candidates = [
+            base.with_file_name(format!("{}.slt", file_name)),
+            base.with_file_name(format!("{}.d.slt", file_name)),
+            base.join("init.slt"),
+            base.join("init.d.slt"),
+        ];
+        candidates
+            .into_iter()
+            .find(|v| v.is_file())
+            .map(|v| std::fs::canonicalize(&v).unwrap_or(v))
+    }
+
+    /// Looks for a chain of `require` calls from the required module
+    /// back to the module that requires it. The chain is made of the
+    /// names as they are written in `require`.
+    pub(crate) fn find_require_cycle(
+        &self,
+        from: &Path,
+        required: &ModuleSymbol,
+    ) -> Option<Vec<String>> {
+        let mut chain = vec![required.name.to_string()];
+        let mut visited = Vec::new();
+        if self.visit_requires(from, required.path.as_ref()?, &mut chain, &mut visited) {
+            Some(chain)
+        } else {
+            None
+        }
+    }
+
+    fn visit_requires(
+        &self,
+        from: &Path,
+        path: &Path,
+        chain: &mut Vec<String>,
+        visited: &mut Vec<PathBuf>,
+    ) -> bool {
+        if same_file(path, from) {
+            return true;
+        }
+        if visited.iter().any(|v| v == path) {
+            return false;
+        }
+        visited.push(path.to_path_buf());
+
+        let module = match self.get_module_by_file(path) {
+            Some(module) => module,
+            None => return false,
+        };
+        for (_, symbol) in module.ctx.symbols.iter() {
+            if let SymbolKind::Module(ModuleSymbol {
+                name,
+                path: Some(next),
+            }) = &symbol.kind
+            {
+                chain.push(name.to_string());
+                if self.visit_requires(from, next, chain, visited) {
+                    return true;
+                }
+                chain.pop();
+            }
+        }
+        false
+    }
 }
 
 pub struct ModuleContext<'env, 'node> {
     pub(crate) declaration: bool,
     pub(crate) diagnostics: Vec<Diagnostic>,
     pub(crate) env: SafePtr<EnvContext<'env, 'node>>,
+    /// Type aliases declared with `export type` in the module scope.
+    pub(crate) exported_types: Dictionary<String, Id<Symbol>>,
+    /// Variables and functions declared with `declare` in a
+    /// declaration file, they are declared in every module.
+    pub(crate) globals: Dictionary<String, Id<Symbol>>,
+    /// Modules declared with `declare module` in a declaration file.
+    pub(crate) declared_modules: Dictionary<String, Id<Symbol>>,
     pub(crate) file_path: Option<PathBuf>,
     pub(crate) nodes: Arena<&'node dyn Node>,
     pub(crate) scopes: Arena<Scope>,
@@ -118,6 +280,9 @@ impl<'env, 'node> ModuleContext<'env, 'node> {
             env,
             declaration,
             diagnostics: Vec::new(),
+            exported_types: Dictionary::new(),
+            globals: Dictionary::new(),
+            declared_modules: Dictionary::new(),
             file_path,
             scopes: Arena::new(),
             symbols: Arena::new(),
@@ -129,6 +294,10 @@ impl<'env, 'node> ModuleContext<'env, 'node> {
         self.diagnostics.push(diag);
     }
 
+    pub fn declaration(&self) -> bool {
+        self.declaration
+    }
+
     pub fn diagnostics(&self) -> &Vec<Diagnostic> {
         &self.diagnostics
     }
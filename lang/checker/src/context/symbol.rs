@@ -1,5 +1,6 @@
 use crate::{hir::TypeParameter, types::Type};
 use salite_ast::Span;
+use std::path::PathBuf;
 
 #[derive(Debug, PartialEq)]
 pub struct TypeAliasSymbol {
@@ -16,13 +17,26 @@ pub struct BlockVariableSymbol {
     pub explicit: bool,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModuleSymbol {
+    /// The path as it is written in `require`
+    pub name: String,
+    /// The source file of the module, it is `None` if it doesn't exist
+    pub path: Option<PathBuf>,
+}
+
 #[derive(Debug, PartialEq)]
 pub enum SymbolKind {
     BlockVariable(BlockVariableSymbol),
     FunctionParameter(String, Type, bool),
-    TypeParameter(String, Type),
+    /// The required module, its type is the type of what the
+    /// module returns and it is only known once it is resolved.
+    Module(ModuleSymbol),
+    /// The flag tells if it is a type parameter of a generic function,
+    /// those are kept as type variables until the function is called.
+    TypeParameter(String, Type, bool),
     TypeAlias(TypeAliasSymbol),
-    UnknownVariable,
+    UnknownVariable(String),
     Value(Type),
 }
 
@@ -36,11 +50,12 @@ impl Symbol {
     pub fn get_type(&self) -> Option<&Type> {
         match &self.kind {
             SymbolKind::BlockVariable(ty) => Some(&ty.typ),
-            SymbolKind::TypeParameter(.., ty) => Some(ty),
+            SymbolKind::TypeParameter(_, ty, ..) => Some(ty),
             SymbolKind::TypeAlias(ty) => Some(&ty.typ),
-            SymbolKind::UnknownVariable => None,
+            SymbolKind::UnknownVariable(..) => None,
             SymbolKind::Value(ty) => Some(ty),
             SymbolKind::FunctionParameter(_, ty, ..) => Some(ty),
+            SymbolKind::Module(..) => None,
         }
     }
 }
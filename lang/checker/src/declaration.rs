@@ -0,0 +1,180 @@
+use crate::{
+    hir,
+    types::{variants, Type},
+    ModuleContext, SymbolKind,
+};
+
+/// Name of the local variable that holds the type of
+/// the exported value in the declaration file.
+const EXPORTS_NAME: &str = "exports";
+
+fn is_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|v| v.is_ascii_alphabetic() || v == '_')
+        && chars.all(|v| v.is_ascii_alphanumeric() || v == '_')
+        && salite_ast::SymbolType::parse(name).is_none()
+}
+
+fn table_key(ctx: &ModuleContext, key: &variants::TableFieldKey) -> Option<String> {
+    match key {
+        variants::TableFieldKey::Name(name, ..) if is_name(name) => Some(name.to_string()),
+        variants::TableFieldKey::Name(name, ..) => Some(format!("[{:?}]", name)),
+        variants::TableFieldKey::Computed(typ, ..) => Some(format!("[{}]", type_source(ctx, typ))),
+        variants::TableFieldKey::None(..) => None,
+    }
+}
+
+fn table_source(ctx: &ModuleContext, tbl: &variants::Table) -> String {
+    if tbl.entries.is_empty() {
+        return String::from("{}");
+    }
+
+    let mut entries = Vec::new();
+    for (key, value) in tbl.entries.iter() {
+        let value = type_source(ctx, value);
+        entries.push(match table_key(ctx, key) {
+            Some(key) => format!("{}: {}", key, value),
+            None => value,
+        });
+    }
+
+    format!("{{ {} }}", entries.join(", "))
+}
+
+fn members_source(ctx: &ModuleContext, members: &[Type], separator: &str) -> String {
+    members
+        .iter()
+        .map(|v| type_source(ctx, v))
+        .collect::<Vec<String>>()
+        .join(separator)
+}
+
+/// Writes the type back into Salite type syntax.
+///
+/// Unlike [`crate::type_description`], it doesn't leave out
+/// anything so it can be parsed back again.
+pub fn type_source(ctx: &ModuleContext, typ: &Type) -> String {
+    match typ {
+        Type::Any(..) => "any".to_string(),
+        Type::Function(info) => {
+            let mut params = Vec::new();
+            for param in info.parameters.iter() {
+                params.push(format!(
+                    "{}{}: {}",
+                    param.name,
+                    if param.optional { "?" } else { "" },
+                    type_source(ctx, &param.typ)
+                ));
+            }
+            if let Some(varidiac) = &info.varidiac_param {
+                params.push(format!("...: {}", type_source(ctx, &varidiac.typ)));
+            }
+            format!(
+                "{}({}) -> {}",
+                match &info.type_parameters {
+                    Some(type_params) => type_parameters_source(ctx, type_params),
+                    None => String::new(),
+                },
+                params.join(", "),
+                type_source(ctx, &info.return_type)
+            )
+        }
+        Type::Literal(variants::Literal {
+            value: Some(value), ..
+        }) => value.to_string(),
+        Type::Literal(info) => match info.typ {
+            variants::LiteralType::Bool => "bool",
+            variants::LiteralType::Number => "number",
+            variants::LiteralType::Nil => "nil",
+            variants::LiteralType::String => "string",
+            variants::LiteralType::Void => "void",
+        }
+        .to_string(),
+        Type::Intersection(node) => members_source(ctx, &node.members, " & "),
+        Type::Reference(info) => match &info.arguments {
+            Some(arguments) => format!("{}<{}>", info.name, members_source(ctx, arguments, ", ")),
+            None => info.name.to_string(),
+        },
+        Type::Recursive(info) => match ctx.symbols.get(info.symbol).map(|v| &v.kind) {
+            Some(SymbolKind::TypeAlias(alias)) => alias.name.to_string(),
+            _ => "any".to_string(),
+        },
+        Type::Unknown(..) => "unknown".to_string(),
+        Type::Union(node) => members_source(ctx, &node.members, " | "),
+        // it shouldn't happen after the module is resolved
+        Type::Unresolved(..) => "any".to_string(),
+        Type::Table(tbl) => table_source(ctx, tbl),
+        Type::Tuple(node) => format!("({})", members_source(ctx, &node.members, ", ")),
+    }
+}
+
+fn type_parameters_source(ctx: &ModuleContext, params: &[hir::TypeParameter]) -> String {
+    let mut output = Vec::new();
+    for param in params.iter() {
+        let mut param_source = param.name.to_string();
+        if let Some(explicit) = &param.explicit {
+            param_source.push_str(&format!(": {}", type_source(ctx, explicit)));
+        }
+        if let Some(default) = &param.default {
+            param_source.push_str(&format!(" = {}", type_source(ctx, default)));
+        }
+        output.push(param_source);
+    }
+    format!("<{}>", output.join(", "))
+}
+
+/// Generates the contents of a declaration file (`.d.slt`) from
+/// a resolved module. Every type alias declared in the module scope
+/// (as it is written) and the type of the returned value are declared.
+pub fn emit_declaration(ctx: &ModuleContext, file: &hir::File) -> String {
+    let mut output = String::new();
+
+    for stmt in file.block.stmts.iter() {
+        let decl = match stmt {
+            hir::Stmt::TypeDeclaration(decl) => decl,
+            _ => continue,
+        };
+        let alias = match ctx.symbols.get(decl.symbol).map(|v| &v.kind) {
+            Some(SymbolKind::TypeAlias(alias)) => alias,
+            _ => continue,
+        };
+        output.push_str(&format!(
+            "{}type {}{} = {}\n",
+            if decl.exported { "export " } else { "" },
+            alias.name,
+            match &alias.parameters {
+                Some(params) => type_parameters_source(ctx, params),
+                None => String::new(),
+            },
+            type_source(ctx, &decl.declared)
+        ));
+    }
+
+    if let hir::LastStmt::Return(node) = &file.block.last_stmt {
+        // only the first value is given from `require`
+        let typ = match &node.concluding_typ {
+            Type::Tuple(tuple) => tuple.members.first(),
+            Type::Literal(variants::Literal {
+                typ: variants::LiteralType::Void,
+                ..
+            }) => None,
+            typ => Some(typ),
+        };
+        if let Some(typ) = typ {
+            if !output.is_empty() {
+                output.push('\n');
+            }
+            // `return { name = "x" }` gives a string field, not a `"x"` one
+            output.push_str(&format!(
+                "declare local {}: {}\nreturn {}\n",
+                EXPORTS_NAME,
+                type_source(ctx, &typ.clone().widen()),
+                EXPORTS_NAME
+            ));
+        }
+    }
+
+    output
+}
@@ -18,6 +18,9 @@ pub enum Diagnostic {
     #[error("Unknown variable: {name}")]
     UnknownVariable { name: String, span: Span },
 
+    #[error("Unknown module: {name}")]
+    UnknownModule { name: String, span: Span },
+
     #[error("Duplicated metatable")]
     DuplicatedMetatable { span: Span },
 }
@@ -28,6 +31,7 @@ impl Diagnostic {
             Diagnostic::InvalidLibraryUse { .. } => DiagnosticLevel::Error,
             Diagnostic::UnknownTypeAlias { .. } => DiagnosticLevel::Info,
             Diagnostic::UnknownVariable { .. } => DiagnosticLevel::Info,
+            Diagnostic::UnknownModule { .. } => DiagnosticLevel::Error,
             Diagnostic::DuplicatedMetatable { .. } => DiagnosticLevel::Error,
         }
     }
@@ -1,17 +1,23 @@
 use super::*;
-use crate::{types::Type, Symbol};
+use crate::{
+    types::{generics, makers, variants, Type},
+    Symbol,
+};
 use id_arena::Id;
-use salite_ast::{Node, Span};
-use std::borrow::Borrow;
+use salite_ast::{BinopKind, Node, Span, UnopKind};
 
 #[derive(Debug, Clone)]
 pub enum Expr<'a> {
+    Binary(Binary<'a>),
     Function(Function<'a>),
+    IfElse(IfElse<'a>),
+    InterpolatedStr(InterpolatedStr<'a>),
     Library(LibraryExpr<'a>),
     Literal(Literal<'a>),
     TypeAssertion(TypeAssertion<'a>),
     Table(Table<'a>),
     Suffixed(Suffixed<'a>),
+    Unary(Unary<'a>),
 }
 
 impl<'a> Expr<'a> {
@@ -21,23 +27,31 @@ impl<'a> Expr<'a> {
             //     Type::Function(n) => n.return_type.borrow(),
             //     c => c,
             // },
+            Expr::Binary(node) => &node.typ,
             Expr::Function(node) => &node.typ,
+            Expr::IfElse(node) => &node.typ,
+            Expr::InterpolatedStr(node) => &node.typ,
             Expr::Literal(node) => &node.typ,
             Expr::TypeAssertion(node) => &node.cast,
             Expr::Table(node) => &node.typ,
             Expr::Library(node) => node.typ(),
             Expr::Suffixed(node) => node.typ(),
+            Expr::Unary(node) => &node.typ,
         }
     }
 
     pub fn span(&self) -> Span {
         match self {
+            Expr::Binary(node) => node.span,
             Expr::Function(node) => node.span,
+            Expr::IfElse(node) => node.span,
+            Expr::InterpolatedStr(node) => node.span,
             Expr::Literal(node) => node.span,
             Expr::TypeAssertion(node) => node.span,
             Expr::Table(node) => node.span,
             Expr::Library(node) => node.span(),
             Expr::Suffixed(node) => node.span,
+            Expr::Unary(node) => node.span,
         }
     }
 
@@ -51,7 +65,17 @@ impl<'a> Expr<'a> {
 
 #[derive(Debug, Clone)]
 pub enum SuffixKind<'a> {
-    Call(Vec<Expr<'a>>),
+    /// Calls with arguments, it holds the type of the result.
+    Call(Vec<Expr<'a>>, Type),
+
+    /// Indexes with an expression, it holds the type of the member.
+    Computed(Box<Expr<'a>>, Type),
+
+    /// Indexes a method, it holds the type of the member.
+    Method(String, Type),
+
+    /// Indexes with a name, it holds the type of the member.
+    Name(String, Type),
 }
 
 #[derive(Debug, Clone)]
@@ -64,10 +88,48 @@ pub struct Suffixed<'a> {
 impl<'a> Suffixed<'a> {
     pub fn typ(&self) -> &Type {
         match &self.kind {
-            SuffixKind::Call(..) => match self.base.typ() {
-                Type::Function(n) => n.return_type.borrow(),
-                c => c,
-            },
+            SuffixKind::Call(_, typ) => typ,
+            SuffixKind::Computed(_, typ) => typ,
+            SuffixKind::Method(_, typ) => typ,
+            SuffixKind::Name(_, typ) => typ,
+        }
+    }
+
+    /// Gets the result type of calling the base expression. The return
+    /// type of generic functions is instantiated with the type arguments
+    /// inferred from the arguments.
+    pub fn call_type(base: &Expr, args: &[Expr]) -> Type {
+        let info = match base.typ() {
+            Type::Function(info) => info,
+            typ => return typ.clone(),
+        };
+        if info.type_parameters.is_none() {
+            return info.return_type.as_ref().clone();
+        }
+
+        // method calls pass the base as the first parameter implicitly
+        let is_method = matches!(
+            base,
+            Expr::Suffixed(Suffixed {
+                kind: SuffixKind::Method(..),
+                ..
+            })
+        );
+        let arg_types = args.iter().map(|v| v.typ()).collect::<Vec<_>>();
+        let inferred = generics::infer_type_arguments(info, &arg_types, is_method as usize);
+        generics::substitute(&info.return_type, &inferred.args)
+    }
+
+    /// Looks for the type of the named member from the base type,
+    /// it will fallback to `any` if it cannot find one.
+    pub fn member_type(base: &Type, name: &str, span: Span) -> Type {
+        match base {
+            Type::Table(tbl) => tbl
+                .entries
+                .get(&variants::TableFieldKey::Name(name.to_string(), span))
+                .cloned()
+                .unwrap_or(makers::any(span)),
+            _ => makers::any(span),
         }
     }
 }
@@ -81,20 +143,33 @@ pub struct SetMetatable<'a> {
     pub metatable: Box<Expr<'a>>,
 }
 
+/// `require` call with a string path, its type is the type
+/// of what the required module returns.
+#[derive(Debug, Clone)]
+pub struct Require<'a> {
+    pub span: Span,
+    pub typ: Type,
+    pub path: Box<Expr<'a>>,
+    pub module_symbol: Id<Symbol>,
+}
+
 #[derive(Debug, Clone)]
 pub enum LibraryExpr<'a> {
+    Require(Require<'a>),
     SetMetatable(SetMetatable<'a>),
 }
 
 impl<'a> LibraryExpr<'a> {
     pub fn typ(&self) -> &Type {
         match self {
+            LibraryExpr::Require(node) => &node.typ,
             LibraryExpr::SetMetatable(node) => &node.return_type,
         }
     }
 
     pub fn span(&self) -> Span {
         match self {
+            LibraryExpr::Require(node) => node.span,
             LibraryExpr::SetMetatable(node) => node.span,
         }
     }
@@ -117,6 +192,153 @@ pub struct Function<'a> {
     pub node_id: Id<&'a dyn Node>,
 }
 
+#[derive(Debug, Clone)]
+pub struct Binary<'a> {
+    pub left: Box<Expr<'a>>,
+    pub op: BinopKind,
+    pub right: Box<Expr<'a>>,
+    pub span: Span,
+    pub typ: Type,
+    pub node_id: Id<&'a dyn Node>,
+}
+
+impl<'a> Binary<'a> {
+    /// Gets the type of the value given from the operator.
+    pub fn result_type(&self) -> Type {
+        match self.op {
+            BinopKind::Add
+            | BinopKind::Subtract
+            | BinopKind::Multiply
+            | BinopKind::Divide
+            | BinopKind::FloorDivision
+            | BinopKind::Modulo
+            | BinopKind::Exponent => makers::number(self.span),
+            BinopKind::Concat => makers::string(self.span),
+            BinopKind::Equality
+            | BinopKind::Inequality
+            | BinopKind::GreaterThan
+            | BinopKind::GreaterEqual
+            | BinopKind::LessThan
+            | BinopKind::LessEqual => makers::bool(self.span),
+            // it gives one of the operands
+            BinopKind::And | BinopKind::Or => values_type(
+                [self.left.as_ref(), self.right.as_ref()].into_iter(),
+                self.span,
+            ),
+            // the left operand is only given if it is not `nil`
+            BinopKind::NilshCoalescing => {
+                let operands = [self.left.as_ref(), self.right.as_ref()];
+                let right = values_type(std::iter::once(self.right.as_ref()), self.span);
+                match values_type(operands.into_iter(), self.span) {
+                    Type::Union(mut union) if !has_nil(&right) => {
+                        union.members.retain(|v| !has_nil(v));
+                        if union.members.len() == 1 {
+                            union.members.pop().unwrap()
+                        } else {
+                            Type::Union(union)
+                        }
+                    }
+                    typ => typ,
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Unary<'a> {
+    pub op: UnopKind,
+    pub expr: Box<Expr<'a>>,
+    pub span: Span,
+    pub typ: Type,
+    pub node_id: Id<&'a dyn Node>,
+}
+
+impl<'a> Unary<'a> {
+    /// Gets the type of the value given from the operator.
+    pub fn result_type(&self) -> Type {
+        match self.op {
+            UnopKind::Length | UnopKind::Negate => makers::number(self.span),
+            UnopKind::Not => makers::bool(self.span),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IfElse<'a> {
+    pub span: Span,
+    /// Condition and value of every branch except the `else` branch.
+    pub branches: Vec<(Expr<'a>, Expr<'a>)>,
+    pub else_value: Box<Expr<'a>>,
+    pub typ: Type,
+    pub node_id: Id<&'a dyn Node>,
+}
+
+fn has_nil(typ: &Type) -> bool {
+    match typ {
+        Type::Literal(variants::Literal {
+            typ: variants::LiteralType::Nil,
+            ..
+        }) => true,
+        Type::Union(union) => union.members.iter().any(has_nil),
+        _ => false,
+    }
+}
+
+/// Combines the types of the values into a union,
+/// the same types are only added once.
+fn values_type<'a, 'b: 'a>(values: impl Iterator<Item = &'a Expr<'b>>, span: Span) -> Type {
+    let mut members: Vec<Type> = Vec::new();
+    for value in values {
+        // only the first value is taken from multiple values
+        let typ = match value.typ() {
+            Type::Tuple(tuple) => match tuple.members.first() {
+                Some(first) => first.clone(),
+                None => makers::nil(value.span()),
+            },
+            Type::Union(union) => {
+                for member in union.members.iter() {
+                    if !members.contains(member) {
+                        members.push(member.clone());
+                    }
+                }
+                continue;
+            }
+            typ => typ.clone(),
+        };
+        if !members.contains(&typ) {
+            members.push(typ);
+        }
+    }
+
+    if members.len() == 1 {
+        members.pop().unwrap()
+    } else {
+        Type::Union(variants::Union { span, members })
+    }
+}
+
+impl<'a> IfElse<'a> {
+    /// Combines the types of every branch value into a union,
+    /// the same types are only added once.
+    pub fn branches_type(&self) -> Type {
+        let values = self
+            .branches
+            .iter()
+            .map(|(_, value)| value)
+            .chain(std::iter::once(self.else_value.as_ref()));
+        values_type(values, self.span)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct InterpolatedStr<'a> {
+    pub span: Span,
+    pub exprs: Vec<Expr<'a>>,
+    pub typ: Type,
+    pub node_id: Id<&'a dyn Node>,
+}
+
 #[derive(Debug, Clone)]
 pub struct Literal<'a> {
     pub span: Span,
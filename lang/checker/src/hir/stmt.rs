@@ -8,6 +8,7 @@ pub enum LastStmt<'a> {
     None,
     Return(Return<'a>),
     Break(Span, Id<&'a dyn Node>),
+    Continue(Span, Id<&'a dyn Node>),
 }
 
 #[derive(Debug, Clone)]
@@ -20,10 +21,96 @@ pub struct Return<'a> {
 
 #[derive(Debug, Clone)]
 pub enum Stmt<'a> {
+    Assign(Assign<'a>),
     Call(Suffixed<'a>),
+    CompoundAssign(CompoundAssign<'a>),
+    Declare(Declare<'a>),
+    Do(Block<'a>),
+    GenericFor(GenericFor<'a>),
+    If(If<'a>),
     Library(LibraryExpr<'a>),
     LocalAssign(LocalAssign<'a>),
+    NumericFor(NumericFor<'a>),
+    Repeat(Loop<'a>),
     TypeDeclaration(TypeDeclaration<'a>),
+    While(Loop<'a>),
+}
+
+/// Assignment to variables or members like `a, b.c = 1, 2`, the
+/// `function a.b() end` statement is assigned to `a.b` as well.
+#[derive(Debug, Clone)]
+pub struct Assign<'a> {
+    /// Targets with the type that can be assigned to them.
+    pub targets: Vec<(Expr<'a>, Type)>,
+    pub exprs: Vec<Expr<'a>>,
+    pub span: Span,
+    pub node_id: Id<&'a dyn Node>,
+}
+
+#[derive(Debug, Clone)]
+pub struct If<'a> {
+    /// Condition and block of every branch except the `else` block.
+    pub branches: Vec<(Expr<'a>, Block<'a>)>,
+    pub else_block: Option<Block<'a>>,
+    pub span: Span,
+    pub node_id: Id<&'a dyn Node>,
+}
+
+/// `while` and `repeat` loops.
+#[derive(Debug, Clone)]
+pub struct Loop<'a> {
+    pub condition: Expr<'a>,
+    pub block: Block<'a>,
+    pub span: Span,
+    pub node_id: Id<&'a dyn Node>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NumericFor<'a> {
+    pub variable: Id<Symbol>,
+    pub start: Expr<'a>,
+    pub end: Expr<'a>,
+    pub step: Option<Expr<'a>>,
+    pub block: Block<'a>,
+    pub span: Span,
+    pub node_id: Id<&'a dyn Node>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GenericFor<'a> {
+    pub variables: Vec<Id<Symbol>>,
+    pub exprs: Vec<Expr<'a>>,
+    pub block: Block<'a>,
+    pub span: Span,
+    pub node_id: Id<&'a dyn Node>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeclareKind {
+    Function,
+    Local,
+    Module,
+}
+
+/// `declare` statement of a declaration file, its symbol is
+/// merged into every module (or `require` if it is a module).
+#[derive(Debug, Clone)]
+pub struct Declare<'a> {
+    pub kind: DeclareKind,
+    pub name: String,
+    pub typ: Type,
+    pub symbol: Id<Symbol>,
+    pub span: Span,
+    pub node_id: Id<&'a dyn Node>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CompoundAssign<'a> {
+    pub target: Expr<'a>,
+    /// `target op value` which is assigned back to the target.
+    pub value: Binary<'a>,
+    pub span: Span,
+    pub node_id: Id<&'a dyn Node>,
 }
 
 #[derive(Debug, Clone)]
@@ -48,9 +135,13 @@ impl PartialEq for TypeParameter {
 #[derive(Debug, Clone)]
 pub struct TypeDeclaration<'a> {
     pub name: String,
+    pub exported: bool,
     pub parameters: Option<Vec<TypeParameter>>,
     pub value: Type,
+    /// The value as it is written before it gets resolved
+    pub declared: Type,
     pub symbol: Id<Symbol>,
+    pub span: Span,
     pub node_id: Id<&'a dyn Node>,
 }
 
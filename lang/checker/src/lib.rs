@@ -6,6 +6,7 @@
 
 mod analyzer;
 mod context;
+mod declaration;
 mod diagnostics;
 mod resolver;
 mod transformer;
@@ -14,6 +15,7 @@ mod utils;
 pub mod hir;
 pub use analyzer::*;
 pub use context::*;
+pub use declaration::*;
 pub use diagnostics::*;
 pub use resolver::*;
 pub use transformer::*;
@@ -60,6 +60,15 @@ pub enum ResolveError {
 
     #[error("{base} has no parameters")]
     TypeHasNoParameters { span: Span, base: String },
+
+    #[error("Cannot find module {name}")]
+    ModuleNotFound { span: Span, name: String },
+
+    #[error("Cyclic require: {cycle}")]
+    RequireCycle { span: Span, cycle: String },
+
+    #[error("{name} cannot be exported outside of the module scope")]
+    NestedTypeExport { span: Span, name: String },
 }
 
 impl ResolveError {
@@ -79,6 +88,9 @@ impl ResolveError {
             ResolveError::MissingTypeArgument { span, .. } => *span,
             ResolveError::MissingArgument { span, .. } => *span,
             ResolveError::TypeHasNoParameters { span, .. } => *span,
+            ResolveError::ModuleNotFound { span, .. } => *span,
+            ResolveError::RequireCycle { span, .. } => *span,
+            ResolveError::NestedTypeExport { span, .. } => *span,
         }
     }
 }
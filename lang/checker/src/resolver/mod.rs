@@ -9,6 +9,7 @@ use id_arena::Id;
 use salite_common::{dictionary::Dictionary, memory::SafePtr};
 
 mod errors;
+mod module;
 mod nodes;
 
 pub use errors::*;
@@ -44,6 +45,9 @@ pub struct Resolver<'a, 'b> {
     pub(crate) env_ctx: SafePtr<EnvContext<'a, 'b>>,
     pub(crate) type_vars: Dictionary<String, Type>,
     pub(crate) type_stack: Vec<Id<Symbol>>,
+    /// Variables whose types are being resolved, to catch
+    /// `typeof` types that refer to themselves.
+    pub(crate) variable_stack: Vec<Id<Symbol>>,
 }
 
 impl<'a, 'b> Resolver<'a, 'b> {
@@ -128,23 +132,46 @@ impl<'a, 'b> Resolver<'a, 'b> {
                         typ: Box::new(self.resolve_type_inner(&param.typ)?),
                     });
                 }
+                let mut type_parameters = None;
+                if let Some(params) = &node.type_parameters {
+                    let mut list = Vec::new();
+                    for param in params.iter() {
+                        let mut param = param.clone();
+                        if let Some(explicit) = &param.explicit {
+                            param.explicit = Some(self.resolve_type_inner(explicit)?);
+                        }
+                        if let Some(default) = &param.default {
+                            param.default = Some(self.resolve_type_inner(default)?);
+                        }
+                        list.push(param);
+                    }
+                    type_parameters = Some(list);
+                }
                 Ok(Type::Function(type_variants::Function {
                     span: node.span,
+                    type_parameters,
                     parameters,
                     varidiac_param,
                     return_type: Box::new(self.resolve_type_inner(&node.return_type)?),
                 }))
             }
-            Type::Unresolved(info) => self.resolve_type_inner(
-                &self
-                    .ctx
-                    .symbols
-                    .get(info.symbol)
-                    .unwrap()
-                    .get_type()
-                    .expect("Expected type")
-                    .clone(),
-            ),
+            Type::Unresolved(info) => {
+                let symbol = self.ctx.symbols.get(info.symbol).unwrap();
+                if self.variable_stack.contains(&info.symbol) {
+                    return Err(ResolveError::RecursiveType {
+                        name: match &symbol.kind {
+                            crate::SymbolKind::BlockVariable(var) => var.name.to_string(),
+                            _ => "<unknown>".to_string(),
+                        },
+                        span: info.span,
+                    });
+                }
+                let typ = symbol.get_type().expect("Expected type").clone();
+                self.variable_stack.push(info.symbol);
+                let result = self.resolve_type_inner(&typ);
+                self.variable_stack.pop();
+                result
+            }
             // Type::CallProcrastinated(id, ..) => {
             //     match &self.binder.symbols.get(*id).unwrap().typ.as_ref().unwrap() {
             //         Type::Function(info) => self.solve_type(&info.return_type.clone()),
@@ -263,8 +290,12 @@ impl<'a, 'b> Resolver<'a, 'b> {
                 }
                 sym_typ.parameters.as_mut().unwrap()
             }
-            crate::SymbolKind::TypeParameter(.., a) => {
+            crate::SymbolKind::TypeParameter(_, a, generic) => {
                 self.type_stack.pop();
+                // it is solved once the generic function is called
+                if *generic {
+                    return Ok(Type::Reference(typ.clone()));
+                }
                 return Ok(a.clone());
             }
             _ => {
@@ -335,7 +366,7 @@ impl<'a, 'b> Resolver<'a, 'b> {
     ) -> ResolveResult {
         let mut resolver = unsafe {
             Self::from_ctx_ptr(
-                SafePtr::from_ptr((result.ctx.borrow() as *const ModuleContext<'a, 'b>).as_mut()),
+                SafePtr::from_ptr((result.ctx.borrow() as *const ModuleContext<'a, 'b>).cast_mut()),
                 env_ctx,
             )
         };
@@ -359,6 +390,7 @@ impl<'a, 'b> Resolver<'a, 'b> {
             ctx,
             env_ctx,
             type_stack: Vec::new(),
+            variable_stack: Vec::new(),
             type_vars: Dictionary::new(),
         }
     }
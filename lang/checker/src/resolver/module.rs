@@ -0,0 +1,281 @@
+use super::*;
+use crate::{ModuleSymbol, SymbolKind, TypeAliasSymbol};
+use salite_ast::Span;
+
+/// Symbols of the other module and their copies in this module.
+type ImportedSymbols = Vec<(Id<Symbol>, Id<Symbol>)>;
+
+impl<'a, 'b> Resolver<'a, 'b> {
+    /// Makes sure the required module exists and it doesn't
+    /// require the module that requires it.
+    pub(crate) fn check_required_module(&self, info: &ModuleSymbol, span: Span) -> ResolveResult {
+        let exists = match &info.path {
+            Some(path) => self.env_ctx.get_module_by_file(path).is_some(),
+            None => false,
+        };
+        if !exists {
+            return Err(ResolveError::ModuleNotFound {
+                span,
+                name: info.name.to_string(),
+            });
+        }
+        if let Some(from) = &self.ctx.file_path {
+            if let Some(chain) = self.env_ctx.find_require_cycle(from, info) {
+                return Err(ResolveError::RequireCycle {
+                    span,
+                    cycle: chain.join(" -> "),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Resolves the type of what the module returns, it has to be done
+    /// before the module is shared because resolving changes its symbols.
+    /// Only the first value is given from `require`.
+    pub(crate) fn module_return_type(
+        ctx: &mut ModuleContext<'a, 'b>,
+        file: &hir::File<'b>,
+    ) -> Option<Type> {
+        let typ = match &file.block.last_stmt {
+            hir::LastStmt::Return(node) => match &node.concluding_typ {
+                Type::Tuple(tuple) => tuple.members.first().cloned(),
+                Type::Literal(type_variants::Literal {
+                    typ: type_variants::LiteralType::Void,
+                    ..
+                }) => None,
+                typ => Some(typ.clone()),
+            },
+            _ => None,
+        }?;
+
+        // errors inside of the required module are reported
+        // once that module is resolved
+        let env = ctx.env.clone();
+        let mut resolver = unsafe { Self::from_ctx_ptr(SafePtr::from_ptr(ctx as *mut _), env) };
+        Some(
+            resolver
+                .resolve_type(&typ)
+                .unwrap_or_else(|_| types::makers::any(typ.span())),
+        )
+    }
+
+    /// Gets the type of what the required module returns and
+    /// copies it into the module of this resolver.
+    pub(crate) fn required_module_type(
+        &mut self,
+        module: &ModuleResult<'a, 'b>,
+        span: Span,
+    ) -> Type {
+        // Lua gives `true` if the module returns nothing
+        let typ = module
+            .return_type
+            .clone()
+            .unwrap_or(types::makers::singleton(
+                span,
+                type_variants::LiteralValue::Bool(true),
+            ));
+        self.import_type(&module.ctx, &typ, &mut Vec::new())
+    }
+
+    /// Copies the type alias that the required module exports
+    /// as `name` into the module of this resolver.
+    pub(crate) fn exported_type_symbol(
+        &mut self,
+        module: &ModuleResult<'a, 'b>,
+        name: &str,
+    ) -> Option<Id<Symbol>> {
+        let symbol_id = *module.ctx.exported_types.get(&name.to_string())?;
+        Some(self.import_symbol(&module.ctx, symbol_id, &mut Vec::new()))
+    }
+
+    /// Copies the types of the variables and functions that the
+    /// declaration file declares into the module of this resolver.
+    pub(crate) fn declared_globals(
+        &mut self,
+        module: &ModuleResult<'a, 'b>,
+    ) -> Vec<(String, Type)> {
+        // declaration files that return a value are modules, their
+        // declarations are only given from `require`
+        if matches!(module.file.block.last_stmt, hir::LastStmt::Return(..)) {
+            return Vec::new();
+        }
+
+        // globals may refer to the same type alias
+        let mut imported = Vec::new();
+        let mut globals = Vec::new();
+        for (name, symbol_id) in module.ctx.globals.iter() {
+            let typ = match module
+                .ctx
+                .symbols
+                .get(*symbol_id)
+                .and_then(|v| v.get_type())
+            {
+                Some(typ) => self.import_type(&module.ctx, typ, &mut imported),
+                None => types::makers::any(Span::invalid()),
+            };
+            globals.push((name.to_string(), typ));
+        }
+        globals
+    }
+
+    /// Copies the type of `declare module "name"` from the
+    /// declaration file into the module of this resolver.
+    pub(crate) fn declared_module_type(
+        &mut self,
+        module: &ModuleResult<'a, 'b>,
+        name: &str,
+    ) -> Option<Type> {
+        let symbol_id = *module.ctx.declared_modules.get(&name.to_string())?;
+        let typ = module.ctx.symbols.get(symbol_id)?.get_type()?;
+        Some(self.import_type(&module.ctx, typ, &mut Vec::new()))
+    }
+
+    fn import_symbol(
+        &mut self,
+        from: &ModuleContext<'a, 'b>,
+        id: Id<Symbol>,
+        imported: &mut ImportedSymbols,
+    ) -> Id<Symbol> {
+        if let Some((_, new_id)) = imported.iter().find(|(v, _)| *v == id) {
+            return *new_id;
+        }
+
+        // the symbol may refer to itself so it has to exist first
+        let new_id = self.ctx.symbols.alloc(Symbol {
+            definitions: Vec::new(),
+            kind: SymbolKind::Value(types::makers::any(Span::invalid())),
+        });
+        imported.push((id, new_id));
+
+        let symbol = from.symbols.get(id).unwrap();
+        let kind = match &symbol.kind {
+            SymbolKind::TypeAlias(alias) => SymbolKind::TypeAlias(TypeAliasSymbol {
+                name: alias.name.to_string(),
+                typ: self.import_type(from, &alias.typ, imported),
+                intrinsic: alias.intrinsic,
+                parameters: alias.parameters.as_ref().map(|params| {
+                    params
+                        .iter()
+                        .map(|param| hir::TypeParameter {
+                            explicit: param
+                                .explicit
+                                .as_ref()
+                                .map(|v| self.import_type(from, v, imported)),
+                            default: param
+                                .default
+                                .as_ref()
+                                .map(|v| self.import_type(from, v, imported)),
+                            ..param.clone()
+                        })
+                        .collect()
+                }),
+            }),
+            SymbolKind::TypeParameter(name, typ, generic) => SymbolKind::TypeParameter(
+                name.to_string(),
+                self.import_type(from, typ, imported),
+                *generic,
+            ),
+            _ => SymbolKind::Value(match symbol.get_type() {
+                Some(typ) => self.import_type(from, typ, imported),
+                None => types::makers::any(Span::invalid()),
+            }),
+        };
+        self.ctx.symbols.get_mut(new_id).unwrap().kind = kind;
+        new_id
+    }
+
+    fn import_table(
+        &mut self,
+        from: &ModuleContext<'a, 'b>,
+        tbl: &type_variants::Table,
+        imported: &mut ImportedSymbols,
+    ) -> type_variants::Table {
+        let mut entries = Dictionary::new();
+        for (key, value) in tbl.entries.iter() {
+            let key = match key {
+                type_variants::TableFieldKey::Computed(typ, span) => {
+                    type_variants::TableFieldKey::Computed(
+                        self.import_type(from, typ, imported),
+                        *span,
+                    )
+                }
+                _ => key.clone(),
+            };
+            entries.insert(key, self.import_type(from, value, imported));
+        }
+        type_variants::Table {
+            span: tbl.span,
+            entries,
+            is_metatable: tbl.is_metatable,
+            metatable: tbl
+                .metatable
+                .as_ref()
+                .map(|v| Box::new(self.import_table(from, v, imported))),
+        }
+    }
+
+    /// Copies a resolved type from the other module, symbols
+    /// that are referred by the type are copied into this module.
+    fn import_type(
+        &mut self,
+        from: &ModuleContext<'a, 'b>,
+        typ: &Type,
+        imported: &mut ImportedSymbols,
+    ) -> Type {
+        let mut import_all = |members: &[Type]| -> Vec<Type> {
+            members
+                .iter()
+                .map(|v| self.import_type(from, v, imported))
+                .collect()
+        };
+        match typ {
+            Type::Any(..) | Type::Literal(..) | Type::Unknown(..) => typ.clone(),
+            Type::Reference(info) => Type::Reference(type_variants::Reference {
+                arguments: info.arguments.as_ref().map(|v| import_all(v)),
+                symbol: self.import_symbol(from, info.symbol, imported),
+                ..info.clone()
+            }),
+            Type::Recursive(info) => Type::Recursive(type_variants::Recursive {
+                span: info.span,
+                symbol: self.import_symbol(from, info.symbol, imported),
+            }),
+            Type::Intersection(node) => Type::Intersection(type_variants::Intersection {
+                span: node.span,
+                members: import_all(&node.members),
+            }),
+            Type::Union(node) => Type::Union(type_variants::Union {
+                span: node.span,
+                members: import_all(&node.members),
+            }),
+            Type::Tuple(node) => Type::Tuple(type_variants::Tuple {
+                span: node.span,
+                members: import_all(&node.members),
+            }),
+            Type::Table(tbl) => Type::Table(self.import_table(from, tbl, imported)),
+            Type::Function(info) => {
+                let mut info = info.clone();
+                for param in info.parameters.iter_mut() {
+                    param.typ = self.import_type(from, &param.typ, imported);
+                }
+                if let Some(param) = info.varidiac_param.as_mut() {
+                    *param.typ = self.import_type(from, &param.typ, imported);
+                }
+                for param in info.type_parameters.iter_mut().flatten() {
+                    param.explicit = param
+                        .explicit
+                        .as_ref()
+                        .map(|v| self.import_type(from, v, imported));
+                    param.default = param
+                        .default
+                        .as_ref()
+                        .map(|v| self.import_type(from, v, imported));
+                }
+                *info.return_type = self.import_type(from, &info.return_type, imported);
+                Type::Function(info)
+            }
+            // it shouldn't happen after the module is resolved
+            Type::Unresolved(info) => types::makers::any(info.span),
+        }
+    }
+}
@@ -5,12 +5,21 @@ impl<'a, 'b> ResolveMut<'a, 'b> for hir::SuffixKind<'b> {
 
     fn resolve(&mut self, resolver: &mut Resolver<'a, 'b>) -> ResolveResult<Self::Output> {
         match self {
-            hir::SuffixKind::Call(args) => {
+            hir::SuffixKind::Call(args, _) => {
                 for arg in args.iter_mut() {
                     arg.resolve(resolver)?;
                 }
                 Ok(())
             }
+            hir::SuffixKind::Computed(index, typ) => {
+                index.resolve(resolver)?;
+                *typ = typ.resolve(resolver)?;
+                Ok(())
+            }
+            hir::SuffixKind::Method(_, typ) | hir::SuffixKind::Name(_, typ) => {
+                *typ = typ.resolve(resolver)?;
+                Ok(())
+            }
         }
     }
 }
@@ -20,7 +29,21 @@ impl<'a, 'b> ResolveMut<'a, 'b> for hir::Suffixed<'b> {
 
     fn resolve(&mut self, resolver: &mut Resolver<'a, 'b>) -> ResolveResult<Self::Output> {
         self.base.resolve(resolver)?;
-        self.kind.resolve(resolver)
+
+        // the base type may be resolved into a table type by now
+        let span = self.span;
+        if let hir::SuffixKind::Method(name, typ) | hir::SuffixKind::Name(name, typ) =
+            &mut self.kind
+        {
+            *typ = hir::Suffixed::member_type(self.base.typ(), name, span);
+        }
+        self.kind.resolve(resolver)?;
+
+        // type arguments are inferred again with the resolved types
+        if let hir::SuffixKind::Call(args, typ) = &mut self.kind {
+            *typ = hir::Suffixed::call_type(&self.base, args);
+        }
+        Ok(())
     }
 }
 
@@ -39,9 +62,28 @@ impl<'a, 'b> ResolveMut<'a, 'b> for hir::Suffixed<'b> {
 impl<'a, 'b> ResolveMut<'a, 'b> for hir::LibraryExpr<'b> {
     type Output = ();
 
-    fn resolve(&mut self, _: &mut Resolver<'a, 'b>) -> ResolveResult<Self::Output> {
-        // TODO(memothelemo): later
-        todo!()
+    fn resolve(&mut self, resolver: &mut Resolver<'a, 'b>) -> ResolveResult<Self::Output> {
+        match self {
+            hir::LibraryExpr::Require(node) => {
+                if let Some(crate::SymbolKind::Module(info)) = resolver
+                    .ctx
+                    .symbols
+                    .get(node.module_symbol)
+                    .map(|v| &v.kind)
+                {
+                    resolver.check_required_module(&info.clone(), node.path.span())?;
+                }
+                node.path.resolve(resolver)?;
+                node.typ = node.typ.resolve(resolver)?;
+                Ok(())
+            }
+            hir::LibraryExpr::SetMetatable(node) => {
+                node.base_table.resolve(resolver)?;
+                node.metatable.resolve(resolver)?;
+                node.return_type = node.return_type.resolve(resolver)?;
+                Ok(())
+            }
+        }
     }
 }
 
@@ -58,6 +100,54 @@ impl<'a, 'b> ResolveMut<'a, 'b> for hir::Function<'b> {
     }
 }
 
+impl<'a, 'b> ResolveMut<'a, 'b> for hir::Binary<'b> {
+    type Output = ();
+
+    fn resolve(&mut self, resolver: &mut Resolver<'a, 'b>) -> ResolveResult<Self::Output> {
+        self.left.resolve(resolver)?;
+        self.right.resolve(resolver)?;
+
+        // operand types are resolved by now
+        self.typ = self.result_type();
+        Ok(())
+    }
+}
+
+impl<'a, 'b> ResolveMut<'a, 'b> for hir::Unary<'b> {
+    type Output = ();
+
+    fn resolve(&mut self, resolver: &mut Resolver<'a, 'b>) -> ResolveResult<Self::Output> {
+        self.expr.resolve(resolver)
+    }
+}
+
+impl<'a, 'b> ResolveMut<'a, 'b> for hir::IfElse<'b> {
+    type Output = ();
+
+    fn resolve(&mut self, resolver: &mut Resolver<'a, 'b>) -> ResolveResult<Self::Output> {
+        for (condition, value) in self.branches.iter_mut() {
+            condition.resolve(resolver)?;
+            value.resolve(resolver)?;
+        }
+        self.else_value.resolve(resolver)?;
+
+        // branch types are resolved by now
+        self.typ = self.branches_type();
+        Ok(())
+    }
+}
+
+impl<'a, 'b> ResolveMut<'a, 'b> for hir::InterpolatedStr<'b> {
+    type Output = ();
+
+    fn resolve(&mut self, resolver: &mut Resolver<'a, 'b>) -> ResolveResult<Self::Output> {
+        for expr in self.exprs.iter_mut() {
+            expr.resolve(resolver)?;
+        }
+        Ok(())
+    }
+}
+
 impl<'a, 'b> ResolveMut<'a, 'b> for hir::Literal<'b> {
     type Output = ();
 
@@ -99,12 +189,16 @@ impl<'a, 'b> ResolveMut<'a, 'b> for hir::Expr<'b> {
 
     fn resolve(&mut self, resolver: &mut Resolver<'a, 'b>) -> ResolveResult<Self::Output> {
         match self {
+            hir::Expr::Binary(node) => node.resolve(resolver),
             hir::Expr::Suffixed(node) => node.resolve(resolver),
             hir::Expr::Function(node) => node.resolve(resolver),
+            hir::Expr::IfElse(node) => node.resolve(resolver),
+            hir::Expr::InterpolatedStr(node) => node.resolve(resolver),
             hir::Expr::Library(node) => node.resolve(resolver),
             hir::Expr::Literal(node) => node.resolve(resolver),
             hir::Expr::TypeAssertion(node) => node.resolve(resolver),
             hir::Expr::Table(node) => node.resolve(resolver),
+            hir::Expr::Unary(node) => node.resolve(resolver),
         }
     }
 }
@@ -0,0 +1,49 @@
+use super::*;
+
+impl<'a, 'b> ResolveMut<'a, 'b> for hir::If<'b> {
+    type Output = ();
+
+    fn resolve(&mut self, resolver: &mut Resolver<'a, 'b>) -> ResolveResult<Self::Output> {
+        for (condition, block) in self.branches.iter_mut() {
+            condition.resolve(resolver)?;
+            block.resolve(resolver)?;
+        }
+        if let Some(block) = self.else_block.as_mut() {
+            block.resolve(resolver)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, 'b> ResolveMut<'a, 'b> for hir::Loop<'b> {
+    type Output = ();
+
+    fn resolve(&mut self, resolver: &mut Resolver<'a, 'b>) -> ResolveResult<Self::Output> {
+        self.condition.resolve(resolver)?;
+        self.block.resolve(resolver)
+    }
+}
+
+impl<'a, 'b> ResolveMut<'a, 'b> for hir::NumericFor<'b> {
+    type Output = ();
+
+    fn resolve(&mut self, resolver: &mut Resolver<'a, 'b>) -> ResolveResult<Self::Output> {
+        self.start.resolve(resolver)?;
+        self.end.resolve(resolver)?;
+        if let Some(step) = self.step.as_mut() {
+            step.resolve(resolver)?;
+        }
+        self.block.resolve(resolver)
+    }
+}
+
+impl<'a, 'b> ResolveMut<'a, 'b> for hir::GenericFor<'b> {
+    type Output = ();
+
+    fn resolve(&mut self, resolver: &mut Resolver<'a, 'b>) -> ResolveResult<Self::Output> {
+        for expr in self.exprs.iter_mut() {
+            expr.resolve(resolver)?;
+        }
+        self.block.resolve(resolver)
+    }
+}
@@ -0,0 +1,18 @@
+use super::*;
+
+impl<'a, 'b> ResolveMut<'a, 'b> for hir::Declare<'b> {
+    type Output = ();
+
+    fn resolve(&mut self, resolver: &mut Resolver<'a, 'b>) -> ResolveResult<Self::Output> {
+        let typ = self.typ.resolve(resolver)?;
+        let symbol = resolver.ctx.get_mut().symbols.get_mut(self.symbol).unwrap();
+        match &mut symbol.kind {
+            crate::SymbolKind::BlockVariable(var) => var.typ = typ.clone(),
+            crate::SymbolKind::Value(value) => *value = typ.clone(),
+            _ => {}
+        }
+
+        self.typ = typ;
+        Ok(())
+    }
+}
@@ -27,11 +27,14 @@ impl<'a, 'b> ResolveMut<'a, 'b> for hir::LocalAssign<'b> {
 
     fn resolve(&mut self, resolver: &mut Resolver<'a, 'b>) -> ResolveResult<Self::Output> {
         for var in self.variables.iter_mut() {
+            // `local x: typeof(x)` can't be resolved
+            resolver.variable_stack.push(var.name_symbol);
             var.explicit_type = if let Some(t) = &var.explicit_type {
                 Some(t.resolve(resolver)?)
             } else {
                 None
             };
+            resolver.variable_stack.pop();
             var.expr = if let Some(t) = &var.expr {
                 Some(t.resolve(resolver)?)
             } else {
@@ -55,3 +58,18 @@ impl<'a, 'b> ResolveMut<'a, 'b> for hir::LocalAssign<'b> {
         Ok(())
     }
 }
+
+impl<'a, 'b> ResolveMut<'a, 'b> for hir::Assign<'b> {
+    type Output = ();
+
+    fn resolve(&mut self, resolver: &mut Resolver<'a, 'b>) -> ResolveResult<Self::Output> {
+        for (target, typ) in self.targets.iter_mut() {
+            target.resolve(resolver)?;
+            *typ = typ.resolve(resolver)?;
+        }
+        for expr in self.exprs.iter_mut() {
+            expr.resolve(resolver)?;
+        }
+        Ok(())
+    }
+}
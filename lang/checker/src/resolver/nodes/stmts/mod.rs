@@ -1,5 +1,7 @@
 use super::*;
 
+mod control_flow;
+mod declare;
 mod local_assign;
 mod type_declare;
 
@@ -25,9 +27,20 @@ impl<'a, 'b> ResolveMut<'a, 'b> for hir::Stmt<'b> {
 
     fn resolve(&mut self, resolver: &mut Resolver<'a, 'b>) -> ResolveResult<Self::Output> {
         match self {
+            hir::Stmt::Assign(node) => node.resolve(resolver),
             hir::Stmt::Call(node) => node.resolve(resolver),
+            hir::Stmt::CompoundAssign(node) => {
+                node.target.resolve(resolver)?;
+                node.value.resolve(resolver)
+            }
+            hir::Stmt::Declare(node) => node.resolve(resolver),
+            hir::Stmt::Do(node) => node.resolve(resolver),
+            hir::Stmt::GenericFor(node) => node.resolve(resolver),
+            hir::Stmt::If(node) => node.resolve(resolver),
             hir::Stmt::Library(node) => node.resolve(resolver),
             hir::Stmt::LocalAssign(node) => node.resolve(resolver),
+            hir::Stmt::NumericFor(node) => node.resolve(resolver),
+            hir::Stmt::Repeat(node) | hir::Stmt::While(node) => node.resolve(resolver),
             hir::Stmt::TypeDeclaration(node) => node.resolve(resolver),
         }
     }
@@ -4,6 +4,13 @@ impl<'a, 'b> ResolveMut<'a, 'b> for hir::TypeDeclaration<'b> {
     type Output = ();
 
     fn resolve(&mut self, resolver: &mut Resolver<'a, 'b>) -> ResolveResult<Self::Output> {
+        if self.exported && resolver.ctx.exported_types.get(&self.name) != Some(&self.symbol) {
+            return Err(ResolveError::NestedTypeExport {
+                span: self.span,
+                name: self.name.to_string(),
+            });
+        }
+
         if let Some(params) = &mut self.parameters {
             for param in params.iter_mut() {
                 param.explicit = if let Some(explicit) = &param.explicit {
@@ -6,7 +6,8 @@ use id_arena::Id;
 use salite_ast::Span;
 
 mod nodes;
-use salite_common::memory::SafePtr;
+mod prelude;
+use salite_common::{dictionary::Dictionary, memory::SafePtr};
 
 pub trait Transform<'a, 'b> {
     type Output: 'b;
@@ -19,6 +20,16 @@ pub struct Transformer<'a, 'b> {
     pub(crate) ctx: SafePtr<ModuleContext<'a, 'b>>,
     pub(crate) stack: Vec<Id<Scope>>,
     pub(crate) type_stack: Vec<String>,
+    /// Types of the required modules, they are only copied once.
+    pub(crate) required: Vec<(std::path::PathBuf, Type)>,
+    /// Variables that hold a required module and their module symbols.
+    pub(crate) module_variables: Dictionary<Id<Symbol>, Id<Symbol>>,
+    /// Explicit types of the local variables, values assigned
+    /// to them later on are checked against it.
+    pub(crate) declared_types: Dictionary<Id<Symbol>, Type>,
+    /// Exported type aliases of the required modules, they are
+    /// only copied once.
+    pub(crate) exported_types: Vec<(std::path::PathBuf, String, Id<Symbol>)>,
 }
 
 impl<'a, 'b> Transformer<'a, 'b> {
@@ -30,8 +41,18 @@ impl<'a, 'b> Transformer<'a, 'b> {
             ctx,
             stack: Vec::new(),
             type_stack: Vec::new(),
+            required: Vec::new(),
+            module_variables: Dictionary::new(),
+            declared_types: Dictionary::new(),
+            exported_types: Vec::new(),
         };
         transformer.init_intrinsics();
+        transformer.init_globals();
+        transformer.init_prelude();
+
+        // the module can declare its own variables with
+        // the same names as the globals
+        transformer.push_scope(ScopeKind::Module);
 
         hir::File {
             block: file.block().transform(&mut transformer),
@@ -58,6 +79,7 @@ impl<'a, 'b> Transformer<'a, 'b> {
             as type = {
                 "any" => types::makers::any(Span::invalid()),
                 "bool" => types::makers::bool(Span::invalid()),
+                "nil" => types::makers::nil(Span::invalid()),
                 "number" => types::makers::number(Span::invalid()),
                 "string" => types::makers::string(Span::invalid()),
                 "unknown" => types::makers::unknown(Span::invalid()),
@@ -118,6 +140,60 @@ impl<'a, 'b> Transformer<'a, 'b> {
         }
     }
 
+    /// Declares the variables and functions of every declaration file,
+    /// the declaration files that are being transformed are skipped.
+    pub(crate) fn init_globals(&mut self) {
+        let mut resolver =
+            unsafe { Resolver::from_ctx_ptr(self.ctx.clone(), self.ctx.env.clone()) };
+        let mut globals = Vec::new();
+        for path in self.ctx.env.declaration_paths() {
+            if let Some(module) = self.ctx.env.load_module(&path) {
+                globals.extend(resolver.declared_globals(module));
+            }
+        }
+        for (name, typ) in globals {
+            self.insert_variable(
+                &name,
+                SymbolKind::BlockVariable(BlockVariableSymbol {
+                    name: name.to_string(),
+                    typ,
+                    explicit: true,
+                }),
+                None,
+            );
+        }
+    }
+
+    /// Declares the standard library of the target Lua version. Globals
+    /// of the declaration files are declared first so they can replace it.
+    pub(crate) fn init_prelude(&mut self) {
+        let target = self.ctx.env.cfg().get().target;
+        // type parameters of the generic functions like `setmetatable`
+        let globals = prelude::globals(target, |name| {
+            let kind = SymbolKind::TypeParameter(
+                name.to_string(),
+                types::makers::any(Span::invalid()),
+                true,
+            );
+            let symbol = self.register_symbol(vec![Span::invalid()], kind);
+            types::makers::reference(Span::invalid(), symbol, name.to_string(), None)
+        });
+        for (name, typ) in globals {
+            if self.current_scope().vars.contains(&name.to_string()) {
+                continue;
+            }
+            self.insert_variable(
+                name,
+                SymbolKind::BlockVariable(BlockVariableSymbol {
+                    name: name.to_string(),
+                    typ,
+                    explicit: true,
+                }),
+                None,
+            );
+        }
+    }
+
     pub(crate) fn push_scope(&mut self, kind: ScopeKind) {
         let scope = Scope::new(kind, self.stack.last().cloned());
         let scope_id = self.ctx.scopes.alloc(scope);
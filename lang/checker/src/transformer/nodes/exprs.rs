@@ -2,6 +2,7 @@ use super::*;
 
 mod arys;
 mod assertion;
+mod if_else;
 
 #[macro_use]
 mod literal;
@@ -20,6 +21,7 @@ impl<'a, 'b> Transform<'a, 'b> for ast::Expr {
     fn transform(&'b self, tfmr: &mut Transformer<'a, 'b>) -> Self::Output {
         match self {
             ast::Expr::Binary(node) => node.transform(tfmr),
+            ast::Expr::IfElse(node) => node.transform(tfmr),
             ast::Expr::Literal(node) => node.transform(tfmr),
             ast::Expr::Paren(node) => node.transform(tfmr),
             ast::Expr::Suffixed(node) => node.transform(tfmr),
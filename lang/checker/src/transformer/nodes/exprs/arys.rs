@@ -1,19 +1,56 @@
 use super::*;
 
+/// Makes a binary expression out of the transformed operands,
+/// compound assignments use it for the value assigned back.
+pub fn transform_binary<'a, 'b>(
+    tfmr: &mut Transformer<'a, 'b>,
+    left: hir::Expr<'b>,
+    op: ast::BinopKind,
+    right: hir::Expr<'b>,
+    span: Span,
+    node: &'b dyn ast::Node,
+) -> hir::Binary<'b> {
+    let mut binary = hir::Binary {
+        left: Box::new(left),
+        op,
+        right: Box::new(right),
+        span,
+        typ: types::makers::any(span),
+        node_id: tfmr.ctx.nodes.alloc(node),
+    };
+    binary.typ = binary.result_type();
+    binary
+}
+
 impl<'a, 'b> Transform<'a, 'b> for ast::Binary {
     type Output = hir::Expr<'b>;
 
-    #[allow(unused)]
     fn transform(&'b self, tfmr: &mut Transformer<'a, 'b>) -> Self::Output {
-        todo!()
+        let left = self.left().transform(tfmr);
+        let right = self.right().transform(tfmr);
+        hir::Expr::Binary(transform_binary(
+            tfmr,
+            left,
+            self.op().kind.clone(),
+            right,
+            self.span(),
+            self,
+        ))
     }
 }
 
 impl<'a, 'b> Transform<'a, 'b> for ast::Unary {
     type Output = hir::Expr<'b>;
 
-    #[allow(unused)]
     fn transform(&'b self, tfmr: &mut Transformer<'a, 'b>) -> Self::Output {
-        todo!()
+        let mut unary = hir::Unary {
+            op: self.op().kind.clone(),
+            expr: Box::new(self.expr().transform(tfmr)),
+            span: self.span(),
+            typ: types::makers::any(self.span()),
+            node_id: tfmr.ctx.nodes.alloc(self),
+        };
+        unary.typ = unary.result_type();
+        hir::Expr::Unary(unary)
     }
 }
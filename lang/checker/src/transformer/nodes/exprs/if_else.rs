@@ -0,0 +1,30 @@
+use super::*;
+use crate::types::makers;
+use ast::SpannedNode;
+
+impl<'a, 'b> Transform<'a, 'b> for ast::IfElseExpr {
+    type Output = hir::Expr<'b>;
+
+    fn transform(&'b self, tfmr: &mut Transformer<'a, 'b>) -> Self::Output {
+        let mut branches = vec![(
+            self.condition().transform(tfmr),
+            self.value().transform(tfmr),
+        )];
+        for clause in self.elseifs().iter() {
+            branches.push((
+                clause.condition().transform(tfmr),
+                clause.value().transform(tfmr),
+            ));
+        }
+
+        let mut node = hir::IfElse {
+            span: self.span(),
+            branches,
+            else_value: Box::new(self.else_value().transform(tfmr)),
+            typ: makers::any(self.span()),
+            node_id: tfmr.ctx.nodes.alloc(self),
+        };
+        node.typ = node.branches_type();
+        hir::Expr::IfElse(node)
+    }
+}
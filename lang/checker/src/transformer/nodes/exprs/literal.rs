@@ -3,15 +3,26 @@ use std::borrow::Borrow;
 use super::*;
 
 mod function;
+mod interpolated_str;
 mod table;
 
 #[allow(unused)]
 pub use function::*;
+#[allow(unused)]
+pub use interpolated_str::*;
 use salite_common::dictionary::Dictionary;
 pub use table::*;
 
 #[macro_export]
 macro_rules! literal {
+    ($node:expr, $tfmr:expr, $self:expr) => {
+        hir::Expr::Literal(hir::Literal {
+            span: $node.span(),
+            typ: singleton_type($node),
+            symbol: None,
+            node_id: $tfmr.ctx.nodes.alloc($self),
+        })
+    };
     ($node:expr, $tfmr:expr, $self:expr, $ty:ident) => {
         hir::Expr::Literal(hir::Literal {
             span: $node.span(),
@@ -22,45 +33,66 @@ macro_rules! literal {
     };
 }
 
+/// Gets the singleton type of a string, number or boolean token.
+pub(crate) fn singleton_type(token: &ast::Token) -> types::Type {
+    let value = match token.ty() {
+        ast::TokenType::Number(_, value) => variants::LiteralValue::Number(value.to_string()),
+        ast::TokenType::Str(_, value) => variants::LiteralValue::String(value.to_string()),
+        ast::TokenType::Symbol(ast::SymbolType::True) => variants::LiteralValue::Bool(true),
+        ast::TokenType::Symbol(ast::SymbolType::False) => variants::LiteralValue::Bool(false),
+        _ => unreachable!(),
+    };
+    types::makers::singleton(token.span(), value)
+}
+
+/// Transforms a name into a variable reference, unknown
+/// variables are reported and typed as `any`.
+pub(crate) fn transform_name<'a, 'b>(
+    tfmr: &mut Transformer<'a, 'b>,
+    name: &ast::Token,
+    node: &'b dyn ast::Node,
+) -> hir::Expr<'b> {
+    let scope = tfmr.current_scope();
+    let real_name = name.ty().as_name();
+
+    let symbol = scope.search_variable(&tfmr.ctx, &real_name);
+    if let Some(symbol_id) = symbol {
+        let symbol = tfmr.ctx.symbols.get(symbol_id).unwrap();
+        hir::Expr::Literal(hir::Literal {
+            // meh?
+            typ: symbol.get_type().unwrap().clone(),
+            span: name.span(),
+            symbol: Some(symbol_id),
+            node_id: tfmr.ctx.nodes.alloc(node),
+        })
+    } else {
+        tfmr.ctx.diagnostics.push(Diagnostic::UnknownVariable {
+            name: real_name.clone(),
+            span: name.span(),
+        });
+        hir::Expr::Literal(hir::Literal {
+            typ: types::makers::any(name.span()),
+            span: name.span(),
+            symbol: Some(
+                tfmr.register_symbol(vec![name.span()], SymbolKind::UnknownVariable(real_name)),
+            ),
+            node_id: tfmr.ctx.nodes.alloc(node),
+        })
+    }
+}
+
 impl<'a, 'b> Transform<'a, 'b> for ast::Literal {
     type Output = hir::Expr<'b>;
 
     fn transform(&'b self, tfmr: &mut Transformer<'a, 'b>) -> Self::Output {
         match self {
-            ast::Literal::Bool(node) => literal!(node, tfmr, self, bool),
+            ast::Literal::Bool(node) => literal!(node, tfmr, self),
             ast::Literal::Function(node) => node.transform(tfmr),
-            ast::Literal::Name(node) => {
-                let scope = tfmr.current_scope();
-                let real_name = node.ty().as_name();
-
-                let symbol = scope.search_variable(&tfmr.ctx, &real_name);
-                if let Some(symbol_id) = symbol {
-                    let symbol = tfmr.ctx.symbols.get(symbol_id).unwrap();
-                    hir::Expr::Literal(hir::Literal {
-                        // meh?
-                        typ: symbol.get_type().unwrap().clone(),
-                        span: node.span(),
-                        symbol: Some(symbol_id),
-                        node_id: tfmr.ctx.nodes.alloc(self),
-                    })
-                } else {
-                    tfmr.ctx.diagnostics.push(Diagnostic::UnknownVariable {
-                        name: real_name,
-                        span: node.span(),
-                    });
-                    hir::Expr::Literal(hir::Literal {
-                        typ: types::makers::any(node.span()),
-                        span: node.span(),
-                        symbol: Some(
-                            tfmr.register_symbol(vec![node.span()], SymbolKind::UnknownVariable),
-                        ),
-                        node_id: tfmr.ctx.nodes.alloc(self),
-                    })
-                }
-            }
-            ast::Literal::Number(node) => literal!(node, tfmr, self, number),
+            ast::Literal::InterpolatedStr(node) => node.transform(tfmr),
+            ast::Literal::Name(node) => transform_name(tfmr, node, self),
+            ast::Literal::Number(node) => literal!(node, tfmr, self),
             ast::Literal::Nil(node) => literal!(node, tfmr, self, nil),
-            ast::Literal::Str(node) => literal!(node, tfmr, self, string),
+            ast::Literal::Str(node) => literal!(node, tfmr, self),
             ast::Literal::Table(node) => {
                 let mut fields = Vec::new();
                 let mut entries = Dictionary::new();
@@ -80,7 +112,7 @@ impl<'a, 'b> Transform<'a, 'b> for ast::Literal {
                             let real_name = match index.borrow() {
                                 ast::Expr::Literal(ast::Literal::Str(str)) => {
                                     Some(match str.ty() {
-                                        ast::TokenType::Str(contents) => contents.to_string(),
+                                        ast::TokenType::Str(_, value) => value.to_string(),
                                         _ => unreachable!(),
                                     })
                                 }
@@ -135,7 +167,23 @@ impl<'a, 'b> Transform<'a, 'b> for ast::Literal {
                     typ,
                 })
             }
-            ast::Literal::Varargs(_) => todo!(),
+            ast::Literal::Varargs(node) => {
+                // functions declare their varargs as `...`, the
+                // ones given to the module are typed as `any`
+                let symbol = tfmr
+                    .current_scope()
+                    .search_variable(&tfmr.ctx, &"...".to_string());
+                let typ = symbol
+                    .and_then(|id| tfmr.ctx.symbols.get(id))
+                    .and_then(|v| v.get_type().cloned())
+                    .unwrap_or(types::makers::any(node.span()));
+                hir::Expr::Literal(hir::Literal {
+                    span: node.span(),
+                    typ,
+                    symbol,
+                    node_id: tfmr.ctx.nodes.alloc(self),
+                })
+            }
         }
     }
 }
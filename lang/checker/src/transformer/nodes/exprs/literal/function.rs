@@ -6,12 +6,19 @@ pub(crate) fn transform_function_body<'a, 'b>(
     body: &'b ast::FunctionBody,
     span: Span,
     allocated_id: Id<&'b dyn ast::Node>,
+    self_type: Option<Type>,
 ) -> hir::Function<'b> {
     let mut parameters = Vec::new();
     let mut defaults = Vec::new();
 
     tfmr.push_scope(ScopeKind::Function);
 
+    let type_parameters = body.type_params().as_ref().map(|params| {
+        let params = transform_type_parameters(tfmr, params);
+        declare_type_parameters(tfmr, &params, true);
+        params
+    });
+
     let expected_type = body
         .return_type()
         .as_ref()
@@ -20,6 +27,22 @@ pub(crate) fn transform_function_body<'a, 'b>(
     let mut scope = tfmr.current_scope_mut();
     scope.expected_type = expected_type.clone();
 
+    // methods take the table as `self` before the other parameters
+    if let Some(typ) = self_type {
+        tfmr.insert_variable(
+            "self",
+            SymbolKind::FunctionParameter("self".to_string(), typ.clone(), false),
+            Some(span),
+        );
+        defaults.push(None);
+        parameters.push(variants::FunctionParameter {
+            optional: false,
+            span,
+            name: "self".to_string(),
+            typ,
+        });
+    }
+
     for param in body.params().iter() {
         let name = param.name.ty().as_name();
         let typ = param
@@ -30,14 +53,27 @@ pub(crate) fn transform_function_body<'a, 'b>(
 
         defaults.push(param.default.as_ref().map(|v| v.transform(tfmr)));
 
+        // defaulted parameters can be omitted at call sites
+        let optional = param.optional || param.default.is_some();
+
         tfmr.insert_variable(
             &name,
-            SymbolKind::FunctionParameter(name.to_string(), typ.clone(), param.optional),
+            SymbolKind::FunctionParameter(name.to_string(), typ.clone(), optional),
             Some(param.span),
         );
 
+        // `nil` gives the default value, the parameter is only
+        // `T` inside of the body since `nil` is replaced by then
+        let typ = match typ {
+            typ if param.default.is_none() || matches!(typ, Type::Any(..)) => typ,
+            typ => Type::Union(variants::Union {
+                span: param.span,
+                members: vec![typ, types::makers::nil(param.span)],
+            }),
+        };
+
         parameters.push(variants::FunctionParameter {
-            optional: param.optional,
+            optional,
             span: param.span,
             name,
             typ,
@@ -46,17 +82,22 @@ pub(crate) fn transform_function_body<'a, 'b>(
 
     let mut varidiac_param = None;
 
-    // TODO(memothelemo): Add support for varidiac parameters
     if let Some(varidiac) = body.varidiac() {
+        let typ = varidiac
+            .typ
+            .as_ref()
+            .map(|v| v.transform(tfmr))
+            .unwrap_or(types::makers::any(varidiac.span));
+
+        // `...` can't be a name, so it is declared as one
+        tfmr.insert_variable(
+            "...",
+            SymbolKind::FunctionParameter("...".to_string(), typ.clone(), true),
+            Some(varidiac.span),
+        );
         varidiac_param = Some(variants::VaridiacParameter {
             span: varidiac.span,
-            typ: Box::new(
-                varidiac
-                    .typ
-                    .as_ref()
-                    .map(|v| v.transform(tfmr))
-                    .unwrap_or(types::makers::any(varidiac.span)),
-            ),
+            typ: Box::new(typ),
         });
     }
 
@@ -66,6 +107,7 @@ pub(crate) fn transform_function_body<'a, 'b>(
         defaults,
         typ: Type::Function(variants::Function {
             span,
+            type_parameters,
             parameters,
             varidiac_param,
             return_type: Box::new(expected_type.unwrap_or(block.actual_type.clone())),
@@ -84,7 +126,7 @@ impl<'a, 'b> Transform<'a, 'b> for ast::FunctionExpr {
 
     fn transform(&'b self, tfmr: &mut Transformer<'a, 'b>) -> Self::Output {
         let id = tfmr.ctx.nodes.alloc(self);
-        let function = transform_function_body(tfmr, self.body(), self.span(), id);
+        let function = transform_function_body(tfmr, self.body(), self.span(), id, None);
         hir::Expr::Function(function)
     }
 }
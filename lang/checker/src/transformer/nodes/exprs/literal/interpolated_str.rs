@@ -0,0 +1,20 @@
+use super::*;
+
+impl<'a, 'b> Transform<'a, 'b> for ast::InterpolatedStr {
+    type Output = hir::Expr<'b>;
+
+    fn transform(&'b self, tfmr: &mut Transformer<'a, 'b>) -> Self::Output {
+        let exprs = self
+            .exprs()
+            .iter()
+            .map(|expr| expr.transform(tfmr))
+            .collect::<Vec<_>>();
+
+        hir::Expr::InterpolatedStr(hir::InterpolatedStr {
+            span: self.span(),
+            exprs,
+            typ: types::makers::string(self.span()),
+            node_id: tfmr.ctx.nodes.alloc(self),
+        })
+    }
+}
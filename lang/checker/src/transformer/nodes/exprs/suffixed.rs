@@ -9,9 +9,38 @@ impl<'a, 'b> Transform<'a, 'b> for ast::Suffixed {
     fn transform(&'b self, tfmr: &mut Transformer<'a, 'b>) -> Self::Output {
         match self.suffix() {
             ast::SuffixKind::Call(args) => visit_call_expr_inner(tfmr, self, args),
-            ast::SuffixKind::Computed(_) => todo!(),
-            ast::SuffixKind::Method(_) => todo!(),
-            ast::SuffixKind::Name(_) => todo!(),
+            ast::SuffixKind::Computed(index) => {
+                let base = self.base().transform(tfmr);
+                let index = index.transform(tfmr);
+                hir::Expr::Suffixed(hir::Suffixed {
+                    span: self.span(),
+                    base: Box::new(base),
+                    kind: hir::SuffixKind::Computed(
+                        Box::new(index),
+                        types::makers::any(self.span()),
+                    ),
+                })
+            }
+            ast::SuffixKind::Method(name) => {
+                let base = self.base().transform(tfmr);
+                let name = name.ty().as_name();
+                let typ = hir::Suffixed::member_type(base.typ(), &name, self.span());
+                hir::Expr::Suffixed(hir::Suffixed {
+                    span: self.span(),
+                    base: Box::new(base),
+                    kind: hir::SuffixKind::Method(name, typ),
+                })
+            }
+            ast::SuffixKind::Name(name) => {
+                let base = self.base().transform(tfmr);
+                let name = name.ty().as_name();
+                let typ = hir::Suffixed::member_type(base.typ(), &name, self.span());
+                hir::Expr::Suffixed(hir::Suffixed {
+                    span: self.span(),
+                    base: Box::new(base),
+                    kind: hir::SuffixKind::Name(name, typ),
+                })
+            }
         }
     }
 }
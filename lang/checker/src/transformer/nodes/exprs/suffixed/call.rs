@@ -1,5 +1,6 @@
 use super::*;
 use crate::literal;
+use std::path::{Path, PathBuf};
 
 #[macro_export]
 macro_rules! invalid_lib_use {
@@ -11,11 +12,118 @@ macro_rules! invalid_lib_use {
     };
 }
 
+/// Gets the string argument of `require("path")`, it is not
+/// a `require` call if `require` is a local variable.
+fn require_argument<'b>(
+    tfmr: &Transformer,
+    node: &'b ast::Suffixed,
+    args: &'b ast::Args,
+) -> Option<&'b ast::Token> {
+    match node.base().as_ref() {
+        ast::Expr::Literal(ast::Literal::Name(name)) if name.ty().as_name() == "require" => {}
+        _ => return None,
+    };
+    if tfmr
+        .current_scope()
+        .search_variable(&tfmr.ctx, &"require".to_string())
+        .is_some()
+    {
+        return None;
+    }
+    match args {
+        ast::Args::ExprList(list) if list.len() == 1 => match &list[0] {
+            ast::Expr::Literal(ast::Literal::Str(arg)) => Some(arg),
+            _ => None,
+        },
+        ast::Args::Str(arg) => Some(arg),
+        _ => None,
+    }
+}
+
+/// Gets the type of what the required module returns, the module is
+/// transformed first if it isn't yet. Missing modules and require
+/// cycles are typed as `any` and reported once it is resolved.
+fn required_module_type(tfmr: &mut Transformer, path: &Path, span: Span) -> Type {
+    if let Some((_, typ)) = tfmr.required.iter().find(|(v, _)| v == path) {
+        return typ.clone();
+    }
+
+    let mut resolver = unsafe { Resolver::from_ctx_ptr(tfmr.ctx.clone(), tfmr.ctx.env.clone()) };
+    let typ = match tfmr.ctx.env.load_module(path) {
+        Some(module) => resolver.required_module_type(module, span),
+        None => types::makers::any(span),
+    };
+    tfmr.required.push((path.to_path_buf(), typ.clone()));
+    typ
+}
+
+/// Gets the type of `declare module "name"` from one of the declaration
+/// files, the path of that declaration file is also given.
+fn declared_module_type(tfmr: &mut Transformer, name: &str) -> Option<(PathBuf, Type)> {
+    let mut resolver = unsafe { Resolver::from_ctx_ptr(tfmr.ctx.clone(), tfmr.ctx.env.clone()) };
+    for path in tfmr.ctx.env.declaration_paths() {
+        let module = match tfmr.ctx.env.load_module(&path) {
+            Some(module) => module,
+            None => continue,
+        };
+        if let Some(typ) = resolver.declared_module_type(module, name) {
+            return Some((path, typ));
+        }
+    }
+    None
+}
+
+fn visit_require<'a, 'b>(
+    tfmr: &mut Transformer<'a, 'b>,
+    node: &'b ast::Suffixed,
+    arg: &'b ast::Token,
+) -> hir::Expr<'b> {
+    let name = match arg.ty() {
+        ast::TokenType::Str(_, value) => value.to_string(),
+        _ => unreachable!(),
+    };
+    let path = tfmr
+        .ctx
+        .env
+        .find_module_path(tfmr.ctx.file_path.as_deref(), &name);
+
+    let (path, typ) = match path {
+        Some(path) => {
+            let typ = required_module_type(tfmr, &path, node.span());
+            (Some(path), typ)
+        }
+        None => match declared_module_type(tfmr, &name) {
+            Some((path, typ)) => (Some(path), typ),
+            None => {
+                tfmr.ctx.diagnostics.push(Diagnostic::UnknownModule {
+                    name: name.to_string(),
+                    span: arg.span(),
+                });
+                (None, types::makers::any(node.span()))
+            }
+        },
+    };
+    let module_symbol = tfmr.register_symbol(
+        vec![arg.span()],
+        SymbolKind::Module(ModuleSymbol { name, path }),
+    );
+    hir::Expr::Library(hir::LibraryExpr::Require(hir::Require {
+        span: node.span(),
+        typ,
+        path: Box::new(literal!(arg, tfmr, node)),
+        module_symbol,
+    }))
+}
+
 pub(crate) fn visit_call_expr_inner<'a, 'b>(
     tfmr: &mut Transformer<'a, 'b>,
     node: &'b ast::Suffixed,
     args: &'b ast::Args,
 ) -> hir::Expr<'b> {
+    if let Some(arg) = require_argument(tfmr, node, args) {
+        return visit_require(tfmr, node, arg);
+    }
+
     let mut arguments = Vec::new();
     let base = node.base().transform(tfmr);
 
@@ -28,12 +136,13 @@ pub(crate) fn visit_call_expr_inner<'a, 'b>(
         ast::Args::Table(arg) => {
             arguments.push(arg.transform(tfmr));
         }
-        ast::Args::Str(arg) => arguments.push(literal!(arg, tfmr, node, string)),
+        ast::Args::Str(arg) => arguments.push(literal!(arg, tfmr, node)),
     };
 
+    let typ = hir::Suffixed::call_type(&base, &arguments);
     hir::Expr::Suffixed(hir::Suffixed {
         span: node.span(),
         base: Box::new(base),
-        kind: hir::SuffixKind::Call(arguments),
+        kind: hir::SuffixKind::Call(arguments, typ),
     })
 }
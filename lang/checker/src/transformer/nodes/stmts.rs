@@ -1,3 +1,7 @@
+mod assign;
+mod compound_assign;
+mod control_flow;
+mod declare;
 mod local_assign;
 mod type_declare;
 
@@ -18,17 +22,21 @@ impl<'a, 'b> Transform<'a, 'b> for ast::Stmt {
                 hir::Expr::Library(node) => hir::Stmt::Library(node),
                 _ => unreachable!(),
             },
-            ast::Stmt::Do(_) => todo!(),
-            ast::Stmt::FunctionAssign(_) => todo!(),
-            ast::Stmt::GenericFor(_) => todo!(),
-            ast::Stmt::If(_) => todo!(),
+            ast::Stmt::CompoundAssign(node) => node.transform(tfmr),
+            ast::Stmt::DeclareFunction(node) => node.transform(tfmr),
+            ast::Stmt::DeclareLocal(node) => node.transform(tfmr),
+            ast::Stmt::DeclareModule(node) => node.transform(tfmr),
+            ast::Stmt::Do(node) => node.transform(tfmr),
+            ast::Stmt::FunctionAssign(node) => node.transform(tfmr),
+            ast::Stmt::GenericFor(node) => node.transform(tfmr),
+            ast::Stmt::If(node) => node.transform(tfmr),
             ast::Stmt::LocalAssign(node) => node.transform(tfmr),
             ast::Stmt::LocalFunction(node) => node.transform(tfmr),
-            ast::Stmt::NumericFor(_) => todo!(),
-            ast::Stmt::Repeat(_) => todo!(),
-            ast::Stmt::While(_) => todo!(),
+            ast::Stmt::NumericFor(node) => node.transform(tfmr),
+            ast::Stmt::Repeat(node) => node.transform(tfmr),
+            ast::Stmt::While(node) => node.transform(tfmr),
             ast::Stmt::TypeDeclaration(node) => node.transform(tfmr),
-            ast::Stmt::VarAssign(_) => todo!(),
+            ast::Stmt::VarAssign(node) => node.transform(tfmr),
             _ => unreachable!(),
         }
     }
@@ -71,6 +79,7 @@ fn visit_last_stmt<'a, 'b>(
             })
         }
         ast::Stmt::Break(n) => hir::LastStmt::Break(n.span(), tfmr.ctx.nodes.alloc(node)),
+        ast::Stmt::Continue(n) => hir::LastStmt::Continue(n.span(), tfmr.ctx.nodes.alloc(node)),
         _ => unreachable!(),
     }
 }
@@ -0,0 +1,87 @@
+use super::*;
+
+/// Gets the type that can be assigned to the target, locals with an
+/// explicit type keep it even if their value narrowed the variable.
+fn assignable_type(tfmr: &Transformer, target: &hir::Expr) -> Type {
+    target
+        .symbol()
+        .and_then(|id| tfmr.declared_types.get(&id).cloned())
+        .unwrap_or_else(|| target.typ().clone())
+}
+
+fn transform_function_name<'a, 'b>(
+    tfmr: &mut Transformer<'a, 'b>,
+    name: &'b ast::FunctionAssignName,
+    node: &'b dyn ast::Node,
+) -> hir::Expr<'b> {
+    match name {
+        ast::FunctionAssignName::Name(token) => transform_name(tfmr, token, node),
+        ast::FunctionAssignName::Property(base, token)
+        | ast::FunctionAssignName::Method(base, token) => {
+            let base = transform_function_name(tfmr, base, node);
+            let name = token.ty().as_name();
+            let span = Span::merge(base.span(), token.span());
+            let typ = hir::Suffixed::member_type(base.typ(), &name, span);
+            hir::Expr::Suffixed(hir::Suffixed {
+                span,
+                base: Box::new(base),
+                kind: hir::SuffixKind::Name(name, typ),
+            })
+        }
+    }
+}
+
+impl<'a, 'b> Transform<'a, 'b> for ast::VarAssign {
+    type Output = hir::Stmt<'b>;
+
+    fn transform(&'b self, tfmr: &mut Transformer<'a, 'b>) -> Self::Output {
+        let mut targets = Vec::new();
+        for name in self.names().iter() {
+            let target = match name {
+                ast::VarAssignName::Name(name) => transform_name(tfmr, name, self),
+                ast::VarAssignName::Suffixed(node) => node.transform(tfmr),
+            };
+            let typ = assignable_type(tfmr, &target);
+            targets.push((target, typ));
+        }
+        let exprs = self
+            .exprlist()
+            .iter()
+            .map(|v| v.transform(tfmr))
+            .collect::<Vec<_>>();
+
+        hir::Stmt::Assign(hir::Assign {
+            targets,
+            exprs,
+            span: self.span(),
+            node_id: tfmr.ctx.nodes.alloc(self),
+        })
+    }
+}
+
+impl<'a, 'b> Transform<'a, 'b> for ast::FunctionAssign {
+    type Output = hir::Stmt<'b>;
+
+    fn transform(&'b self, tfmr: &mut Transformer<'a, 'b>) -> Self::Output {
+        let target = transform_function_name(tfmr, self.name(), self);
+        let typ = assignable_type(tfmr, &target);
+
+        // `function a:b() end` is `a.b = function(self) end`
+        let self_type = match (self.name(), &target) {
+            (ast::FunctionAssignName::Method(..), hir::Expr::Suffixed(node)) => {
+                Some(node.base.typ().clone())
+            }
+            _ => None,
+        };
+
+        let node_id = tfmr.ctx.nodes.alloc(self);
+        let body = transform_function_body(tfmr, self.body(), self.span(), node_id, self_type);
+
+        hir::Stmt::Assign(hir::Assign {
+            targets: vec![(target, typ)],
+            exprs: vec![hir::Expr::Function(body)],
+            span: self.span(),
+            node_id,
+        })
+    }
+}
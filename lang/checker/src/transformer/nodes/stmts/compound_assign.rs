@@ -0,0 +1,27 @@
+use super::*;
+
+impl<'a, 'b> Transform<'a, 'b> for ast::CompoundAssign {
+    type Output = hir::Stmt<'b>;
+
+    fn transform(&'b self, tfmr: &mut Transformer<'a, 'b>) -> Self::Output {
+        let target = match self.name() {
+            ast::VarAssignName::Name(name) => transform_name(tfmr, name, self),
+            ast::VarAssignName::Suffixed(node) => node.transform(tfmr),
+        };
+        let value = self.expr().transform(tfmr);
+        let value = transform_binary(
+            tfmr,
+            target.clone(),
+            self.op().kind.clone(),
+            value,
+            self.span(),
+            self,
+        );
+        hir::Stmt::CompoundAssign(hir::CompoundAssign {
+            target,
+            value,
+            span: self.span(),
+            node_id: tfmr.ctx.nodes.alloc(self),
+        })
+    }
+}
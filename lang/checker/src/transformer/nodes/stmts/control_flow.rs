@@ -0,0 +1,194 @@
+use super::*;
+
+/// Pushes the scope of a block inside of a function, returns inside
+/// of it are still checked against the return type of the function.
+fn push_block_scope(tfmr: &mut Transformer) {
+    let expected_type = tfmr.current_scope().expected_type.clone();
+    tfmr.push_scope(ScopeKind::Block);
+    tfmr.current_scope_mut().expected_type = expected_type;
+}
+
+fn transform_block<'a, 'b>(
+    tfmr: &mut Transformer<'a, 'b>,
+    block: &'b ast::Block,
+) -> hir::Block<'b> {
+    push_block_scope(tfmr);
+    let block = block.transform(tfmr);
+    tfmr.pop_scope();
+    block
+}
+
+fn declare_loop_variable(tfmr: &mut Transformer, name: &ast::Token, typ: Type) -> Id<Symbol> {
+    let real_name = name.ty().as_name();
+    tfmr.insert_variable(
+        &real_name,
+        SymbolKind::BlockVariable(BlockVariableSymbol {
+            name: real_name.to_string(),
+            typ,
+            explicit: false,
+        }),
+        Some(name.span()),
+    )
+}
+
+/// The loop ends once the first value given from the iterator is
+/// `nil`, so it is never `nil` inside of the loop.
+fn without_nil(typ: Type) -> Type {
+    match typ {
+        Type::Union(mut union) => {
+            union.members.retain(|v| {
+                !matches!(
+                    v,
+                    Type::Literal(variants::Literal {
+                        typ: variants::LiteralType::Nil,
+                        ..
+                    })
+                )
+            });
+            if union.members.len() == 1 {
+                union.members.pop().unwrap()
+            } else {
+                Type::Union(union)
+            }
+        }
+        typ => typ,
+    }
+}
+
+impl<'a, 'b> Transform<'a, 'b> for ast::DoStmt {
+    type Output = hir::Stmt<'b>;
+
+    fn transform(&'b self, tfmr: &mut Transformer<'a, 'b>) -> Self::Output {
+        hir::Stmt::Do(transform_block(tfmr, self.block()))
+    }
+}
+
+impl<'a, 'b> Transform<'a, 'b> for ast::IfStmt {
+    type Output = hir::Stmt<'b>;
+
+    fn transform(&'b self, tfmr: &mut Transformer<'a, 'b>) -> Self::Output {
+        let mut branches = vec![(
+            self.condition().transform(tfmr),
+            transform_block(tfmr, self.block()),
+        )];
+        for clause in self.elseifs().iter() {
+            branches.push((
+                clause.condition().transform(tfmr),
+                transform_block(tfmr, clause.block()),
+            ));
+        }
+        let else_block = self
+            .else_block()
+            .as_ref()
+            .map(|block| transform_block(tfmr, block));
+
+        hir::Stmt::If(hir::If {
+            branches,
+            else_block,
+            span: self.span(),
+            node_id: tfmr.ctx.nodes.alloc(self),
+        })
+    }
+}
+
+impl<'a, 'b> Transform<'a, 'b> for ast::WhileStmt {
+    type Output = hir::Stmt<'b>;
+
+    fn transform(&'b self, tfmr: &mut Transformer<'a, 'b>) -> Self::Output {
+        hir::Stmt::While(hir::Loop {
+            condition: self.condition().transform(tfmr),
+            block: transform_block(tfmr, self.block()),
+            span: self.span(),
+            node_id: tfmr.ctx.nodes.alloc(self),
+        })
+    }
+}
+
+impl<'a, 'b> Transform<'a, 'b> for ast::RepeatStmt {
+    type Output = hir::Stmt<'b>;
+
+    fn transform(&'b self, tfmr: &mut Transformer<'a, 'b>) -> Self::Output {
+        // locals of the block are visible to the `until` condition
+        push_block_scope(tfmr);
+        let block = self.block().transform(tfmr);
+        let condition = self.condition().transform(tfmr);
+        tfmr.pop_scope();
+
+        hir::Stmt::Repeat(hir::Loop {
+            condition,
+            block,
+            span: self.span(),
+            node_id: tfmr.ctx.nodes.alloc(self),
+        })
+    }
+}
+
+impl<'a, 'b> Transform<'a, 'b> for ast::NumericFor {
+    type Output = hir::Stmt<'b>;
+
+    fn transform(&'b self, tfmr: &mut Transformer<'a, 'b>) -> Self::Output {
+        let start = self.start().transform(tfmr);
+        let end = self.end().transform(tfmr);
+        let step = self.step().as_ref().map(|v| v.transform(tfmr));
+
+        push_block_scope(tfmr);
+        let variable =
+            declare_loop_variable(tfmr, self.name(), types::makers::number(self.name().span()));
+        let block = self.block().transform(tfmr);
+        tfmr.pop_scope();
+
+        hir::Stmt::NumericFor(hir::NumericFor {
+            variable,
+            start,
+            end,
+            step,
+            block,
+            span: self.span(),
+            node_id: tfmr.ctx.nodes.alloc(self),
+        })
+    }
+}
+
+impl<'a, 'b> Transform<'a, 'b> for ast::GenericFor {
+    type Output = hir::Stmt<'b>;
+
+    fn transform(&'b self, tfmr: &mut Transformer<'a, 'b>) -> Self::Output {
+        let exprs = self
+            .exprlist()
+            .iter()
+            .map(|v| v.transform(tfmr))
+            .collect::<Vec<_>>();
+
+        // the variables are the values given from the iterator function
+        let iterator = exprs
+            .iter()
+            .flat_map(|v| v.typ().clone().deref_tuples())
+            .next();
+        let mut values = match iterator {
+            Some(Type::Function(info)) => info.return_type.deref_tuples(),
+            _ => Vec::new(),
+        }
+        .into_iter();
+
+        push_block_scope(tfmr);
+        let mut variables = Vec::new();
+        for (idx, name) in self.names().iter().enumerate() {
+            let typ = match values.next() {
+                Some(typ) if idx == 0 => without_nil(typ),
+                Some(typ) => typ,
+                None => types::makers::any(name.span()),
+            };
+            variables.push(declare_loop_variable(tfmr, name, typ));
+        }
+        let block = self.block().transform(tfmr);
+        tfmr.pop_scope();
+
+        hir::Stmt::GenericFor(hir::GenericFor {
+            variables,
+            exprs,
+            block,
+            span: self.span(),
+            node_id: tfmr.ctx.nodes.alloc(self),
+        })
+    }
+}
@@ -0,0 +1,93 @@
+use super::*;
+
+fn declare_variable<'a, 'b>(
+    tfmr: &mut Transformer<'a, 'b>,
+    kind: hir::DeclareKind,
+    name: &ast::Token,
+    typ: Type,
+    span: Span,
+    node_id: Id<&'b dyn ast::Node>,
+) -> hir::Stmt<'b> {
+    let real_name = name.ty().as_name();
+    let symbol_id = tfmr.insert_variable(
+        &real_name,
+        SymbolKind::BlockVariable(BlockVariableSymbol {
+            name: real_name.to_string(),
+            typ: typ.clone(),
+            explicit: true,
+        }),
+        Some(name.span()),
+    );
+    tfmr.ctx.globals.insert(real_name.to_string(), symbol_id);
+
+    hir::Stmt::Declare(hir::Declare {
+        kind,
+        name: real_name,
+        typ,
+        symbol: symbol_id,
+        span,
+        node_id,
+    })
+}
+
+impl<'a, 'b> Transform<'a, 'b> for ast::DeclareFunction {
+    type Output = hir::Stmt<'b>;
+
+    fn transform(&'b self, tfmr: &mut Transformer<'a, 'b>) -> Self::Output {
+        let typ = self.typ().transform(tfmr);
+        let node_id = tfmr.ctx.nodes.alloc(self);
+        declare_variable(
+            tfmr,
+            hir::DeclareKind::Function,
+            self.name(),
+            typ,
+            self.span(),
+            node_id,
+        )
+    }
+}
+
+impl<'a, 'b> Transform<'a, 'b> for ast::DeclareLocal {
+    type Output = hir::Stmt<'b>;
+
+    fn transform(&'b self, tfmr: &mut Transformer<'a, 'b>) -> Self::Output {
+        let typ = self.typ().transform(tfmr);
+        let node_id = tfmr.ctx.nodes.alloc(self);
+        declare_variable(
+            tfmr,
+            hir::DeclareKind::Local,
+            self.name(),
+            typ,
+            self.span(),
+            node_id,
+        )
+    }
+}
+
+impl<'a, 'b> Transform<'a, 'b> for ast::DeclareModule {
+    type Output = hir::Stmt<'b>;
+
+    fn transform(&'b self, tfmr: &mut Transformer<'a, 'b>) -> Self::Output {
+        let name = match self.name().ty() {
+            ast::TokenType::Str(_, value) => value.to_string(),
+            _ => unreachable!(),
+        };
+        let typ = self.typ().transform(tfmr);
+
+        // it is not a variable, only `require` can get it
+        let symbol_id =
+            tfmr.register_symbol(vec![self.name().span()], SymbolKind::Value(typ.clone()));
+        tfmr.ctx
+            .declared_modules
+            .insert(name.to_string(), symbol_id);
+
+        hir::Stmt::Declare(hir::Declare {
+            kind: hir::DeclareKind::Module,
+            name,
+            typ,
+            symbol: symbol_id,
+            span: self.span(),
+            node_id: tfmr.ctx.nodes.alloc(self),
+        })
+    }
+}
@@ -1,6 +1,14 @@
 use super::*;
 use ast::SpannedNode;
 
+fn set_variable_type(tfmr: &mut Transformer, symbol_id: Id<Symbol>, typ: Type) {
+    if let Some(SymbolKind::BlockVariable(var)) =
+        tfmr.ctx.symbols.get_mut(symbol_id).map(|v| &mut v.kind)
+    {
+        var.typ = typ;
+    }
+}
+
 impl<'a, 'b> Transform<'a, 'b> for ast::LocalFunction {
     type Output = hir::Stmt<'b>;
 
@@ -8,7 +16,7 @@ impl<'a, 'b> Transform<'a, 'b> for ast::LocalFunction {
         let name = self.name().ty().as_name();
         let node_id = tfmr.ctx.nodes.alloc(self);
 
-        let body = transform_function_body(tfmr, self.body(), self.span(), node_id);
+        let body = transform_function_body(tfmr, self.body(), self.span(), node_id, None);
 
         let symbol_id = tfmr.insert_variable(
             &name,
@@ -64,17 +72,43 @@ impl<'a, 'b> Transform<'a, 'b> for ast::LocalAssign {
                 .map(|v| (Some(v.0), Some(v.1)))
                 .unwrap_or((None, None));
 
+            // the variable is declared before its explicit type is transformed,
+            // so `local x: typeof(x)` is left unresolved for the resolver to catch
             let symbol_id = tfmr.insert_variable(
                 &real_name,
                 SymbolKind::BlockVariable(BlockVariableSymbol {
                     name: real_name.to_string(),
-                    typ: expr.clone().unwrap_or(types::makers::any(name.span())),
+                    typ: types::makers::any(name.span()),
                     explicit: name.type_info().is_some(),
                 }),
                 Some(name.span()),
             );
+            set_variable_type(
+                tfmr,
+                symbol_id,
+                types::makers::unresolved(symbol_id, name.span()),
+            );
 
             let explicit_type = name.type_info().as_ref().map(|v| v.transform(tfmr));
+
+            // `local mode = "read"` is a string variable, not a `"read"` one
+            // unless it is declared to be a singleton type
+            let typ = match (&explicit_type, &expr) {
+                (Some(explicit), Some(typ)) if typ.has_singletons() => explicit.clone(),
+                (_, Some(typ)) => typ.clone().widen(),
+                (_, None) => types::makers::any(name.span()),
+            };
+            set_variable_type(tfmr, symbol_id, typ);
+            if let Some(explicit) = &explicit_type {
+                tfmr.declared_types.insert(symbol_id, explicit.clone());
+            }
+
+            // `local M = require("./m")` lets `M.Config` refer to
+            // the types exported by that module
+            if let Some((.., hir::Expr::Library(hir::LibraryExpr::Require(node)))) = exprs.get(id) {
+                tfmr.module_variables.insert(symbol_id, node.module_symbol);
+            }
+
             // let (explicit_type, expr) = match dbg!((explicit_type, expr)) {
             //     (Some(Type::Function(assertion)), Some(Type::Function(expr))) => {
             //         let (v0, v1) = tfmr.revisit_function_type(expr, assertion);
@@ -4,25 +4,10 @@ impl<'a, 'b> Transform<'a, 'b> for ast::TypeDeclaration {
     type Output = hir::Stmt<'b>;
 
     fn transform(&'b self, tfmr: &mut Transformer<'a, 'b>) -> Self::Output {
-        let parameters = if let Some(real_params) = self.params() {
-            let mut params: Vec<hir::TypeParameter> = Vec::new();
-            for param in real_params.iter() {
-                let real_name = param.name().ty().as_name();
-                let explicit_type = param.typ().as_ref().map(|v| v.transform(tfmr));
-                let default_type = param.default().as_ref().map(|v| v.transform(tfmr));
-
-                params.push(hir::TypeParameter {
-                    name: real_name,
-                    name_span: param.name().span(),
-                    explicit: explicit_type,
-                    default: default_type,
-                    span: param.span(),
-                });
-            }
-            Some(params)
-        } else {
-            None
-        };
+        let parameters = self
+            .params()
+            .as_ref()
+            .map(|params| transform_type_parameters(tfmr, params));
 
         // recursive types are allowed :)
         // unless it has to be explicit...
@@ -38,26 +23,20 @@ impl<'a, 'b> Transform<'a, 'b> for ast::TypeDeclaration {
             Some(self.name().span()),
         );
 
+        // only the module scope can export types, the resolver
+        // catches the ones that are not recorded here
+        if *self.exported() && tfmr.current_scope().kind == ScopeKind::Module {
+            tfmr.ctx
+                .exported_types
+                .insert(real_name.to_string(), symbol_id);
+        }
+
         tfmr.push_scope(ScopeKind::TypeAliasDeclaration);
 
         // declare all of the parameters in an
         // isolated type declaration scope
         if let Some(ref parameters) = parameters {
-            for param in parameters.iter() {
-                // assume declare type variable?
-                tfmr.insert_type_alias(
-                    &param.name,
-                    SymbolKind::TypeParameter(
-                        param.name.to_string(),
-                        param
-                            .explicit
-                            .clone()
-                            .or(param.default.clone())
-                            .unwrap_or(types::makers::any(param.name_span)),
-                    ),
-                    Some(param.name_span),
-                );
-            }
+            declare_type_parameters(tfmr, parameters, false);
         }
 
         let value = self.typ().transform(tfmr);
@@ -71,9 +50,12 @@ impl<'a, 'b> Transform<'a, 'b> for ast::TypeDeclaration {
 
         hir::Stmt::TypeDeclaration(hir::TypeDeclaration {
             name: real_name,
+            exported: *self.exported(),
             parameters,
+            declared: value.clone(),
             value,
             symbol: symbol_id,
+            span: self.span(),
             node_id: tfmr.ctx.nodes.alloc(self),
         })
     }
@@ -2,12 +2,15 @@ use super::*;
 use crate::types::Type;
 
 mod callback;
+mod parameters;
 mod reference;
 mod sections;
 mod table;
 mod tuple;
+mod type_of;
 
 pub use callback::*;
+pub use parameters::*;
 pub use reference::*;
 pub use sections::*;
 pub use tuple::*;
@@ -19,10 +22,12 @@ impl<'a, 'b> Transform<'a, 'b> for ast::TypeInfo {
         match self {
             ast::TypeInfo::Callback(node) => node.transform(tfmr),
             ast::TypeInfo::Intersection(node) => node.transform(tfmr),
+            ast::TypeInfo::Literal(node) => singleton_type(node),
             ast::TypeInfo::Reference(node) => node.transform(tfmr),
             ast::TypeInfo::Metatable(node) => node.transform(tfmr),
             ast::TypeInfo::Table(node) => node.transform(tfmr),
             ast::TypeInfo::Tuple(node) => node.transform(tfmr),
+            ast::TypeInfo::TypeOf(node) => node.transform(tfmr),
             ast::TypeInfo::Union(node) => node.transform(tfmr),
         }
     }
@@ -4,6 +4,14 @@ impl<'a, 'b> Transform<'a, 'b> for ast::TypeCallback {
     type Output = Type;
 
     fn transform(&'b self, tfmr: &mut Transformer<'a, 'b>) -> Self::Output {
+        // type parameters are only visible in the callback type
+        tfmr.push_scope(ScopeKind::TypeAliasDeclaration);
+        let type_parameters = self.type_params().as_ref().map(|params| {
+            let params = transform_type_parameters(tfmr, params);
+            declare_type_parameters(tfmr, &params, true);
+            params
+        });
+
         let parameters = self
             .parameters()
             .iter()
@@ -21,13 +29,29 @@ impl<'a, 'b> Transform<'a, 'b> for ast::TypeCallback {
             })
             .collect();
 
+        let varidiac_param = self
+            .varidiac()
+            .as_ref()
+            .map(|varidiac| variants::VaridiacParameter {
+                span: varidiac.span,
+                typ: Box::new(
+                    varidiac
+                        .typ
+                        .as_ref()
+                        .map(|v| v.transform(tfmr))
+                        .unwrap_or(types::makers::any(varidiac.span)),
+                ),
+            });
+
+        let return_type = self.return_type().transform(tfmr);
+        tfmr.pop_scope();
+
         Type::Function(variants::Function {
             span: self.span(),
+            type_parameters,
             parameters,
-            return_type: Box::new(self.return_type().transform(tfmr)),
-
-            // TODO(memothelemo): Add varidiac type parameter?
-            varidiac_param: None,
+            return_type: Box::new(return_type),
+            varidiac_param,
         })
     }
 }
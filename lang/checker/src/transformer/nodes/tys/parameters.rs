@@ -0,0 +1,45 @@
+use super::*;
+
+/// Transforms type parameters of a type alias or a generic function.
+pub fn transform_type_parameters<'a, 'b>(
+    tfmr: &mut Transformer<'a, 'b>,
+    params: &'b [ast::TypeParameter],
+) -> Vec<hir::TypeParameter> {
+    let mut list = Vec::new();
+    for param in params.iter() {
+        let explicit = param.typ().as_ref().map(|v| v.transform(tfmr));
+        let default = param.default().as_ref().map(|v| v.transform(tfmr));
+        list.push(hir::TypeParameter {
+            name: param.name().ty().as_name(),
+            name_span: param.name().span(),
+            explicit,
+            default,
+            span: param.span(),
+        });
+    }
+    list
+}
+
+/// Declares the type parameters in the current scope. Type parameters
+/// of generic functions are kept as type variables when they are resolved.
+pub fn declare_type_parameters(
+    tfmr: &mut Transformer,
+    params: &[hir::TypeParameter],
+    generic: bool,
+) {
+    for param in params.iter() {
+        tfmr.insert_type_alias(
+            &param.name,
+            SymbolKind::TypeParameter(
+                param.name.to_string(),
+                param
+                    .explicit
+                    .clone()
+                    .or(param.default.clone())
+                    .unwrap_or(types::makers::any(param.name_span)),
+                generic,
+            ),
+            Some(param.name_span),
+        );
+    }
+}
@@ -1,13 +1,53 @@
 use super::*;
+use std::path::PathBuf;
+
+/// Gets the path of the module that is held by the variable,
+/// it has to be assigned from `require` to hold a module.
+fn module_variable_path(tfmr: &Transformer, name: &String) -> Option<PathBuf> {
+    let var_id = tfmr.current_scope().search_variable(&tfmr.ctx, name)?;
+    let module_id = tfmr.module_variables.get(&var_id)?;
+    match tfmr.ctx.symbols.get(*module_id).map(|v| &v.kind) {
+        Some(SymbolKind::Module(info)) => info.path.clone(),
+        _ => None,
+    }
+}
+
+/// Finds the type alias that the required module exports, it is
+/// copied into this module once it is found.
+fn exported_type_alias(tfmr: &mut Transformer, module: &String, name: &str) -> Option<Id<Symbol>> {
+    let path = module_variable_path(tfmr, module)?;
+    if let Some((.., symbol_id)) = tfmr
+        .exported_types
+        .iter()
+        .find(|(p, n, ..)| *p == path && n == name)
+    {
+        return Some(*symbol_id);
+    }
+
+    let mut resolver = unsafe { Resolver::from_ctx_ptr(tfmr.ctx.clone(), tfmr.ctx.env.clone()) };
+    let module_result = tfmr.ctx.env.load_module(&path)?;
+    let symbol_id = resolver.exported_type_symbol(module_result, name)?;
+    tfmr.exported_types.push((path, name.to_string(), symbol_id));
+    Some(symbol_id)
+}
 
 impl<'a, 'b> Transform<'a, 'b> for ast::TypeReference {
     type Output = Type;
 
     fn transform(&'b self, tfmr: &mut Transformer<'a, 'b>) -> Self::Output {
-        let scope = tfmr.current_scope();
         let name = self.name().ty().as_name();
 
-        let symbol = scope.search_type_alias(&tfmr.ctx, &name);
+        let (name, symbol) = match self.module() {
+            Some(module) => {
+                let module = module.ty().as_name();
+                let symbol = exported_type_alias(tfmr, &module, &name);
+                (format!("{}.{}", module, name), symbol)
+            }
+            None => {
+                let symbol = tfmr.current_scope().search_type_alias(&tfmr.ctx, &name);
+                (name, symbol)
+            }
+        };
         if let Some(symbol_id) = symbol {
             let arguments = self.arguments().as_ref().map(|arguments| {
                 let mut list = Vec::new();
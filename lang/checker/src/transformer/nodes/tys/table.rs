@@ -14,7 +14,16 @@ pub(crate) fn visit_type_table_inner<'a, 'b>(
                 let key = key.transform(tfmr);
                 let key_span = key.span();
                 let value = value.transform(tfmr);
-                entries.insert(variants::TableFieldKey::Computed(key, key_span), value);
+
+                // `["name"]: T` is the same as `name: T`
+                let key = match key {
+                    Type::Literal(variants::Literal {
+                        value: Some(variants::LiteralValue::String(name)),
+                        ..
+                    }) => variants::TableFieldKey::Name(name, key_span),
+                    key => variants::TableFieldKey::Computed(key, key_span),
+                };
+                entries.insert(key, value);
             }
             ast::TypeTableField::Named { name, value, .. } => {
                 let real_name = name.ty().as_name();
@@ -0,0 +1,12 @@
+use super::*;
+
+impl<'a, 'b> Transform<'a, 'b> for ast::TypeOf {
+    type Output = Type;
+
+    fn transform(&'b self, tfmr: &mut Transformer<'a, 'b>) -> Self::Output {
+        // the expression is only transformed to get its type,
+        // it won't be checked or emitted
+        let expr = self.expr().transform(tfmr);
+        expr.typ().clone()
+    }
+}
@@ -0,0 +1,713 @@
+use crate::{
+    analyzer::stdlib,
+    hir,
+    types::{makers, variants, Type},
+};
+use salite_ast::Span;
+use salite_common::{dictionary::Dictionary, LuaTarget};
+
+fn any() -> Type {
+    makers::any(Span::invalid())
+}
+
+fn bool() -> Type {
+    makers::bool(Span::invalid())
+}
+
+fn number() -> Type {
+    makers::number(Span::invalid())
+}
+
+fn string() -> Type {
+    makers::string(Span::invalid())
+}
+
+fn void() -> Type {
+    makers::void(Span::invalid())
+}
+
+fn maybe(typ: Type) -> Type {
+    Type::Union(variants::Union {
+        span: Span::invalid(),
+        members: vec![typ, makers::nil(Span::invalid())],
+    })
+}
+
+fn tuple(members: Vec<Type>) -> Type {
+    makers::tuple(Span::invalid(), members)
+}
+
+fn param(name: &str, typ: Type) -> variants::FunctionParameter {
+    variants::FunctionParameter {
+        span: Span::invalid(),
+        name: name.to_string(),
+        optional: false,
+        typ,
+    }
+}
+
+fn optional(name: &str, typ: Type) -> variants::FunctionParameter {
+    variants::FunctionParameter {
+        optional: true,
+        ..param(name, typ)
+    }
+}
+
+fn variadic(
+    parameters: Vec<variants::FunctionParameter>,
+    varidiac: Option<Type>,
+    return_type: Type,
+) -> Type {
+    Type::Function(variants::Function {
+        span: Span::invalid(),
+        type_parameters: None,
+        parameters,
+        varidiac_param: varidiac.map(|typ| variants::VaridiacParameter {
+            span: Span::invalid(),
+            typ: Box::new(typ),
+        }),
+        return_type: Box::new(return_type),
+    })
+}
+
+fn function(parameters: Vec<variants::FunctionParameter>, return_type: Type) -> Type {
+    variadic(parameters, None, return_type)
+}
+
+/// Makes a function type that takes any kind of arguments.
+fn callback() -> Type {
+    variadic(Vec::new(), Some(any()), any())
+}
+
+fn generic(name: &str, typ: Type) -> Type {
+    match typ {
+        Type::Function(info) => Type::Function(variants::Function {
+            type_parameters: Some(vec![hir::TypeParameter {
+                name: name.to_string(),
+                explicit: None,
+                default: None,
+                name_span: Span::invalid(),
+                span: Span::invalid(),
+            }]),
+            ..info
+        }),
+        _ => typ,
+    }
+}
+
+fn list(typ: Type) -> Type {
+    let mut entries = Dictionary::new();
+    entries.insert(variants::TableFieldKey::None(1, Span::invalid()), typ);
+    Type::Table(variants::Table {
+        is_metatable: false,
+        span: Span::invalid(),
+        entries,
+        metatable: None,
+    })
+}
+
+/// Makes the table of a library, members that don't
+/// exist on the target are left out.
+fn library(target: LuaTarget, name: &str, members: Vec<(&str, Type)>) -> Type {
+    let mut entries = Dictionary::new();
+    for (member, typ) in members {
+        if stdlib::is_available(&format!("{}.{}", name, member), target) {
+            entries.insert(
+                variants::TableFieldKey::Name(member.to_string(), Span::invalid()),
+                typ,
+            );
+        }
+    }
+    Type::Table(variants::Table {
+        is_metatable: false,
+        span: Span::invalid(),
+        entries,
+        metatable: None,
+    })
+}
+
+// dictionaries are typed as `any` since table types have to match
+// exactly, it would not accept most of the tables otherwise.
+
+/// The base library, `type_param` makes a reference
+/// to the type parameter of a generic function.
+fn base_library(type_param: &mut dyn FnMut(&str) -> Type) -> Vec<(&'static str, Type)> {
+    let next = || {
+        function(
+            vec![param("t", any()), optional("index", any())],
+            tuple(vec![any(), any()]),
+        )
+    };
+    let t = type_param("T");
+    vec![
+        ("_G", any()),
+        ("_VERSION", string()),
+        (
+            "assert",
+            variadic(vec![param("value", any())], Some(any()), any()),
+        ),
+        (
+            "collectgarbage",
+            function(
+                vec![optional("opt", string()), optional("arg", any())],
+                any(),
+            ),
+        ),
+        (
+            "dofile",
+            function(vec![optional("filename", string())], any()),
+        ),
+        (
+            "error",
+            function(
+                vec![param("message", any()), optional("level", number())],
+                void(),
+            ),
+        ),
+        ("getfenv", function(vec![optional("f", any())], any())),
+        (
+            "getmetatable",
+            function(vec![param("object", any())], any()),
+        ),
+        (
+            "ipairs",
+            generic(
+                "T",
+                function(
+                    vec![param("t", list(t.clone()))],
+                    tuple(vec![
+                        function(
+                            vec![param("t", list(t.clone())), param("i", number())],
+                            tuple(vec![maybe(number()), t.clone()]),
+                        ),
+                        list(t.clone()),
+                        number(),
+                    ]),
+                ),
+            ),
+        ),
+        (
+            "load",
+            function(
+                vec![
+                    param("chunk", any()),
+                    optional("chunkname", string()),
+                    optional("mode", string()),
+                    optional("env", any()),
+                ],
+                tuple(vec![any(), maybe(string())]),
+            ),
+        ),
+        (
+            "loadfile",
+            function(
+                vec![
+                    optional("filename", string()),
+                    optional("mode", string()),
+                    optional("env", any()),
+                ],
+                tuple(vec![any(), maybe(string())]),
+            ),
+        ),
+        (
+            "loadstring",
+            function(
+                vec![param("chunk", string()), optional("chunkname", string())],
+                tuple(vec![any(), maybe(string())]),
+            ),
+        ),
+        (
+            "module",
+            variadic(vec![param("name", string())], Some(any()), void()),
+        ),
+        ("next", next()),
+        (
+            "pairs",
+            function(
+                vec![param("t", any())],
+                tuple(vec![next(), any(), makers::nil(Span::invalid())]),
+            ),
+        ),
+        (
+            "pcall",
+            variadic(
+                vec![param("f", callback())],
+                Some(any()),
+                tuple(vec![bool(), any()]),
+            ),
+        ),
+        ("print", variadic(Vec::new(), Some(any()), void())),
+        (
+            "rawequal",
+            function(vec![param("v1", any()), param("v2", any())], bool()),
+        ),
+        (
+            "rawget",
+            function(vec![param("t", any()), param("index", any())], any()),
+        ),
+        ("rawlen", function(vec![param("v", any())], number())),
+        (
+            "rawset",
+            function(
+                vec![
+                    param("t", any()),
+                    param("index", any()),
+                    param("value", any()),
+                ],
+                any(),
+            ),
+        ),
+        (
+            "select",
+            variadic(vec![param("index", any())], Some(any()), any()),
+        ),
+        (
+            "setfenv",
+            function(vec![param("f", any()), param("table", any())], any()),
+        ),
+        (
+            "setmetatable",
+            generic(
+                "T",
+                function(vec![param("t", t.clone()), param("metatable", any())], t),
+            ),
+        ),
+        (
+            "tonumber",
+            function(
+                vec![param("value", any()), optional("base", number())],
+                maybe(number()),
+            ),
+        ),
+        ("tostring", function(vec![param("value", any())], string())),
+        ("type", function(vec![param("value", any())], string())),
+        ("typeof", function(vec![param("value", any())], string())),
+        (
+            "unpack",
+            function(
+                vec![
+                    param("list", list(any())),
+                    optional("i", number()),
+                    optional("j", number()),
+                ],
+                any(),
+            ),
+        ),
+        (
+            "warn",
+            variadic(vec![param("message", string())], Some(string()), void()),
+        ),
+        (
+            "xpcall",
+            variadic(
+                vec![
+                    param("f", callback()),
+                    param("handler", function(vec![param("err", any())], any())),
+                ],
+                Some(any()),
+                tuple(vec![bool(), any()]),
+            ),
+        ),
+    ]
+}
+
+fn string_library() -> Vec<(&'static str, Type)> {
+    let range = || {
+        vec![
+            param("s", string()),
+            optional("i", number()),
+            optional("j", number()),
+        ]
+    };
+    vec![
+        ("byte", function(range(), any())),
+        ("char", variadic(Vec::new(), Some(number()), string())),
+        (
+            "find",
+            function(
+                vec![
+                    param("s", string()),
+                    param("pattern", string()),
+                    optional("init", number()),
+                    optional("plain", bool()),
+                ],
+                any(),
+            ),
+        ),
+        (
+            "format",
+            variadic(vec![param("format", string())], Some(any()), string()),
+        ),
+        (
+            "gmatch",
+            function(
+                vec![param("s", string()), param("pattern", string())],
+                any(),
+            ),
+        ),
+        (
+            "gsub",
+            function(
+                vec![
+                    param("s", string()),
+                    param("pattern", string()),
+                    param("repl", any()),
+                    optional("n", number()),
+                ],
+                tuple(vec![string(), number()]),
+            ),
+        ),
+        ("len", function(vec![param("s", string())], number())),
+        ("lower", function(vec![param("s", string())], string())),
+        (
+            "match",
+            function(
+                vec![
+                    param("s", string()),
+                    param("pattern", string()),
+                    optional("init", number()),
+                ],
+                any(),
+            ),
+        ),
+        (
+            "pack",
+            variadic(vec![param("format", string())], Some(any()), string()),
+        ),
+        (
+            "packsize",
+            function(vec![param("format", string())], number()),
+        ),
+        (
+            "rep",
+            function(
+                vec![
+                    param("s", string()),
+                    param("n", number()),
+                    optional("sep", string()),
+                ],
+                string(),
+            ),
+        ),
+        ("reverse", function(vec![param("s", string())], string())),
+        (
+            "split",
+            function(
+                vec![param("s", string()), optional("separator", string())],
+                any(),
+            ),
+        ),
+        ("sub", function(range(), string())),
+        (
+            "unpack",
+            function(
+                vec![
+                    param("format", string()),
+                    param("s", string()),
+                    optional("pos", number()),
+                ],
+                any(),
+            ),
+        ),
+        ("upper", function(vec![param("s", string())], string())),
+    ]
+}
+
+fn table_library() -> Vec<(&'static str, Type)> {
+    vec![
+        ("clear", function(vec![param("t", any())], void())),
+        ("clone", function(vec![param("t", any())], any())),
+        (
+            "concat",
+            function(
+                vec![
+                    param("list", list(any())),
+                    optional("sep", string()),
+                    optional("i", number()),
+                    optional("j", number()),
+                ],
+                string(),
+            ),
+        ),
+        (
+            "create",
+            function(
+                vec![param("count", number()), optional("value", any())],
+                any(),
+            ),
+        ),
+        (
+            "find",
+            function(
+                vec![
+                    param("t", any()),
+                    param("value", any()),
+                    optional("init", number()),
+                ],
+                maybe(number()),
+            ),
+        ),
+        ("freeze", function(vec![param("t", any())], any())),
+        ("getn", function(vec![param("list", list(any()))], number())),
+        // the position is optional so it is `table.insert(list, [pos,] value)`
+        (
+            "insert",
+            function(
+                vec![
+                    param("list", list(any())),
+                    param("pos", any()),
+                    optional("value", any()),
+                ],
+                void(),
+            ),
+        ),
+        ("isfrozen", function(vec![param("t", any())], bool())),
+        ("maxn", function(vec![param("t", any())], number())),
+        (
+            "move",
+            function(
+                vec![
+                    param("a1", any()),
+                    param("f", number()),
+                    param("e", number()),
+                    param("t", number()),
+                    optional("a2", any()),
+                ],
+                any(),
+            ),
+        ),
+        ("pack", variadic(Vec::new(), Some(any()), list(any()))),
+        (
+            "remove",
+            function(
+                vec![param("list", list(any())), optional("pos", number())],
+                any(),
+            ),
+        ),
+        (
+            "setn",
+            function(
+                vec![param("list", list(any())), param("n", number())],
+                void(),
+            ),
+        ),
+        (
+            "sort",
+            function(
+                vec![
+                    param("list", list(any())),
+                    optional(
+                        "comp",
+                        function(vec![param("a", any()), param("b", any())], bool()),
+                    ),
+                ],
+                void(),
+            ),
+        ),
+        (
+            "unpack",
+            function(
+                vec![
+                    param("list", list(any())),
+                    optional("i", number()),
+                    optional("j", number()),
+                ],
+                any(),
+            ),
+        ),
+    ]
+}
+
+fn math_library() -> Vec<(&'static str, Type)> {
+    let unary = || function(vec![param("x", number())], number());
+    let binary = || function(vec![param("x", number()), param("y", number())], number());
+    vec![
+        ("abs", unary()),
+        ("acos", unary()),
+        ("asin", unary()),
+        (
+            "atan",
+            function(
+                vec![param("y", number()), optional("x", number())],
+                number(),
+            ),
+        ),
+        ("atan2", binary()),
+        ("ceil", unary()),
+        (
+            "clamp",
+            function(
+                vec![
+                    param("x", number()),
+                    param("min", number()),
+                    param("max", number()),
+                ],
+                number(),
+            ),
+        ),
+        ("cos", unary()),
+        ("cosh", unary()),
+        ("deg", unary()),
+        ("exp", unary()),
+        ("floor", unary()),
+        ("fmod", binary()),
+        (
+            "frexp",
+            function(vec![param("x", number())], tuple(vec![number(), number()])),
+        ),
+        ("huge", number()),
+        ("ldexp", binary()),
+        (
+            "log",
+            function(
+                vec![param("x", number()), optional("base", number())],
+                number(),
+            ),
+        ),
+        ("log10", unary()),
+        (
+            "max",
+            variadic(vec![param("x", number())], Some(number()), number()),
+        ),
+        ("maxinteger", number()),
+        (
+            "min",
+            variadic(vec![param("x", number())], Some(number()), number()),
+        ),
+        ("mininteger", number()),
+        (
+            "modf",
+            function(vec![param("x", number())], tuple(vec![number(), number()])),
+        ),
+        (
+            "noise",
+            function(
+                vec![
+                    param("x", number()),
+                    optional("y", number()),
+                    optional("z", number()),
+                ],
+                number(),
+            ),
+        ),
+        ("pi", number()),
+        ("pow", binary()),
+        ("rad", unary()),
+        (
+            "random",
+            function(
+                vec![optional("m", number()), optional("n", number())],
+                number(),
+            ),
+        ),
+        (
+            "randomseed",
+            function(vec![optional("x", number())], void()),
+        ),
+        ("round", unary()),
+        ("sign", unary()),
+        ("sin", unary()),
+        ("sinh", unary()),
+        ("sqrt", unary()),
+        ("tan", unary()),
+        ("tanh", unary()),
+        (
+            "tointeger",
+            function(vec![param("x", any())], maybe(number())),
+        ),
+        ("type", function(vec![param("x", any())], maybe(string()))),
+        (
+            "ult",
+            function(vec![param("m", number()), param("n", number())], bool()),
+        ),
+    ]
+}
+
+fn os_library() -> Vec<(&'static str, Type)> {
+    vec![
+        ("clock", function(Vec::new(), number())),
+        (
+            "date",
+            function(
+                vec![optional("format", string()), optional("time", number())],
+                any(),
+            ),
+        ),
+        (
+            "difftime",
+            function(
+                vec![param("t2", number()), optional("t1", number())],
+                number(),
+            ),
+        ),
+        (
+            "execute",
+            function(vec![optional("command", string())], any()),
+        ),
+        (
+            "exit",
+            function(
+                vec![optional("code", any()), optional("close", bool())],
+                void(),
+            ),
+        ),
+        (
+            "getenv",
+            function(vec![param("name", string())], maybe(string())),
+        ),
+        ("remove", function(vec![param("filename", string())], any())),
+        (
+            "rename",
+            function(
+                vec![param("oldname", string()), param("newname", string())],
+                any(),
+            ),
+        ),
+        (
+            "setlocale",
+            function(
+                vec![optional("locale", string()), optional("category", string())],
+                maybe(string()),
+            ),
+        ),
+        ("time", function(vec![optional("t", any())], number())),
+        ("tmpname", function(Vec::new(), string())),
+    ]
+}
+
+fn coroutine_library() -> Vec<(&'static str, Type)> {
+    vec![
+        ("close", function(vec![param("co", any())], any())),
+        ("create", function(vec![param("f", callback())], any())),
+        ("isyieldable", function(Vec::new(), bool())),
+        (
+            "resume",
+            variadic(vec![param("co", any())], Some(any()), any()),
+        ),
+        ("running", function(Vec::new(), any())),
+        ("status", function(vec![param("co", any())], string())),
+        ("wrap", function(vec![param("f", callback())], callback())),
+        ("yield", variadic(Vec::new(), Some(any()), any())),
+    ]
+}
+
+/// Gets the globals of the Lua standard library that exist on the
+/// target, like `print` and `string.format`.
+pub(crate) fn globals(
+    target: LuaTarget,
+    mut type_param: impl FnMut(&str) -> Type,
+) -> Vec<(&'static str, Type)> {
+    let mut globals = base_library(&mut type_param);
+    globals.extend([
+        ("string", library(target, "string", string_library())),
+        ("table", library(target, "table", table_library())),
+        ("math", library(target, "math", math_library())),
+        ("os", library(target, "os", os_library())),
+        (
+            "coroutine",
+            library(target, "coroutine", coroutine_library()),
+        ),
+    ]);
+    globals.retain(|(name, _)| stdlib::is_available(name, target));
+    globals
+}
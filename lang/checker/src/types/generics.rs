@@ -0,0 +1,278 @@
+use super::*;
+use salite_common::dictionary::Dictionary;
+
+/// Reasons why the type arguments of a generic function
+/// cannot be inferred from the arguments of the call.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InferError {
+    /// None of the arguments tells what the type parameter is.
+    NotInferred { name: String },
+
+    /// The type parameter is inferred as two different types.
+    Conflict {
+        name: String,
+        first: Type,
+        second: Type,
+    },
+}
+
+/// Type arguments inferred from a call. The rest of the type
+/// arguments are still inferred after the first error.
+#[derive(Debug, Clone)]
+pub struct Inference {
+    pub args: Dictionary<String, Type>,
+    pub error: Option<InferError>,
+}
+
+/// Infers the type arguments of a generic function from the types
+/// of the arguments given by the call. Type parameters that cannot be
+/// inferred will use their default type if they have one.
+pub fn infer_type_arguments(
+    info: &variants::Function,
+    args: &[&Type],
+    skip_params: usize,
+) -> Inference {
+    let params = match &info.type_parameters {
+        Some(params) => params,
+        None => {
+            return Inference {
+                args: Dictionary::new(),
+                error: None,
+            }
+        }
+    };
+    let mut unifier = Unifier {
+        params,
+        inference: Inference {
+            args: Dictionary::new(),
+            error: None,
+        },
+    };
+
+    let mut args = args.iter();
+    for param in info.parameters.iter().skip(skip_params) {
+        match args.next() {
+            Some(arg) => unifier.unify(&param.typ, arg),
+            None => break,
+        }
+    }
+    if let Some(varidiac) = &info.varidiac_param {
+        for arg in args {
+            unifier.unify(&varidiac.typ, arg);
+        }
+    }
+
+    let mut inference = unifier.inference;
+    for param in params.iter() {
+        if inference.args.contains(&param.name) {
+            continue;
+        }
+        match &param.default {
+            Some(default) => inference
+                .args
+                .insert(param.name.to_string(), default.clone()),
+            None if inference.error.is_none() => {
+                inference.error = Some(InferError::NotInferred {
+                    name: param.name.to_string(),
+                });
+            }
+            None => {}
+        }
+    }
+
+    inference
+}
+
+/// Replaces every type parameter with its type argument.
+pub fn substitute(typ: &Type, args: &Dictionary<String, Type>) -> Type {
+    let substitute_all =
+        |members: &[Type]| -> Vec<Type> { members.iter().map(|v| substitute(v, args)).collect() };
+    match typ {
+        Type::Reference(info) if info.arguments.is_none() => match args.get(&info.name) {
+            Some(typ) => typ.clone(),
+            None => typ.clone(),
+        },
+        Type::Reference(info) => Type::Reference(variants::Reference {
+            arguments: info.arguments.as_ref().map(|v| substitute_all(v)),
+            ..info.clone()
+        }),
+        Type::Function(info) => Type::Function(instantiate(info, args)),
+        Type::Intersection(node) => Type::Intersection(variants::Intersection {
+            span: node.span,
+            members: substitute_all(&node.members),
+        }),
+        Type::Union(node) => Type::Union(variants::Union {
+            span: node.span,
+            members: substitute_all(&node.members),
+        }),
+        Type::Tuple(node) => Type::Tuple(variants::Tuple {
+            span: node.span,
+            members: substitute_all(&node.members),
+        }),
+        Type::Table(tbl) => {
+            let mut tbl = tbl.clone();
+            for (key, value) in tbl.entries.iter_mut() {
+                if let variants::TableFieldKey::Computed(key, ..) = key {
+                    *key = substitute(key, args);
+                }
+                *value = substitute(value, args);
+            }
+            Type::Table(tbl)
+        }
+        _ => typ.clone(),
+    }
+}
+
+/// Substitutes the type parameters in the parameters and the return type
+/// of the function. Type parameters of the function itself shadow the
+/// type arguments with the same name.
+pub fn instantiate(
+    info: &variants::Function,
+    args: &Dictionary<String, Type>,
+) -> variants::Function {
+    let mut args = args.clone();
+    if let Some(params) = &info.type_parameters {
+        for param in params.iter() {
+            args.remove(&param.name);
+        }
+    }
+
+    let mut info = info.clone();
+    for param in info.parameters.iter_mut() {
+        param.typ = substitute(&param.typ, &args);
+    }
+    if let Some(param) = info.varidiac_param.as_mut() {
+        *param.typ = substitute(&param.typ, &args);
+    }
+    *info.return_type = substitute(&info.return_type, &args);
+    info
+}
+
+struct Unifier<'a> {
+    params: &'a [hir::TypeParameter],
+    inference: Inference,
+}
+
+impl<'a> Unifier<'a> {
+    fn is_param(&self, name: &str) -> bool {
+        self.params.iter().any(|v| v.name == name)
+    }
+
+    /// Checks if the type has any of the type parameters inside of it.
+    fn mentions(&self, typ: &Type) -> bool {
+        match typ {
+            Type::Reference(info) => {
+                (info.arguments.is_none() && self.is_param(&info.name))
+                    || info.arguments.iter().flatten().any(|v| self.mentions(v))
+            }
+            Type::Function(info) => {
+                info.parameters.iter().any(|v| self.mentions(&v.typ))
+                    || info
+                        .varidiac_param
+                        .as_ref()
+                        .map(|v| self.mentions(&v.typ))
+                        .unwrap_or_default()
+                    || self.mentions(&info.return_type)
+            }
+            Type::Intersection(node) => node.members.iter().any(|v| self.mentions(v)),
+            Type::Union(node) => node.members.iter().any(|v| self.mentions(v)),
+            Type::Tuple(node) => node.members.iter().any(|v| self.mentions(v)),
+            Type::Table(tbl) => tbl.entries.iter().any(|(_, v)| self.mentions(v)),
+            _ => false,
+        }
+    }
+
+    fn bind(&mut self, name: &str, arg: &Type) {
+        let args = &mut self.inference.args;
+        let previous = match args.get(&name.to_string()) {
+            Some(previous) => previous.clone(),
+            None => return args.insert(name.to_string(), arg.clone()),
+        };
+        match (&previous, arg) {
+            (Type::Any(..) | Type::Unknown(..), _) => {}
+            (_, Type::Any(..) | Type::Unknown(..)) => {}
+            // they are compared once the signature is instantiated
+            (Type::Table(..), Type::Table(..)) | (Type::Function(..), Type::Function(..)) => {}
+            _ if &previous == arg => {}
+            // `1` and `2` are both numbers
+            _ if previous.clone().widen() == arg.clone().widen() => {
+                if let Some(typ) = args.get_mut(&name.to_string()) {
+                    *typ = previous.widen();
+                }
+            }
+            _ if self.inference.error.is_none() => {
+                self.inference.error = Some(InferError::Conflict {
+                    name: name.to_string(),
+                    first: previous,
+                    second: arg.clone(),
+                });
+            }
+            _ => {}
+        }
+    }
+
+    fn unify_all(&mut self, params: &[Type], args: &[Type]) {
+        for (param, arg) in params.iter().zip(args.iter()) {
+            self.unify(param, arg);
+        }
+    }
+
+    fn unify(&mut self, param: &Type, arg: &Type) {
+        match (param, arg) {
+            (Type::Reference(info), _) if info.arguments.is_none() && self.is_param(&info.name) => {
+                self.bind(&info.name, arg)
+            }
+            (Type::Reference(a), Type::Reference(b)) if a.symbol == b.symbol => {
+                if let (Some(a), Some(b)) = (&a.arguments, &b.arguments) {
+                    self.unify_all(a, b)
+                }
+            }
+            (Type::Tuple(a), Type::Tuple(b)) => self.unify_all(&a.members, &b.members),
+            (Type::Tuple(a), _) if a.members.len() == 1 => self.unify(&a.members[0], arg),
+            (_, Type::Tuple(b)) if b.members.len() == 1 => self.unify(param, &b.members[0]),
+            (Type::Function(a), Type::Function(b)) => {
+                for (param, arg) in a.parameters.iter().zip(b.parameters.iter()) {
+                    self.unify(&param.typ, &arg.typ);
+                }
+                self.unify(&a.return_type, &b.return_type)
+            }
+            (Type::Table(a), Type::Table(b)) => {
+                for (key, value) in a.entries.iter() {
+                    if let Some(other) = b.entries.get(key) {
+                        self.unify(value, other);
+                    }
+                }
+            }
+            (Type::Union(union), _) => {
+                // only `T?` alike unions can be inferred, members
+                // without type parameters are taken out of the argument
+                let (generic, others): (Vec<&Type>, Vec<&Type>) =
+                    union.members.iter().partition(|v| self.mentions(v));
+                if generic.len() != 1 || others.contains(&arg) {
+                    return;
+                }
+                let arg = match arg {
+                    Type::Union(node) => {
+                        let mut members = node
+                            .members
+                            .iter()
+                            .filter(|v| !others.contains(v))
+                            .cloned()
+                            .collect::<Vec<_>>();
+                        if members.len() == 1 {
+                            members.pop().unwrap()
+                        } else {
+                            Type::Union(variants::Union {
+                                span: node.span,
+                                members,
+                            })
+                        }
+                    }
+                    _ => arg.clone(),
+                };
+                self.unify(generic[0], &arg)
+            }
+            _ => {}
+        }
+    }
+}
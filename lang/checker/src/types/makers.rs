@@ -11,6 +11,7 @@ pub fn bool(span: Span) -> Type {
     Type::Literal(Literal {
         typ: LiteralType::Bool,
         span,
+        value: None,
     })
 }
 
@@ -18,6 +19,7 @@ pub fn number(span: Span) -> Type {
     Type::Literal(Literal {
         typ: LiteralType::Number,
         span,
+        value: None,
     })
 }
 
@@ -25,6 +27,7 @@ pub fn nil(span: Span) -> Type {
     Type::Literal(Literal {
         typ: LiteralType::Nil,
         span,
+        value: None,
     })
 }
 
@@ -45,10 +48,19 @@ pub fn reference(span: Span, symbol: Id<Symbol>, name: String, args: Option<Vec<
     })
 }
 
+pub fn singleton(span: Span, value: LiteralValue) -> Type {
+    Type::Literal(Literal {
+        typ: value.typ(),
+        value: Some(value),
+        span,
+    })
+}
+
 pub fn string(span: Span) -> Type {
     Type::Literal(Literal {
         typ: LiteralType::String,
         span,
+        value: None,
     })
 }
 
@@ -64,5 +76,6 @@ pub fn void(span: Span) -> Type {
     Type::Literal(Literal {
         typ: LiteralType::Void,
         span,
+        value: None,
     })
 }
@@ -2,6 +2,7 @@ use crate::*;
 use salite_ast::Span;
 use std::hash::Hash;
 
+pub mod generics;
 pub mod makers;
 pub mod utils;
 
@@ -66,15 +67,47 @@ pub mod variants {
         Void,
     }
 
+    /// Value of a singleton literal type, like `"read"` or `1`.
+    #[derive(Debug, Clone, PartialEq, Hash)]
+    pub enum LiteralValue {
+        Bool(bool),
+        /// Number as it is written in the source code
+        Number(String),
+        String(String),
+    }
+
+    impl LiteralValue {
+        pub fn typ(&self) -> LiteralType {
+            match self {
+                LiteralValue::Bool(..) => LiteralType::Bool,
+                LiteralValue::Number(..) => LiteralType::Number,
+                LiteralValue::String(..) => LiteralType::String,
+            }
+        }
+    }
+
+    impl std::fmt::Display for LiteralValue {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                LiteralValue::Bool(value) => write!(f, "{}", value),
+                LiteralValue::Number(value) => write!(f, "{}", value),
+                // escaped, so it can be parsed back
+                LiteralValue::String(value) => write!(f, "{:?}", value),
+            }
+        }
+    }
+
     #[derive(Debug, Clone)]
     pub struct Literal {
         pub span: Span,
         pub typ: LiteralType,
+        /// Only the value is allowed if it is a singleton type.
+        pub value: Option<LiteralValue>,
     }
 
     impl PartialEq for Literal {
         fn eq(&self, other: &Self) -> bool {
-            self.typ == other.typ
+            self.typ == other.typ && self.value == other.value
         }
     }
 
@@ -82,6 +115,7 @@ pub mod variants {
         fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
             self.span.hash(state);
             self.typ.hash(state);
+            self.value.hash(state);
         }
     }
 
@@ -199,6 +233,7 @@ pub mod variants {
     #[derive(Debug, Clone)]
     pub struct Function {
         pub span: Span,
+        pub type_parameters: Option<Vec<hir::TypeParameter>>,
         pub parameters: Vec<FunctionParameter>,
         pub varidiac_param: Option<VaridiacParameter>,
         pub return_type: Box<Type>,
@@ -366,6 +401,52 @@ impl Type {
         }
     }
 
+    /// Checks if the type has singleton literal types inside of it.
+    pub fn has_singletons(&self) -> bool {
+        match self {
+            Type::Literal(node) => node.value.is_some(),
+            Type::Intersection(node) => node.members.iter().any(Type::has_singletons),
+            Type::Union(node) => node.members.iter().any(Type::has_singletons),
+            Type::Tuple(node) => node.members.iter().any(Type::has_singletons),
+            Type::Table(tbl) => tbl.entries.iter().any(|(_, v)| v.has_singletons()),
+            _ => false,
+        }
+    }
+
+    /// Turns singleton literal types into their base types,
+    /// `"read"` becomes `string`.
+    pub fn widen(self) -> Type {
+        match self {
+            Type::Literal(variants::Literal { span, typ, .. }) => {
+                Type::Literal(variants::Literal {
+                    span,
+                    typ,
+                    value: None,
+                })
+            }
+            Type::Union(mut node) => {
+                let mut members: Vec<Type> = Vec::new();
+                for member in node.members.into_iter().map(Type::widen) {
+                    if !members.contains(&member) {
+                        members.push(member);
+                    }
+                }
+                if members.len() == 1 {
+                    return members.pop().unwrap();
+                }
+                node.members = members;
+                Type::Union(node)
+            }
+            Type::Table(mut tbl) => {
+                for (_, value) in tbl.entries.iter_mut() {
+                    *value = value.clone().widen();
+                }
+                Type::Table(tbl)
+            }
+            typ => typ,
+        }
+    }
+
     pub fn deref_tuples(self) -> Vec<Type> {
         let mut types = Vec::new();
         self.deref_tuples_inner(&mut types);
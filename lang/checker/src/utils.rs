@@ -68,6 +68,9 @@ pub fn type_description<'a, 'b>(ctx: &ModuleContext<'a, 'b>, typ: &Type) -> Stri
             }
             format!("({})", result.join(","))
         }
+        Type::Literal(variants::Literal {
+            value: Some(value), ..
+        }) => value.to_string(),
         Type::Literal(info) => match info.typ {
             variants::LiteralType::Bool => "bool",
             variants::LiteralType::Number => "number",
@@ -84,24 +87,67 @@ pub fn type_description<'a, 'b>(ctx: &ModuleContext<'a, 'b>, typ: &Type) -> Stri
                 let typ = type_description(ctx, &param.typ);
                 params.push(format!("{}{}", name, typ));
             }
+            if let Some(varidiac) = &info.varidiac_param {
+                params.push(format!("...: {}", type_description(ctx, &varidiac.typ)));
+            }
+            let type_params = match &info.type_parameters {
+                Some(type_params) => format!(
+                    "<{}>",
+                    type_params
+                        .iter()
+                        .map(|v| v.name.to_string())
+                        .collect::<Vec<_>>()
+                        .join(",")
+                ),
+                None => String::new(),
+            };
             format!(
-                "({}) -> {}",
+                "{}({}) -> {}",
+                type_params,
                 params.join(","),
                 type_description(ctx, &info.return_type)
             )
         }
         Type::Unresolved(info) => panic!("Unresolved type: {:#?}", info),
         Type::Intersection(node) => member_description!(node.members, " & "),
-        Type::Union(node) => member_description!(node.members, " | "),
+        Type::Union(node) => {
+            let is_nil = |v: &&Type| {
+                matches!(
+                    v,
+                    Type::Literal(variants::Literal {
+                        typ: variants::LiteralType::Nil,
+                        ..
+                    })
+                )
+            };
+            let members = node
+                .members
+                .iter()
+                .filter(|v| !is_nil(v))
+                .collect::<Vec<_>>();
+            if members.is_empty() || members.len() == node.members.len() {
+                member_description!(node.members, " | ")
+            } else if members.len() == 1
+                && !matches!(
+                    members[0],
+                    Type::Function(..) | Type::Intersection(..) | Type::Union(..)
+                )
+            {
+                format!("{}?", type_description(ctx, members[0]))
+            } else {
+                format!("({})?", member_description!(members, " | "))
+            }
+        }
         Type::Any(..) => "any".to_string(),
         Type::Recursive(info) => {
             let sym = ctx.symbols.get(info.symbol).unwrap();
             match &sym.kind {
                 crate::SymbolKind::BlockVariable(var) => var.name.to_string(),
                 crate::SymbolKind::FunctionParameter(name, ..) => name.to_string(),
+                crate::SymbolKind::Module(info) => info.name.to_string(),
                 crate::SymbolKind::TypeParameter(name, ..) => name.to_string(),
                 crate::SymbolKind::TypeAlias(info) => info.name.to_string(),
-                crate::SymbolKind::UnknownVariable => "!UNKNOWN_VAR!".to_string(),
+                crate::SymbolKind::UnknownVariable(..) => "!UNKNOWN_VAR!".to_string(),
                 crate::SymbolKind::Value(_) => "value".to_string(),
             }
         }
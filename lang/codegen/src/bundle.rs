@@ -0,0 +1,88 @@
+/// Loader that shadows `require` in the bundle, modules that
+/// are not in the bundle are loaded with the original `require`.
+const BUNDLE_PRELUDE: &str = "\
+local __modules = {}
+local __loaded = {}
+local __require = require
+local function require(name)
+\tif __loaded[name] == nil then
+\t\tlocal loader = __modules[name]
+\t\tif loader == nil then
+\t\t\treturn __require(name)
+\t\tend
+\t\tlocal value = loader(name)
+\t\t__loaded[name] = value == nil and true or value
+\tend
+\treturn __loaded[name]
+end
+";
+
+pub(crate) fn quote(name: &str) -> String {
+    format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Links emitted Lua modules into one Lua file.
+#[derive(Debug, Default)]
+pub struct Bundle {
+    modules: Vec<(String, String)>,
+}
+
+impl Bundle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an emitted module which can be loaded with
+    /// `require(name)` inside of the bundle.
+    pub fn add_module(&mut self, name: String, code: String) {
+        self.modules.push((name, code));
+    }
+
+    /// Writes every module into one Lua file, ordered by their names.
+    /// The bundle returns the value of the `entry` module if it exists.
+    pub fn finish(mut self, entry: &str) -> String {
+        self.modules.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut output = String::from(BUNDLE_PRELUDE);
+        for (name, code) in self.modules.iter() {
+            // module code is not indented, multiline strings
+            // have to stay the same
+            output.push_str(&format!("\n__modules[{}] = function(...)\n", quote(name)));
+            output.push_str(code);
+            if !code.is_empty() && !code.ends_with('\n') {
+                output.push('\n');
+            }
+            output.push_str("end\n");
+        }
+
+        if self.modules.iter().any(|(name, _)| name == entry) {
+            output.push_str(&format!("\nreturn require({})\n", quote(entry)));
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn modules_are_ordered() {
+        let mut bundle = Bundle::new();
+        bundle.add_module("init".into(), "return require(\"a.b\")\n".into());
+        bundle.add_module("a.b".into(), "return 1".into());
+
+        let output = bundle.finish("init");
+        let a = output.find("__modules[\"a.b\"] = function(...)\nreturn 1\nend\n");
+        let init = output.find("__modules[\"init\"] = function(...)\n");
+        assert!(a.is_some() && init.is_some() && a < init);
+        assert!(output.ends_with("\nreturn require(\"init\")\n"));
+    }
+
+    #[test]
+    fn no_entry_module() {
+        let mut bundle = Bundle::new();
+        bundle.add_module("lib".into(), String::new());
+        assert!(!bundle.finish("init").contains("return require(\"init\")"));
+    }
+}
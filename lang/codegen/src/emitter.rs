@@ -0,0 +1,1333 @@
+use salite_ast::*;
+use salite_common::LuaTarget;
+
+use crate::{bundle::quote, Mapping, SourceMap};
+
+const INDENT: &str = "\t";
+
+/// Lua operator precedence, it is different from Salite's
+/// operator precedence because unary operators in Lua binds
+/// tighter than multiplicative operators.
+fn binop_precedence(kind: &BinopKind) -> usize {
+    match kind {
+        BinopKind::Or => 1,
+        BinopKind::And => 2,
+        BinopKind::Equality
+        | BinopKind::Inequality
+        | BinopKind::GreaterThan
+        | BinopKind::GreaterEqual
+        | BinopKind::LessThan
+        | BinopKind::LessEqual => 3,
+        BinopKind::Concat => 4,
+        BinopKind::Add | BinopKind::Subtract => 5,
+        BinopKind::Multiply | BinopKind::FloorDivision | BinopKind::Divide | BinopKind::Modulo => 6,
+        BinopKind::Exponent => 8,
+        // it is lowered into a function call
+        BinopKind::NilshCoalescing => 9,
+    }
+}
+
+const UNARY_PRECEDENCE: usize = 7;
+
+fn binop_str(kind: &BinopKind) -> &'static str {
+    match kind {
+        BinopKind::NilshCoalescing => "??",
+        BinopKind::Exponent => "^",
+        BinopKind::Multiply => "*",
+        BinopKind::FloorDivision => "//",
+        BinopKind::Divide => "/",
+        BinopKind::Modulo => "%",
+        BinopKind::Add => "+",
+        BinopKind::Subtract => "-",
+        BinopKind::Concat => "..",
+        BinopKind::Equality => "==",
+        BinopKind::Inequality => "~=",
+        BinopKind::GreaterThan => ">",
+        BinopKind::GreaterEqual => ">=",
+        BinopKind::LessThan => "<",
+        BinopKind::LessEqual => "<=",
+        BinopKind::And => "and",
+        BinopKind::Or => "or",
+    }
+}
+
+fn expr_precedence(expr: &Expr) -> usize {
+    match expr {
+        Expr::Binary(node) => binop_precedence(&node.op().kind),
+        Expr::Unary(..) => UNARY_PRECEDENCE,
+        Expr::TypeAssertion(node) => expr_precedence(node.base()),
+        // the else branch takes everything after it
+        Expr::IfElse(..) => 0,
+        _ => usize::MAX,
+    }
+}
+
+/// Checks if the statement only exists for the type checker.
+fn is_erased(stmt: &Stmt) -> bool {
+    matches!(
+        stmt,
+        Stmt::TypeDeclaration(..)
+            | Stmt::DeclareFunction(..)
+            | Stmt::DeclareLocal(..)
+            | Stmt::DeclareModule(..)
+    )
+}
+
+/// Checks if the block has nothing to emit at all.
+fn is_block_empty(block: &Block) -> bool {
+    block.last_stmt().is_none() && block.stmts().iter().all(is_erased)
+}
+
+/// Checks if the emitted expression starts with an open parenthesis,
+/// which Lua might confuse it as a call from the previous statement.
+fn starts_with_paren(expr: &Expr) -> bool {
+    match expr {
+        Expr::Paren(..) => true,
+        Expr::Suffixed(node) => starts_with_paren(node.base()) || !is_prefix_expr(node.base()),
+        Expr::TypeAssertion(node) => starts_with_paren(node.base()),
+        _ => false,
+    }
+}
+
+fn stmt_starts_with_paren(stmt: &Stmt) -> bool {
+    match stmt {
+        Stmt::Call(expr) => starts_with_paren(expr),
+        Stmt::VarAssign(node) => match node.names().first() {
+            Some(VarAssignName::Suffixed(first)) => {
+                starts_with_paren(first.base()) || !is_prefix_expr(first.base())
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Gets the name given to `require` if it is called
+/// with a string literal only.
+fn require_argument(node: &Suffixed) -> Option<&str> {
+    match node.base().as_ref() {
+        Expr::Literal(Literal::Name(name)) if name.ty().as_name() == "require" => {}
+        _ => return None,
+    };
+    let arg = match node.suffix() {
+        SuffixKind::Call(Args::ExprList(list)) if list.len() == 1 => match &list[0] {
+            Expr::Literal(Literal::Str(arg)) => arg,
+            _ => return None,
+        },
+        SuffixKind::Call(Args::Str(arg)) => arg,
+        _ => return None,
+    };
+    match arg.ty() {
+        TokenType::Str(_, value) => Some(value.as_str()),
+        _ => None,
+    }
+}
+
+/// Lua only allows names, parenthesized and suffixed expressions to
+/// be suffixed with a call or an index.
+fn is_prefix_expr(expr: &Expr) -> bool {
+    match expr {
+        Expr::Literal(Literal::Name(..)) | Expr::Paren(..) | Expr::Suffixed(..) => true,
+        Expr::TypeAssertion(node) => is_prefix_expr(node.base()),
+        _ => false,
+    }
+}
+
+fn table_uses_varargs(table: &TableCtor) -> bool {
+    table.fields().iter().any(|v| match v {
+        TableField::Array(value) => uses_varargs(value),
+        TableField::Named { value, .. } => uses_varargs(value),
+        TableField::Expr { index, value, .. } => uses_varargs(index) || uses_varargs(value),
+    })
+}
+
+fn if_else_uses_varargs(node: &IfElseExpr) -> bool {
+    uses_varargs(node.condition())
+        || uses_varargs(node.value())
+        || node
+            .elseifs()
+            .iter()
+            .any(|v| uses_varargs(v.condition()) || uses_varargs(v.value()))
+        || uses_varargs(node.else_value())
+}
+
+/// Checks if the expression uses `...` of the current function,
+/// varargs inside of function literals are not counted.
+fn uses_varargs(expr: &Expr) -> bool {
+    match expr {
+        Expr::Binary(node) => uses_varargs(node.left()) || uses_varargs(node.right()),
+        Expr::IfElse(node) => if_else_uses_varargs(node),
+        Expr::Literal(Literal::Varargs(..)) => true,
+        Expr::Literal(Literal::Table(table)) => table_uses_varargs(table),
+        Expr::Literal(Literal::InterpolatedStr(node)) => node.exprs().iter().any(uses_varargs),
+        Expr::Literal(..) => false,
+        Expr::Paren(node) => uses_varargs(node),
+        Expr::Suffixed(node) => {
+            uses_varargs(node.base())
+                || match node.suffix() {
+                    SuffixKind::Call(Args::ExprList(list)) => list.iter().any(uses_varargs),
+                    SuffixKind::Call(Args::Table(table)) => table_uses_varargs(table),
+                    SuffixKind::Computed(index) => uses_varargs(index),
+                    _ => false,
+                }
+        }
+        Expr::TypeAssertion(node) => uses_varargs(node.base()),
+        Expr::Unary(node) => uses_varargs(node.expr()),
+    }
+}
+
+/// Writes a number literal the way the target can read it. Digit
+/// separators and binary literals are only in Luau, hexadecimal
+/// floats are not in Lua 5.1 and Luau.
+fn number_source(text: &str, value: NumberValue, target: LuaTarget) -> String {
+    let lower = text.to_ascii_lowercase();
+    let is_binary = lower.starts_with("0b");
+    let is_hex_float = lower.starts_with("0x") && (lower.contains('.') || lower.contains('p'));
+
+    match value {
+        NumberValue::Integer(value) if is_binary && target != LuaTarget::Luau => {
+            if value < 0 {
+                format!("0x{:x}", value)
+            } else {
+                value.to_string()
+            }
+        }
+        NumberValue::Float(value)
+            if is_hex_float && matches!(target, LuaTarget::Lua51 | LuaTarget::Luau) =>
+        {
+            format!("{:?}", value)
+        }
+        _ if target != LuaTarget::Luau => text.replace('_', ""),
+        _ => text.to_string(),
+    }
+}
+
+/// Rewrites the escape sequences of a quoted string that Lua 5.1 does
+/// not have, `\x41` and `\u{41}` become decimal escapes and `\z` skips
+/// the whitespaces after it.
+fn lua51_string(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.char_indices().peekable();
+    let write_bytes = |result: &mut String, bytes: &[u8]| {
+        for byte in bytes {
+            result.push_str(&format!("\\{:03}", byte));
+        }
+    };
+    while let Some((_, char)) = chars.next() {
+        if char != '\\' {
+            result.push(char);
+            continue;
+        }
+        match chars.next() {
+            Some((idx, 'x')) => {
+                let code = u8::from_str_radix(&text[idx + 1..idx + 3], 16).unwrap();
+                write_bytes(&mut result, &[code]);
+                chars.nth(1);
+            }
+            Some((idx, 'u')) => {
+                let end = idx + text[idx..].find('}').unwrap();
+                let code = u32::from_str_radix(&text[idx + 2..end], 16).unwrap();
+                // surrogates are allowed in Lua, they can't be a `char`
+                let bytes = match code {
+                    0..=0x7F => vec![code as u8],
+                    0x80..=0x7FF => vec![0xC0 | (code >> 6) as u8, 0x80 | (code & 0x3F) as u8],
+                    0x800..=0xFFFF => vec![
+                        0xE0 | (code >> 12) as u8,
+                        0x80 | ((code >> 6) & 0x3F) as u8,
+                        0x80 | (code & 0x3F) as u8,
+                    ],
+                    _ => vec![
+                        0xF0 | (code >> 18) as u8,
+                        0x80 | ((code >> 12) & 0x3F) as u8,
+                        0x80 | ((code >> 6) & 0x3F) as u8,
+                        0x80 | (code & 0x3F) as u8,
+                    ],
+                };
+                write_bytes(&mut result, &bytes);
+                while chars.next_if(|(idx, _)| *idx <= end).is_some() {}
+            }
+            Some((_, 'z')) => while chars.next_if(|(_, v)| v.is_ascii_whitespace()).is_some() {},
+            Some((_, escaped)) => {
+                result.push('\\');
+                result.push(escaped);
+            }
+            None => result.push('\\'),
+        }
+    }
+    result
+}
+
+/// Checks if the expression may have more than one value.
+fn is_multiple_values(expr: &Expr) -> bool {
+    match expr {
+        Expr::Literal(Literal::Varargs(..)) => true,
+        Expr::Suffixed(node) => matches!(node.suffix(), SuffixKind::Call(..)),
+        Expr::TypeAssertion(node) => is_multiple_values(node.base()),
+        _ => false,
+    }
+}
+
+/// How `continue` is written inside of a loop.
+#[derive(Debug, Clone, Copy)]
+enum LoopLowering {
+    /// The target has `continue` or the loop does not use it.
+    Native,
+    /// `continue` jumps to the `continue` label at the end of the loop body.
+    Goto,
+    /// The loop body is wrapped with `repeat ... until true` and `continue`
+    /// breaks out of it. The flag is set if the loop body uses `break`.
+    Wrapped { flag: bool },
+}
+
+/// A loop being written.
+struct LoopState<'a> {
+    lowering: LoopLowering,
+    /// The condition of a `repeat` loop that is not written natively
+    /// and the local its value is stored in.
+    until: Option<(&'a Expr, String)>,
+    /// The local telling whether the body of a `Wrapped` loop with
+    /// `break` ran to its end.
+    continued: Option<String>,
+}
+
+fn is_continue(stmt: &Stmt) -> bool {
+    matches!(stmt, Stmt::Continue(..))
+}
+
+fn is_break(stmt: &Stmt) -> bool {
+    matches!(stmt, Stmt::Break(..))
+}
+
+/// Checks if the loop body has a `continue` or `break` statement
+/// (depends on `is_jump`) of the loop itself. Statements inside
+/// of nested loops and functions are not counted.
+fn has_loop_jump(block: &Block, is_jump: fn(&Stmt) -> bool) -> bool {
+    block.last_stmt().as_deref().map(is_jump).unwrap_or(false)
+        || block.stmts().iter().any(|stmt| match stmt {
+            Stmt::Do(node) => has_loop_jump(node.block(), is_jump),
+            Stmt::If(node) => {
+                has_loop_jump(node.block(), is_jump)
+                    || node
+                        .elseifs()
+                        .iter()
+                        .any(|v| has_loop_jump(v.block(), is_jump))
+                    || node
+                        .else_block()
+                        .as_ref()
+                        .map(|v| has_loop_jump(v, is_jump))
+                        .unwrap_or(false)
+            }
+            _ => false,
+        })
+}
+
+/// Gives the name that `require(name)` is rewritten to.
+type RequireNames<'a> = Box<dyn Fn(&str) -> Option<String> + 'a>;
+
+/// Generates Lua source code out from Salite AST nodes.
+pub struct Emitter<'a> {
+    source: &'a str,
+    target: LuaTarget,
+    output: String,
+    indentation: usize,
+    line_start: bool,
+    /// Loops being written, the innermost one is the last.
+    loops: Vec<LoopState<'a>>,
+    require_names: Option<RequireNames<'a>>,
+
+    // source map stuff
+    line_offsets: Vec<usize>,
+    mappings: Vec<Mapping>,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Emitter<'a> {
+    pub fn new(source: &'a str, target: LuaTarget) -> Self {
+        let mut line_offsets = vec![0];
+        for (offset, c) in source.char_indices() {
+            if c == '\n' {
+                line_offsets.push(offset + 1);
+            }
+        }
+        Self {
+            source,
+            target,
+            output: String::new(),
+            indentation: 0,
+            line_start: true,
+            loops: Vec::new(),
+            require_names: None,
+            line_offsets,
+            mappings: Vec::new(),
+            line: 0,
+            column: 0,
+        }
+    }
+
+    /// Rewrites the name of every `require` call with a string literal,
+    /// names that are not given back are written as they are.
+    pub fn set_require_names(&mut self, names: impl Fn(&str) -> Option<String> + 'a) {
+        self.require_names = Some(Box::new(names));
+    }
+
+    pub fn emit_file(&mut self, file: &'a File) {
+        self.visit_block(file.block());
+    }
+
+    /// Consumes the emitter and returns the generated code.
+    pub fn finish(self) -> String {
+        self.output
+    }
+
+    /// Consumes the emitter and returns the generated code
+    /// with its source map.
+    pub fn finish_with_source_map(self, file: String, source: String) -> (String, SourceMap) {
+        let map = SourceMap::new(file, source, &self.mappings);
+        (self.output, map)
+    }
+
+    /// Gets the position of the source code from an offset.
+    pub fn source_position(&self, offset: usize) -> Position {
+        let line = match self.line_offsets.binary_search(&offset) {
+            Ok(line) => line,
+            Err(line) => line - 1,
+        };
+        let line_offset = self.line_offsets[line];
+        let column = self
+            .source
+            .get(line_offset..offset)
+            .map(|v| v.chars().count())
+            .unwrap_or(0);
+        Position::new(line + 1, column + 1)
+    }
+
+    /// Marks the current location of the generated code
+    /// where it comes from the source code.
+    fn mark(&mut self, span: Span) {
+        let column = if self.line_start {
+            self.indentation * INDENT.len()
+        } else {
+            self.column
+        };
+        if let Some(last) = self.mappings.last() {
+            if last.generated_line == self.line && last.generated_column == column {
+                return;
+            }
+        }
+        let position = self.source_position(span.start);
+        self.mappings.push(Mapping {
+            generated_line: self.line,
+            generated_column: column,
+            source_line: position.line() - 1,
+            source_column: position.column() - 1,
+        });
+    }
+
+    fn write(&mut self, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        if self.line_start {
+            self.output.push_str(&INDENT.repeat(self.indentation));
+            self.column = self.indentation * INDENT.len();
+            self.line_start = false;
+        }
+        for c in text.chars() {
+            if c == '\n' {
+                self.line += 1;
+                self.column = 0;
+            } else {
+                self.column += 1;
+            }
+        }
+        self.output.push_str(text);
+    }
+
+    fn write_token(&mut self, token: &Token) {
+        self.mark(token.span());
+        match token.ty() {
+            TokenType::Identifier(..) | TokenType::Symbol(..) => {
+                self.write(&token.ty().as_name());
+            }
+            TokenType::Number(number, value) => {
+                self.write(&number_source(number, *value, self.target));
+            }
+            // string literals have different variants, it is better
+            // to take it from the source code itself.
+            TokenType::Str(..) | TokenType::InterpolatedStr(..) => {
+                let span = token.span();
+                let source = &self.source[span.range()];
+                // long strings do not have escape sequences
+                if self.target == LuaTarget::Lua51 && !source.starts_with('[') {
+                    self.write(&lua51_string(source));
+                } else {
+                    self.write(source);
+                }
+            }
+            _ => unreachable!("unexpected token {:?}", token.ty()),
+        }
+    }
+
+    fn newline(&mut self) {
+        self.output.push('\n');
+        self.line_start = true;
+        self.line += 1;
+        self.column = 0;
+    }
+
+    fn write_list<T>(&mut self, list: &'a [T], mut callback: impl FnMut(&mut Self, &'a T)) {
+        for (id, node) in list.iter().enumerate() {
+            if id > 0 {
+                self.write(", ");
+            }
+            callback(self, node);
+        }
+    }
+
+    fn write_exprlist(&mut self, list: &'a [Expr]) {
+        self.write_list(list, |this, expr| this.visit_expr(expr));
+    }
+
+    /// Writes the block and the `end` keyword after it. It will
+    /// write into one line if the block has nothing to emit.
+    fn write_block_end(&mut self, block: &'a Block) {
+        self.write_block_with(block, "end");
+    }
+
+    fn write_block_with(&mut self, block: &'a Block, closing: &str) {
+        if is_block_empty(block) {
+            self.write(" ");
+        } else {
+            self.newline();
+            self.indentation += 1;
+            self.visit_block(block);
+            self.indentation -= 1;
+        }
+        self.write(closing);
+    }
+
+    fn write_function_body(&mut self, body: &'a FunctionBody) {
+        self.write("(");
+        self.write_list(body.params(), |this, param| this.write_token(&param.name));
+        if body.varidiac().is_some() {
+            if !body.params().is_empty() {
+                self.write(", ");
+            }
+            self.write("...");
+        }
+        self.write(")");
+
+        let defaults = body
+            .params()
+            .iter()
+            .filter(|v| v.default.is_some())
+            .collect::<Vec<&'a Param>>();
+
+        // loops outside of the function are not visible inside of it
+        let loops = std::mem::take(&mut self.loops);
+        if defaults.is_empty() {
+            self.write_block_end(body.block());
+            self.loops = loops;
+            return;
+        }
+
+        self.newline();
+        self.indentation += 1;
+        for param in defaults {
+            self.mark(param.span);
+            self.write("if ");
+            self.write_token(&param.name);
+            self.write(" == nil then ");
+            self.write_token(&param.name);
+            self.write(" = ");
+            self.visit_expr(param.default.as_ref().unwrap());
+            self.write(" end");
+            self.newline();
+        }
+        self.visit_block(body.block());
+        self.indentation -= 1;
+        self.write("end");
+        self.loops = loops;
+    }
+
+    fn loop_lowering(&self, block: &Block) -> LoopLowering {
+        if self.target == LuaTarget::Luau || !has_loop_jump(block, is_continue) {
+            LoopLowering::Native
+        } else if self.target == LuaTarget::Lua51 {
+            LoopLowering::Wrapped {
+                flag: has_loop_jump(block, is_break),
+            }
+        } else {
+            LoopLowering::Goto
+        }
+    }
+
+    /// Gives a name for a generated local, it does not collide with the
+    /// names of the source code nor the locals of the loops being written.
+    fn generated_name(&self, name: &str) -> String {
+        let is_used = |name: &str| {
+            self.source.contains(name)
+                || self.loops.iter().any(|v| {
+                    v.until.as_ref().map(|v| v.1.as_str()) == Some(name)
+                        || v.continued.as_deref() == Some(name)
+                })
+        };
+        let mut result = format!("__{}", name);
+        let mut idx = 0;
+        while is_used(&result) {
+            idx += 1;
+            result = format!("__{}{}", name, idx);
+        }
+        result
+    }
+
+    /// Computes the condition of the `repeat` loop being written into
+    /// its local, the locals of the loop body are not visible in `until`
+    /// once the body is rewritten.
+    fn write_until_flag(&mut self) {
+        if let Some((condition, name)) = self.loops.last().and_then(|v| v.until.clone()) {
+            self.write(&name);
+            self.write(" = ");
+            self.visit_expr(condition);
+            self.newline();
+        }
+    }
+
+    /// Writes the body of a loop and the closing keyword after it.
+    /// Targets without `continue` get the body rewritten if it has
+    /// any `continue` statements inside of it.
+    fn write_loop_block(
+        &mut self,
+        block: &'a Block,
+        closing: &str,
+        until: Option<(&'a Expr, String)>,
+    ) {
+        let lowering = self.loop_lowering(block);
+        let continued = match lowering {
+            LoopLowering::Wrapped { flag: true } => Some(self.generated_name("continue")),
+            _ => None,
+        };
+
+        let has_until = until.is_some();
+        self.loops.push(LoopState {
+            lowering,
+            until,
+            continued: continued.clone(),
+        });
+        match lowering {
+            LoopLowering::Native => self.write_block_with(block, closing),
+            LoopLowering::Goto => {
+                self.newline();
+                self.indentation += 1;
+                // the label cannot be placed after `return` or `break`, and
+                // it cannot jump into the scope of the locals before `until`
+                if has_until
+                    || matches!(
+                        block.last_stmt().as_deref(),
+                        Some(Stmt::Return(..) | Stmt::Break(..))
+                    )
+                {
+                    self.write("do");
+                    self.newline();
+                    self.indentation += 1;
+                    self.visit_block(block);
+                    if block.last_stmt().is_none() {
+                        self.write_until_flag();
+                    }
+                    self.indentation -= 1;
+                    self.write("end");
+                    self.newline();
+                } else {
+                    self.visit_block(block);
+                }
+                self.write("::continue::");
+                self.newline();
+                self.indentation -= 1;
+                self.write(closing);
+            }
+            // `break` stops the `repeat ... until true` loop instead of
+            // the real loop, the flag tells whether it has to stop as well.
+            LoopLowering::Wrapped { .. } => {
+                self.newline();
+                self.indentation += 1;
+                if let Some(continued) = &continued {
+                    self.write("local ");
+                    self.write(continued);
+                    self.write(" = false");
+                    self.newline();
+                }
+                self.write("repeat");
+                self.newline();
+                self.indentation += 1;
+                self.visit_block(block);
+                if block.last_stmt().is_none() {
+                    self.write_until_flag();
+                }
+                if let Some(continued) = continued.as_ref().filter(|_| block.last_stmt().is_none())
+                {
+                    self.write(continued);
+                    self.write(" = true");
+                    self.newline();
+                }
+                self.indentation -= 1;
+                self.write("until true");
+                self.newline();
+                if let Some(continued) = &continued {
+                    self.write("if not ");
+                    self.write(continued);
+                    self.write(" then break end");
+                    self.newline();
+                }
+                self.indentation -= 1;
+                self.write(closing);
+            }
+        }
+        self.loops.pop();
+    }
+
+    fn write_function_assign_name(&mut self, name: &'a FunctionAssignName) {
+        match name {
+            FunctionAssignName::Property(base, name) => {
+                self.write_function_assign_name(base);
+                self.write(".");
+                self.write_token(name);
+            }
+            FunctionAssignName::Method(base, name) => {
+                self.write_function_assign_name(base);
+                self.write(":");
+                self.write_token(name);
+            }
+            FunctionAssignName::Name(name) => self.write_token(name),
+        }
+    }
+
+    fn write_expr_with_parens(&mut self, expr: &'a Expr, parens: bool) {
+        if parens {
+            self.write("(");
+            self.visit_expr(expr);
+            self.write(")");
+        } else {
+            self.visit_expr(expr);
+        }
+    }
+
+    fn write_args(&mut self, args: &'a Args) {
+        match args {
+            Args::ExprList(list) => {
+                self.write("(");
+                self.write_exprlist(list);
+                self.write(")");
+            }
+            Args::Table(table) => self.visit_table_ctor_expr(table),
+            Args::Str(token) => self.write_token(token),
+        }
+    }
+
+    fn write_nilsh_coalescing(&mut self, node: &'a Binary) {
+        let left: &Expr = node.left();
+        if self.target == LuaTarget::Luau && matches!(left, Expr::Literal(Literal::Name(..))) {
+            // names are free to evaluate twice
+            self.write("(if ");
+            self.visit_expr(left);
+            self.write(" == nil then ");
+            self.visit_expr(node.right());
+            self.write(" else ");
+            self.visit_expr(left);
+            self.write(")");
+            return;
+        }
+
+        // the left operand must be evaluated once and the right operand
+        // only if the left one is nil. Both of them are passed as arguments
+        // so names in the right operand will not be shadowed. Varargs are
+        // passed through and the right operand only gives one value.
+        let varargs = uses_varargs(node.right());
+        self.write(if varargs {
+            "(function(l, r, ...) if l == nil then return (r(...)) end return l end)("
+        } else {
+            "(function(l, r) if l == nil then return (r()) end return l end)("
+        });
+        self.visit_expr(left);
+        self.write(if varargs {
+            ", function(...) return "
+        } else {
+            ", function() return "
+        });
+        self.visit_expr(node.right());
+        self.write(if varargs { " end, ...)" } else { " end)" });
+    }
+
+    /// Writes a branch value of an if-expression lowered into a function,
+    /// if-expressions only have one value.
+    fn write_if_else_return(&mut self, value: &'a Expr) {
+        self.write("return ");
+        self.write_expr_with_parens(value, is_multiple_values(value));
+    }
+
+    /// Writes the text of an interpolated string segment as the contents
+    /// of a quoted Lua string, `%` is escaped if it is a format string.
+    fn write_interpolated_segment(&mut self, segment: &Token, format: bool) {
+        let text = match segment.ty() {
+            TokenType::InterpolatedStr(_, text) => text,
+            _ => unreachable!("unexpected token {:?}", segment.ty()),
+        };
+
+        let mut contents = String::with_capacity(text.len());
+        let mut chars = text.chars().peekable();
+        while let Some(char) = chars.next() {
+            match char {
+                // backticks and braces are only escaped in interpolated strings
+                '\\' => match chars.next() {
+                    Some(escaped @ ('`' | '{')) => contents.push(escaped),
+                    // line breaks after it are written as escapes
+                    Some('z') => while chars.next_if(char::is_ascii_whitespace).is_some() {},
+                    Some(escaped) => {
+                        contents.push('\\');
+                        contents.push(escaped);
+                    }
+                    None => contents.push('\\'),
+                },
+                '"' => contents.push_str("\\\""),
+                '\n' => contents.push_str("\\n"),
+                '%' if format => contents.push_str("%%"),
+                c => contents.push(c),
+            }
+        }
+        if self.target == LuaTarget::Lua51 {
+            contents = lua51_string(&contents);
+        }
+        self.write(&contents);
+    }
+
+    fn write_var_assign_name(&mut self, name: &'a VarAssignName) {
+        match name {
+            VarAssignName::Name(token) => self.write_token(token),
+            VarAssignName::Suffixed(node) => self.visit_suffixed_expr(node),
+        }
+    }
+
+    /// Writes the value of a compound assignment, the target is
+    /// written as the left operand of the operator.
+    fn write_compound_value(
+        &mut self,
+        kind: &BinopKind,
+        write_target: impl Fn(&mut Self),
+        value: &'a Expr,
+    ) {
+        // Lua 5.1 does not have floor division operator
+        let floor = *kind == BinopKind::FloorDivision && self.target == LuaTarget::Lua51;
+        let kind = if floor { &BinopKind::Divide } else { kind };
+        if floor {
+            self.write("math.floor(");
+        }
+
+        write_target(self);
+        self.write(" ");
+        self.write(binop_str(kind));
+        self.write(" ");
+
+        let precedence = binop_precedence(kind);
+        let value_precedence = expr_precedence(value);
+        self.write_expr_with_parens(
+            value,
+            value_precedence < precedence
+                || (value_precedence == precedence && !kind.is_right_associative()),
+        );
+
+        if floor {
+            self.write(")");
+        }
+    }
+
+    fn write_binary(&mut self, node: &'a Binary, kind: &BinopKind) {
+        let precedence = binop_precedence(kind);
+        let right_associative = kind.is_right_associative();
+
+        let left_precedence = expr_precedence(node.left());
+        self.write_expr_with_parens(
+            node.left(),
+            left_precedence < precedence || (left_precedence == precedence && right_associative),
+        );
+
+        self.write(" ");
+        self.write(binop_str(kind));
+        self.write(" ");
+
+        let right_precedence = expr_precedence(node.right());
+        self.write_expr_with_parens(
+            node.right(),
+            right_precedence < precedence || (right_precedence == precedence && !right_associative),
+        );
+    }
+}
+
+impl<'a> AstVisitor<'a> for Emitter<'a> {
+    type BlockOutput = ();
+
+    fn visit_block(&mut self, node: &'a Block) -> Self::BlockOutput {
+        let mut stmts = node.stmts().iter().filter(|v| !is_erased(v)).peekable();
+
+        while let Some(stmt) = stmts.next() {
+            self.mark(stmt.span());
+            self.visit_stmt(stmt);
+
+            // Lua may treat the parenthesis as a call from the previous statement
+            if stmts.peek().map(|v| stmt_starts_with_paren(v)) == Some(true) {
+                self.write(";");
+            }
+            self.newline();
+        }
+        if let Some(last_stmt) = node.last_stmt() {
+            self.mark(last_stmt.span());
+            self.visit_last_stmt(last_stmt);
+            self.newline();
+        }
+    }
+}
+
+impl<'a> LastStmtVisitor<'a> for Emitter<'a> {
+    type Output = ();
+
+    fn visit_break_stmt(&mut self, _: &'a Token) -> Self::Output {
+        self.write("break");
+    }
+
+    fn visit_continue_stmt(&mut self, _: &'a Token) -> Self::Output {
+        self.write_until_flag();
+        let continued = self.loops.last().and_then(|v| v.continued.clone());
+        match self.loops.last().map(|v| v.lowering) {
+            Some(LoopLowering::Goto) => self.write("goto continue"),
+            Some(LoopLowering::Wrapped { flag: true }) => {
+                self.write(&continued.unwrap());
+                self.write(" = true");
+                self.newline();
+                self.write("break");
+            }
+            Some(LoopLowering::Wrapped { flag: false }) => self.write("break"),
+            _ => self.write("continue"),
+        }
+    }
+
+    fn visit_return_stmt(&mut self, node: &'a ReturnStmt) -> Self::Output {
+        self.write("return");
+        if !node.exprlist().is_empty() {
+            self.write(" ");
+            self.write_exprlist(node.exprlist());
+        }
+    }
+}
+
+impl<'a> StmtVisitor<'a> for Emitter<'a> {
+    type Output = ();
+
+    fn visit_call_stmt(&mut self, node: &'a Expr) -> Self::Output {
+        self.visit_expr(node);
+    }
+
+    fn visit_compound_assign_stmt(&mut self, node: &'a CompoundAssign) -> Self::Output {
+        let kind = &node.op().kind;
+        let (base, suffix) = match node.name() {
+            VarAssignName::Suffixed(target) => (&**target.base(), target.suffix()),
+            name => {
+                self.write_var_assign_name(name);
+                self.write(" = ");
+                self.write_compound_value(
+                    kind,
+                    |this| this.write_var_assign_name(name),
+                    node.expr(),
+                );
+                return;
+            }
+        };
+
+        // names and literals are free to evaluate twice, other
+        // expressions are stored in locals to be evaluated once.
+        let base_local = !matches!(base, Expr::Literal(Literal::Name(..)));
+        let key_local = match suffix {
+            SuffixKind::Computed(key) => !matches!(
+                &**key,
+                Expr::Literal(Literal::Name(..) | Literal::Number(..) | Literal::Str(..))
+            ),
+            _ => false,
+        };
+
+        let base_name = self.generated_name("base");
+        let key_name = self.generated_name("key");
+        let write_target = |this: &mut Self| {
+            if base_local {
+                this.write(&base_name);
+            } else {
+                this.visit_expr(base);
+            }
+            match suffix {
+                SuffixKind::Computed(..) if key_local => {
+                    this.write("[");
+                    this.write(&key_name);
+                    this.write("]");
+                }
+                suffix => this.visit_suffix_kind_expr(suffix),
+            }
+        };
+
+        if !base_local && !key_local {
+            write_target(self);
+            self.write(" = ");
+            self.write_compound_value(kind, write_target, node.expr());
+            return;
+        }
+
+        self.write("do");
+        self.newline();
+        self.indentation += 1;
+        match (base_local, suffix) {
+            (true, SuffixKind::Computed(key)) if key_local => {
+                self.write(&format!("local {}, {} = ", base_name, key_name));
+                self.visit_expr(base);
+                self.write(", ");
+                self.visit_expr(key);
+            }
+            (true, _) => {
+                self.write(&format!("local {} = ", base_name));
+                self.visit_expr(base);
+            }
+            (false, SuffixKind::Computed(key)) => {
+                self.write(&format!("local {} = ", key_name));
+                self.visit_expr(key);
+            }
+            _ => unreachable!(),
+        }
+        self.newline();
+        write_target(self);
+        self.write(" = ");
+        self.write_compound_value(kind, write_target, node.expr());
+        self.newline();
+        self.indentation -= 1;
+        self.write("end");
+    }
+
+    fn visit_do_stmt(&mut self, node: &'a DoStmt) -> Self::Output {
+        self.write("do");
+        self.write_block_end(node.block());
+    }
+
+    fn visit_function_assign_stmt(&mut self, node: &'a FunctionAssign) -> Self::Output {
+        self.write("function ");
+        self.write_function_assign_name(node.name());
+        self.write_function_body(node.body());
+    }
+
+    fn visit_generic_for_stmt(&mut self, node: &'a GenericFor) -> Self::Output {
+        self.write("for ");
+        self.write_list(node.names(), |this, name| this.write_token(name));
+        self.write(" in ");
+        self.write_exprlist(node.exprlist());
+        self.write(" do");
+        self.write_loop_block(node.block(), "end", None);
+    }
+
+    fn visit_if_stmt(&mut self, node: &'a IfStmt) -> Self::Output {
+        self.write("if ");
+        self.visit_expr(node.condition());
+        self.write(" then");
+        self.write_block_with(node.block(), "");
+        for clause in node.elseifs().iter() {
+            self.write("elseif ");
+            self.visit_expr(clause.condition());
+            self.write(" then");
+            self.write_block_with(clause.block(), "");
+        }
+        if let Some(block) = node.else_block() {
+            self.write("else");
+            self.write_block_with(block, "");
+        }
+        self.write("end");
+    }
+
+    fn visit_local_assign_stmt(&mut self, node: &'a LocalAssign) -> Self::Output {
+        self.write("local ");
+        self.write_list(node.names(), |this, name| this.write_token(name.name()));
+        if !node.exprlist().is_empty() {
+            self.write(" = ");
+            self.write_exprlist(node.exprlist());
+        }
+    }
+
+    fn visit_local_function_stmt(&mut self, node: &'a LocalFunction) -> Self::Output {
+        self.write("local function ");
+        self.write_token(node.name());
+        self.write_function_body(node.body());
+    }
+
+    fn visit_numeric_for_stmt(&mut self, node: &'a NumericFor) -> Self::Output {
+        self.write("for ");
+        self.write_token(node.name());
+        self.write(" = ");
+        self.visit_expr(node.start());
+        self.write(", ");
+        self.visit_expr(node.end());
+        if let Some(step) = node.step() {
+            self.write(", ");
+            self.visit_expr(step);
+        }
+        self.write(" do");
+        self.write_loop_block(node.block(), "end", None);
+    }
+
+    fn visit_repeat_stmt(&mut self, node: &'a RepeatStmt) -> Self::Output {
+        if matches!(self.loop_lowering(node.block()), LoopLowering::Native) {
+            self.write("repeat");
+            self.write_loop_block(node.block(), "until ", None);
+            self.visit_expr(node.condition());
+            return;
+        }
+        let until = self.generated_name("until");
+        self.write("local ");
+        self.write(&until);
+        self.write(" = false");
+        self.newline();
+        self.write("repeat");
+        let closing = format!("until {}", until);
+        self.write_loop_block(node.block(), &closing, Some((node.condition(), until)));
+    }
+
+    fn visit_while_stmt(&mut self, node: &'a WhileStmt) -> Self::Output {
+        self.write("while ");
+        self.visit_expr(node.condition());
+        self.write(" do");
+        self.write_loop_block(node.block(), "end", None);
+    }
+
+    fn visit_var_assign_stmt(&mut self, node: &'a VarAssign) -> Self::Output {
+        self.write_list(node.names(), |this, name| this.write_var_assign_name(name));
+        self.write(" = ");
+        self.write_exprlist(node.exprlist());
+    }
+
+    fn visit_type_declaration_stmt(&mut self, _: &'a TypeDeclaration) -> Self::Output {
+        // types are erased at runtime
+    }
+
+    fn visit_declare_function_stmt(&mut self, _: &'a DeclareFunction) -> Self::Output {
+        // declarations are erased at runtime
+    }
+
+    fn visit_declare_local_stmt(&mut self, _: &'a DeclareLocal) -> Self::Output {
+        // declarations are erased at runtime
+    }
+
+    fn visit_declare_module_stmt(&mut self, _: &'a DeclareModule) -> Self::Output {
+        // declarations are erased at runtime
+    }
+}
+
+impl<'a> ExprVisitor<'a> for Emitter<'a> {
+    type Output = ();
+
+    fn visit_bool_expr(&mut self, node: &'a Token) -> Self::Output {
+        self.write_token(node);
+    }
+
+    fn visit_function_expr(&mut self, node: &'a FunctionExpr) -> Self::Output {
+        self.write("function");
+        self.write_function_body(node.body());
+    }
+
+    fn visit_interpolated_str_expr(&mut self, node: &'a InterpolatedStr) -> Self::Output {
+        let segments = node.segments();
+        if self.target == LuaTarget::Luau {
+            let mut exprs = node.exprs().iter();
+            for segment in segments.iter() {
+                self.write_token(segment);
+                if let Some(expr) = exprs.next() {
+                    self.visit_expr(expr);
+                }
+            }
+            return;
+        }
+
+        self.mark(node.span());
+        if node.exprs().is_empty() {
+            self.write("\"");
+            self.write_interpolated_segment(&segments[0], false);
+            self.write("\"");
+            return;
+        }
+
+        self.write("string.format(\"");
+        for (idx, segment) in segments.iter().enumerate() {
+            if idx > 0 {
+                self.write("%s");
+            }
+            self.write_interpolated_segment(segment, true);
+        }
+        self.write("\"");
+        for expr in node.exprs().iter() {
+            self.write(", ");
+            // `%s` of Lua 5.1 does not call `tostring`. Expressions without
+            // any values are written as `nil`, they must have one value.
+            if self.target == LuaTarget::Lua51 {
+                self.write("tostring(");
+                self.write_expr_with_parens(expr, is_multiple_values(expr));
+                self.write(")");
+            } else {
+                self.write_expr_with_parens(expr, is_multiple_values(expr));
+            }
+        }
+        self.write(")");
+    }
+
+    fn visit_name_expr(&mut self, node: &'a Token) -> Self::Output {
+        self.write_token(node);
+    }
+
+    fn visit_number_expr(&mut self, node: &'a Token) -> Self::Output {
+        self.write_token(node);
+    }
+
+    fn visit_nil_expr(&mut self, _: &'a Token) -> Self::Output {
+        self.write("nil");
+    }
+
+    fn visit_str_expr(&mut self, node: &'a Token) -> Self::Output {
+        self.write_token(node);
+    }
+
+    fn visit_table_ctor_expr(&mut self, node: &'a TableCtor) -> Self::Output {
+        self.write("{");
+        self.write_list(node.fields(), |this, field| match field {
+            TableField::Array(value) => this.visit_expr(value),
+            TableField::Named { name, value, .. } => {
+                this.write_token(name);
+                this.write(" = ");
+                this.visit_expr(value);
+            }
+            TableField::Expr { index, value, .. } => {
+                this.write("[");
+                this.visit_expr(index);
+                this.write("] = ");
+                this.visit_expr(value);
+            }
+        });
+        self.write("}");
+    }
+
+    fn visit_varargs_expr(&mut self, _: &'a Token) -> Self::Output {
+        self.write("...");
+    }
+
+    fn visit_binary_expr(&mut self, node: &'a Binary) -> Self::Output {
+        match &node.op().kind {
+            BinopKind::NilshCoalescing => self.write_nilsh_coalescing(node),
+            // Lua 5.1 does not have floor division operator
+            BinopKind::FloorDivision if self.target == LuaTarget::Lua51 => {
+                self.write("math.floor(");
+                self.write_binary(node, &BinopKind::Divide);
+                self.write(")");
+            }
+            kind => self.write_binary(node, kind),
+        }
+    }
+
+    fn visit_if_else_expr(&mut self, node: &'a IfElseExpr) -> Self::Output {
+        if self.target == LuaTarget::Luau {
+            self.write("if ");
+            self.visit_expr(node.condition());
+            self.write(" then ");
+            self.visit_expr(node.value());
+            for clause in node.elseifs().iter() {
+                self.write(" elseif ");
+                self.visit_expr(clause.condition());
+                self.write(" then ");
+                self.visit_expr(clause.value());
+            }
+            self.write(" else ");
+            self.visit_expr(node.else_value());
+            return;
+        }
+
+        // other targets do not have if-expressions, only the taken
+        // branch is evaluated inside of the function. Varargs are passed
+        // through since they cannot be used in the function itself.
+        let varargs = if_else_uses_varargs(node);
+        self.write(if varargs {
+            "(function(...) if "
+        } else {
+            "(function() if "
+        });
+        self.visit_expr(node.condition());
+        self.write(" then ");
+        self.write_if_else_return(node.value());
+        for clause in node.elseifs().iter() {
+            self.write(" elseif ");
+            self.visit_expr(clause.condition());
+            self.write(" then ");
+            self.write_if_else_return(clause.value());
+        }
+        self.write(" else ");
+        self.write_if_else_return(node.else_value());
+        self.write(if varargs {
+            " end end)(...)"
+        } else {
+            " end end)()"
+        });
+    }
+
+    fn visit_paren_expr(&mut self, node: &'a Expr) -> Self::Output {
+        self.write("(");
+        self.visit_expr(node);
+        self.write(")");
+    }
+
+    fn visit_suffixed_expr(&mut self, node: &'a Suffixed) -> Self::Output {
+        let required = match (&self.require_names, require_argument(node)) {
+            (Some(names), Some(name)) => names(name),
+            _ => None,
+        };
+        if let Some(name) = required {
+            self.visit_expr(node.base());
+            self.write(&format!("({})", quote(&name)));
+            return;
+        }
+        self.write_expr_with_parens(node.base(), !is_prefix_expr(node.base()));
+        self.visit_suffix_kind_expr(node.suffix());
+    }
+
+    fn visit_type_assertion_expr(&mut self, node: &'a TypeAssertion) -> Self::Output {
+        self.visit_expr(node.base());
+    }
+
+    fn visit_unary_expr(&mut self, node: &'a Unary) -> Self::Output {
+        let expr: &Expr = node.expr();
+        match node.op().kind {
+            UnopKind::Length => self.write("#"),
+            UnopKind::Not => self.write("not "),
+            UnopKind::Negate => self.write("-"),
+        };
+
+        // `- -a` must not be written as a comment
+        let parens = expr_precedence(expr) < UNARY_PRECEDENCE
+            || matches!(expr, Expr::Unary(node) if node.op().kind == UnopKind::Negate);
+
+        self.write_expr_with_parens(expr, parens);
+    }
+
+    fn visit_suffix_kind_expr(&mut self, node: &'a SuffixKind) -> Self::Output {
+        match node {
+            SuffixKind::Call(args) => self.write_args(args),
+            SuffixKind::Computed(index) => {
+                self.write("[");
+                self.visit_expr(index);
+                self.write("]");
+            }
+            SuffixKind::Method(name) => {
+                self.write(":");
+                self.write_token(name);
+            }
+            SuffixKind::Name(name) => {
+                self.write(".");
+                self.write_token(name);
+            }
+        }
+    }
+}
@@ -0,0 +1,55 @@
+mod bundle;
+mod emitter;
+mod sourcemap;
+
+pub use bundle::*;
+pub use emitter::*;
+pub use sourcemap::*;
+
+use salite_ast as ast;
+use salite_common::LuaTarget;
+
+/// Emits plain Lua source code from a parsed Salite file. Every
+/// type-only construct is erased along the way.
+///
+/// `source` must be the same source code the file is parsed from,
+/// string literals are taken from it as is. Some constructs are
+/// lowered differently depending on the `target`.
+pub fn emit_file(file: &ast::File, source: &str, target: LuaTarget) -> String {
+    let mut emitter = Emitter::new(source, target);
+    emitter.emit_file(file);
+    emitter.finish()
+}
+
+/// Emits a module of a bundle from a parsed Salite file like [`emit_file`].
+/// The name of every `require` call with a string literal is given to
+/// `names`, and it is rewritten to the name that `names` gives back.
+pub fn emit_bundle_module(
+    file: &ast::File,
+    source: &str,
+    target: LuaTarget,
+    names: impl Fn(&str) -> Option<String>,
+) -> String {
+    let mut emitter = Emitter::new(source, target);
+    emitter.set_require_names(names);
+    emitter.emit_file(file);
+    emitter.finish()
+}
+
+/// Emits plain Lua source code from a parsed Salite file with the
+/// source map of it. `output_path` and `source_path` are the paths
+/// written into the source map, `require` calls are rewritten with
+/// `names` like [`emit_bundle_module`].
+pub fn emit_file_with_source_map(
+    file: &ast::File,
+    source: &str,
+    target: LuaTarget,
+    output_path: String,
+    source_path: String,
+    names: impl Fn(&str) -> Option<String>,
+) -> (String, SourceMap) {
+    let mut emitter = Emitter::new(source, target);
+    emitter.set_require_names(names);
+    emitter.emit_file(file);
+    emitter.finish_with_source_map(output_path, source_path)
+}
@@ -0,0 +1,286 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+const BASE64_CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+const VLQ_SHIFT: u32 = 5;
+const VLQ_CONTINUATION_BIT: i64 = 1 << VLQ_SHIFT;
+const VLQ_MASK: i64 = VLQ_CONTINUATION_BIT - 1;
+
+/// Errors given when decoding the mappings of a source map.
+#[derive(Debug, Error)]
+pub enum SourceMapError {
+    #[error("Invalid base64 character {0:?} in mappings")]
+    InvalidBase64(char),
+
+    #[error("Unexpected end of VLQ segment in mappings")]
+    IncompleteSegment,
+
+    #[error("Invalid segment length {0} in mappings")]
+    InvalidSegment(usize),
+
+    #[error("Failed to parse source map: {0}")]
+    Parse(serde_json::Error),
+}
+
+/// A location of the generated code and where it is originally
+/// from in the source code. All lines and columns are zero based.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mapping {
+    pub generated_line: usize,
+    pub generated_column: usize,
+    pub source_line: usize,
+    pub source_column: usize,
+}
+
+/// Source Map revision 3 for every emitted Lua file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SourceMap {
+    pub version: u8,
+    pub file: String,
+    #[serde(rename = "sourceRoot", default)]
+    pub source_root: String,
+    pub sources: Vec<String>,
+    #[serde(default)]
+    pub names: Vec<String>,
+    pub mappings: String,
+}
+
+fn encode_vlq(output: &mut String, value: i64) {
+    let mut value = if value < 0 {
+        ((-value) << 1) | 1
+    } else {
+        value << 1
+    };
+    loop {
+        let mut digit = value & VLQ_MASK;
+        value >>= VLQ_SHIFT;
+        if value > 0 {
+            digit |= VLQ_CONTINUATION_BIT;
+        }
+        output.push(BASE64_CHARS[digit as usize] as char);
+        if value == 0 {
+            break;
+        }
+    }
+}
+
+fn decode_segment(segment: &str) -> Result<Vec<i64>, SourceMapError> {
+    let mut values = Vec::new();
+    let mut value = 0;
+    let mut shift = 0;
+    let mut continued = false;
+    for c in segment.chars() {
+        let digit = BASE64_CHARS
+            .iter()
+            .position(|v| *v as char == c)
+            .ok_or(SourceMapError::InvalidBase64(c))? as i64;
+
+        value += (digit & VLQ_MASK) << shift;
+        continued = digit & VLQ_CONTINUATION_BIT != 0;
+        if continued {
+            shift += VLQ_SHIFT;
+        } else {
+            let negative = value & 1 == 1;
+            value >>= 1;
+            values.push(if negative { -value } else { value });
+            value = 0;
+            shift = 0;
+        }
+    }
+    if continued {
+        return Err(SourceMapError::IncompleteSegment);
+    }
+    Ok(values)
+}
+
+impl SourceMap {
+    /// Creates a new source map with only one source file.
+    pub fn new(file: String, source: String, mappings: &[Mapping]) -> Self {
+        Self {
+            version: 3,
+            file,
+            source_root: String::new(),
+            sources: vec![source],
+            names: Vec::new(),
+            mappings: Self::encode_mappings(mappings),
+        }
+    }
+
+    pub fn from_json(contents: &str) -> Result<Self, SourceMapError> {
+        serde_json::from_str(contents).map_err(SourceMapError::Parse)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+
+    /// Encodes the mappings into a VLQ encoded string. Mappings must be
+    /// sorted by their generated location beforehand.
+    pub fn encode_mappings(mappings: &[Mapping]) -> String {
+        let mut output = String::new();
+        let mut line = 0;
+        let mut previous_column = 0;
+        let mut previous_source_line = 0;
+        let mut previous_source_column = 0;
+        let mut first_in_line = true;
+
+        for mapping in mappings.iter() {
+            while line < mapping.generated_line {
+                output.push(';');
+                line += 1;
+                previous_column = 0;
+                first_in_line = true;
+            }
+            if !first_in_line {
+                output.push(',');
+            }
+            encode_vlq(
+                &mut output,
+                mapping.generated_column as i64 - previous_column as i64,
+            );
+            // there's only one source file per map
+            encode_vlq(&mut output, 0);
+            encode_vlq(
+                &mut output,
+                mapping.source_line as i64 - previous_source_line as i64,
+            );
+            encode_vlq(
+                &mut output,
+                mapping.source_column as i64 - previous_source_column as i64,
+            );
+            previous_column = mapping.generated_column;
+            previous_source_line = mapping.source_line;
+            previous_source_column = mapping.source_column;
+            first_in_line = false;
+        }
+
+        output
+    }
+
+    /// Decodes the VLQ encoded mappings. Segments that do not point
+    /// to any source location are skipped.
+    pub fn decode_mappings(&self) -> Result<Vec<Mapping>, SourceMapError> {
+        let mut mappings = Vec::new();
+        let mut source_line = 0;
+        let mut source_column = 0;
+        let mut source_id = 0;
+
+        for (generated_line, line) in self.mappings.split(';').enumerate() {
+            let mut generated_column = 0;
+            for segment in line.split(',').filter(|v| !v.is_empty()) {
+                let values = decode_segment(segment)?;
+                match values.len() {
+                    1 => {
+                        generated_column += values[0];
+                        continue;
+                    }
+                    4 | 5 => {}
+                    len => return Err(SourceMapError::InvalidSegment(len)),
+                }
+                generated_column += values[0];
+                source_id += values[1];
+                source_line += values[2];
+                source_column += values[3];
+                if source_id != 0 {
+                    continue;
+                }
+                mappings.push(Mapping {
+                    generated_line,
+                    generated_column: generated_column as usize,
+                    source_line: source_line as usize,
+                    source_column: source_column as usize,
+                });
+            }
+        }
+
+        Ok(mappings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mappings_roundtrip() {
+        let mappings = vec![
+            Mapping {
+                generated_line: 0,
+                generated_column: 0,
+                source_line: 2,
+                source_column: 4,
+            },
+            Mapping {
+                generated_line: 0,
+                generated_column: 6,
+                source_line: 2,
+                source_column: 10,
+            },
+            Mapping {
+                generated_line: 3,
+                generated_column: 1,
+                source_line: 1,
+                source_column: 0,
+            },
+            Mapping {
+                generated_line: 3,
+                generated_column: 40,
+                source_line: 120,
+                source_column: 33,
+            },
+        ];
+        let map = SourceMap::new("init.lua".into(), "init.slt".into(), &mappings);
+        assert_eq!(map.decode_mappings().unwrap(), mappings);
+    }
+
+    fn emit(source: &str) -> (String, Vec<Mapping>) {
+        let tokens = salite_tokenizer::tokenize(source).unwrap();
+        let tokens = salite_ast::filter_non_trivia_tokens(tokens);
+        let file = salite_parser::parse_file(false, &tokens).unwrap();
+        let (code, map) = crate::emit_file_with_source_map(
+            &file,
+            source,
+            salite_common::LuaTarget::Lua54,
+            "init.lua".into(),
+            "init.slt".into(),
+            |_| None,
+        );
+        let map = SourceMap::from_json(&map.to_json()).unwrap();
+        (code, map.decode_mappings().unwrap())
+    }
+
+    fn source_of(mappings: &[Mapping], line: usize, column: usize) -> Option<(usize, usize)> {
+        mappings
+            .iter()
+            .find(|v| v.generated_line == line && v.generated_column == column)
+            .map(|v| (v.source_line, v.source_column))
+    }
+
+    #[test]
+    fn emitted_mappings() {
+        let (code, mappings) =
+            emit("local a: number = 1\n\nlocal function f(x: number)\n    return x + a\nend\n");
+        assert_eq!(
+            code,
+            "local a = 1\nlocal function f(x)\n\treturn x + a\nend\n"
+        );
+
+        // erased type annotations shift the columns
+        assert_eq!(source_of(&mappings, 0, 0), Some((0, 0)));
+        assert_eq!(source_of(&mappings, 0, 10), Some((0, 18)));
+        // the empty line is not emitted
+        assert_eq!(source_of(&mappings, 1, 0), Some((2, 0)));
+        assert_eq!(source_of(&mappings, 2, 1), Some((3, 4)));
+        assert_eq!(source_of(&mappings, 2, 12), Some((3, 15)));
+    }
+
+    #[test]
+    fn encode_vlq_values() {
+        let mut output = String::new();
+        encode_vlq(&mut output, 0);
+        encode_vlq(&mut output, -1);
+        encode_vlq(&mut output, 16);
+        assert_eq!(output, "ADgB");
+    }
+}
@@ -15,6 +15,79 @@ pub enum ConfigError {
     Parse(serde_json::Error),
 }
 
+/// Lua dialect where the emitted Lua code is going to run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Deserialize)]
+pub enum LuaTarget {
+    /// Lua 5.1 (also used by LuaJIT)
+    #[serde(rename = "5.1")]
+    Lua51,
+
+    /// Lua 5.3
+    #[serde(rename = "5.3")]
+    Lua53,
+
+    /// Lua 5.4
+    #[default]
+    #[serde(rename = "5.4")]
+    Lua54,
+
+    /// Roblox's Luau
+    #[serde(rename = "luau")]
+    Luau,
+}
+
+impl std::fmt::Display for LuaTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            LuaTarget::Lua51 => "Lua 5.1",
+            LuaTarget::Lua53 => "Lua 5.3",
+            LuaTarget::Lua54 => "Lua 5.4",
+            LuaTarget::Luau => "Luau",
+        })
+    }
+}
+
+impl std::str::FromStr for LuaTarget {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "5.1" => Ok(LuaTarget::Lua51),
+            "5.3" => Ok(LuaTarget::Lua53),
+            "5.4" => Ok(LuaTarget::Lua54),
+            "luau" => Ok(LuaTarget::Luau),
+            _ => Err(format!("unknown Lua target: {}", s)),
+        }
+    }
+}
+
+/// Options of the source code formatter.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct FormatOptions {
+    /// Maximum line width before table constructors and call
+    /// arguments are split into multiple lines
+    pub width: usize,
+
+    /// How many columns does one indentation level take
+    #[serde(rename = "indentWidth")]
+    pub indent_width: usize,
+
+    /// Indents with tabs instead of spaces
+    #[serde(rename = "useTabs")]
+    pub use_tabs: bool,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            width: 100,
+            indent_width: 4,
+            use_tabs: true,
+        }
+    }
+}
+
 /// Compiler configuration contents for the Salite project object.
 #[derive(Debug, Default, PartialEq, Deserialize)]
 pub struct ConfigInfo {
@@ -30,6 +103,18 @@ pub struct ConfigInfo {
     /// Output Lua files compiled from Salite source files
     #[serde(rename = "outDir")]
     pub output_dir: PathBuf,
+
+    /// Lua dialect to emit code for, defaults to Lua 5.4
+    #[serde(default)]
+    pub target: LuaTarget,
+
+    /// Bundles every emitted Lua file into this Lua file
+    /// if it is specified
+    pub bundle: Option<PathBuf>,
+
+    /// Options for `salite fmt`
+    #[serde(default)]
+    pub format: FormatOptions,
 }
 
 impl ConfigInfo {
@@ -18,6 +18,9 @@ pub enum ParseErrorType {
         expected: String,
         token: salite_tokens::Token,
     },
+    ContinueOutsideLoop,
+    DeclareOutsideDeclaration,
+    ExecutableInDeclaration,
 }
 
 impl SaliteError for ParseErrorType {
@@ -36,6 +39,13 @@ impl SaliteError for ParseErrorType {
                     get_token_ranged(code, token.span())?
                 )
             }
+            ParseErrorType::ContinueOutsideLoop => "`continue` is not inside of a loop".to_string(),
+            ParseErrorType::DeclareOutsideDeclaration => {
+                "`declare` is only allowed in declaration files".to_string()
+            }
+            ParseErrorType::ExecutableInDeclaration => {
+                "declaration files cannot have executable statements".to_string()
+            }
         })
     }
 }
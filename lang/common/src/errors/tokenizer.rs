@@ -11,6 +11,10 @@ pub enum TokenizeErrorType {
     IncompleteString,
     IncompleteComment,
     IncorrectShebang,
+    /// Exact span of the escape sequence in the string.
+    InvalidEscape(Span),
+    /// The number literal can't be read, it has the reason.
+    MalformedNumber(String),
     UnexpectedCharacter(char),
 }
 
@@ -20,6 +24,8 @@ impl std::fmt::Display for TokenizeErrorType {
             TokenizeErrorType::IncompleteString => "incomplete string".to_string(),
             TokenizeErrorType::IncompleteComment => "incomplete comment".to_string(),
             TokenizeErrorType::IncorrectShebang => "incorrect shebang".to_string(),
+            TokenizeErrorType::InvalidEscape(..) => "invalid escape sequence".to_string(),
+            TokenizeErrorType::MalformedNumber(reason) => format!("malformed number: {}", reason),
             TokenizeErrorType::UnexpectedCharacter(c) => format!("unexpected character: {}", c),
         }
         .fmt(f)
@@ -27,8 +33,14 @@ impl std::fmt::Display for TokenizeErrorType {
 }
 
 impl SaliteError for TokenizeErrorType {
-    fn message(&self, _: &str) -> Result<String, super::TextSpanOutOfBounds> {
-        Ok(self.to_string())
+    fn message(&self, code: &str) -> Result<String, super::TextSpanOutOfBounds> {
+        match self {
+            TokenizeErrorType::InvalidEscape(span) => Ok(format!(
+                "invalid escape sequence: {}",
+                super::get_token_ranged(code, *span)?
+            )),
+            _ => Ok(self.to_string()),
+        }
     }
 }
 
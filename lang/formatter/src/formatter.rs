@@ -0,0 +1,964 @@
+use salite_ast::{cst::Cst, *};
+use salite_common::FormatOptions;
+
+/// Comment (or shebang) from the concrete syntax tree.
+struct Comment<'a> {
+    span: Span,
+    text: &'a str,
+    /// It is written in the same line after a token.
+    trailing: bool,
+}
+
+fn collect_comments(cst: &Cst) -> Vec<Comment<'_>> {
+    let mut comments = Vec::new();
+    for reference in cst.tokens().iter() {
+        let leading = reference.leading_trivia().iter().map(|v| (v, false));
+        let trailing = reference.trailing_trivia().iter().map(|v| (v, true));
+        for (token, trailing) in leading.chain(trailing) {
+            if matches!(token.ty(), TokenType::Comment(..) | TokenType::Shebang(..)) {
+                comments.push(Comment {
+                    span: token.span(),
+                    text: cst.text(token).trim_end(),
+                    trailing: trailing && !matches!(token.ty(), TokenType::Shebang(..)),
+                });
+            }
+        }
+    }
+    comments
+}
+
+fn binop_str(kind: &BinopKind) -> &'static str {
+    match kind {
+        BinopKind::NilshCoalescing => "??",
+        BinopKind::Exponent => "^",
+        BinopKind::Multiply => "*",
+        BinopKind::FloorDivision => "//",
+        BinopKind::Divide => "/",
+        BinopKind::Modulo => "%",
+        BinopKind::Add => "+",
+        BinopKind::Subtract => "-",
+        BinopKind::Concat => "..",
+        BinopKind::Equality => "==",
+        BinopKind::Inequality => "~=",
+        BinopKind::GreaterThan => ">",
+        BinopKind::GreaterEqual => ">=",
+        BinopKind::LessThan => "<",
+        BinopKind::LessEqual => "<=",
+        BinopKind::And => "and",
+        BinopKind::Or => "or",
+    }
+}
+
+/// Gets where the expression starts in the source code, spans
+/// of suffixed expressions only cover their suffix.
+fn expr_start(expr: &Expr) -> usize {
+    match expr {
+        Expr::Binary(node) => expr_start(node.left()),
+        Expr::Suffixed(node) => expr_start(node.base()),
+        Expr::TypeAssertion(node) => expr_start(node.base()),
+        _ => expr.span().start,
+    }
+}
+
+fn stmt_start(stmt: &Stmt) -> usize {
+    match stmt {
+        Stmt::Call(expr) => expr_start(expr),
+        Stmt::VarAssign(node) => match node.names().first() {
+            Some(VarAssignName::Suffixed(first)) => expr_start(first.base()),
+            _ => stmt.span().start,
+        },
+        Stmt::CompoundAssign(node) => match node.name() {
+            VarAssignName::Suffixed(name) => expr_start(name.base()),
+            _ => stmt.span().start,
+        },
+        _ => stmt.span().start,
+    }
+}
+
+fn starts_with_paren(expr: &Expr) -> bool {
+    match expr {
+        Expr::Paren(..) => true,
+        Expr::Suffixed(node) => starts_with_paren(node.base()),
+        Expr::TypeAssertion(node) => starts_with_paren(node.base()),
+        _ => false,
+    }
+}
+
+/// Checks if the statement starts with an open parenthesis, the
+/// previous statement needs a semicolon so it will not be a call.
+fn stmt_starts_with_paren(stmt: &Stmt) -> bool {
+    match stmt {
+        Stmt::Call(expr) => starts_with_paren(expr),
+        Stmt::VarAssign(node) => match node.names().first() {
+            Some(VarAssignName::Suffixed(first)) => starts_with_paren(first.base()),
+            _ => false,
+        },
+        Stmt::CompoundAssign(node) => match node.name() {
+            VarAssignName::Suffixed(name) => starts_with_paren(name.base()),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// `- -a` must not be written as a comment.
+fn starts_with_dash(expr: &Expr) -> bool {
+    match expr {
+        Expr::Unary(node) => node.op().kind == UnopKind::Negate,
+        Expr::Binary(node) => starts_with_dash(node.left()),
+        Expr::Suffixed(node) => starts_with_dash(node.base()),
+        Expr::TypeAssertion(node) => starts_with_dash(node.base()),
+        _ => false,
+    }
+}
+
+fn table_field_start(field: &TableField) -> usize {
+    match field {
+        TableField::Array(value) => expr_start(value),
+        _ => field.span().start,
+    }
+}
+
+fn type_table_field_start(field: &TypeTableField) -> usize {
+    match field {
+        TypeTableField::Array(value) => value.span().start,
+        TypeTableField::Computed { span, .. } | TypeTableField::Named { span, .. } => span.start,
+    }
+}
+
+/// Pretty-prints Salite AST nodes back into source code.
+///
+/// Comments are placed before the statement (or the table field)
+/// that comes after them, or at the end of the line if they are
+/// written after a token in the same line.
+pub struct Formatter<'a> {
+    cst: &'a Cst,
+    options: &'a FormatOptions,
+    comments: Vec<Comment<'a>>,
+    next_comment: usize,
+
+    output: String,
+    indentation: usize,
+    line_start: bool,
+    column: usize,
+
+    /// No statements or comments are written in the current block yet.
+    block_start: bool,
+    /// Everything is written in one line (except function bodies),
+    /// it is used to measure the width of the nodes.
+    flat: bool,
+}
+
+impl<'a> Formatter<'a> {
+    pub fn new(cst: &'a Cst, options: &'a FormatOptions) -> Self {
+        Self {
+            cst,
+            options,
+            comments: collect_comments(cst),
+            next_comment: 0,
+            output: String::new(),
+            indentation: 0,
+            line_start: true,
+            column: 0,
+            block_start: true,
+            flat: false,
+        }
+    }
+
+    pub fn format_file(&mut self, file: &'a File) {
+        self.visit_block(file.block());
+    }
+
+    /// Consumes the formatter and returns the formatted code.
+    pub fn finish(mut self) -> String {
+        self.flush_comments(usize::MAX);
+        let len = self.output.trim_end().len();
+        self.output.truncate(len);
+        if !self.output.is_empty() {
+            self.output.push('\n');
+        }
+        self.output
+    }
+
+    fn indent_str(&self) -> String {
+        if self.options.use_tabs {
+            "\t".repeat(self.indentation)
+        } else {
+            " ".repeat(self.indentation * self.options.indent_width)
+        }
+    }
+
+    fn write(&mut self, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        if self.line_start {
+            self.output.push_str(&self.indent_str());
+            self.column = self.indentation * self.options.indent_width;
+            self.line_start = false;
+        }
+        match text.rfind('\n') {
+            Some(idx) => self.column = text[idx + 1..].chars().count(),
+            None => self.column += text.chars().count(),
+        }
+        self.output.push_str(text);
+    }
+
+    fn write_token(&mut self, token: &Token) {
+        // literals have different variants, it is better
+        // to take it from the source code itself.
+        let cst = self.cst;
+        self.write(cst.text(token));
+    }
+
+    fn newline(&mut self) {
+        self.output.push('\n');
+        self.line_start = true;
+        self.column = 0;
+    }
+
+    /// Renders the node in one line without touching the output
+    /// and the comments, to check if it fits in the line width.
+    fn measure(&mut self, callback: impl FnOnce(&mut Self)) -> String {
+        let output = std::mem::take(&mut self.output);
+        let (line_start, column, flat) = (self.line_start, self.column, self.flat);
+        self.line_start = false;
+        self.flat = true;
+
+        callback(self);
+
+        let measured = std::mem::replace(&mut self.output, output);
+        self.line_start = line_start;
+        self.column = column;
+        self.flat = flat;
+        measured
+    }
+
+    fn fits(&self, measured: &str) -> bool {
+        let first_line = measured.split('\n').next().unwrap_or_default();
+        self.column + first_line.chars().count() <= self.options.width
+    }
+
+    fn has_comments_before(&self, offset: usize) -> bool {
+        self.comments
+            .get(self.next_comment)
+            .map(|v| v.span.start < offset)
+            .unwrap_or(false)
+    }
+
+    /// Checks if there is a blank line right before the offset.
+    fn has_blank_line_before(&self, offset: usize) -> bool {
+        let source = self.cst.source();
+        let before = source[..offset.min(source.len())].trim_end_matches('(');
+        let gap = &before[before.trim_end().len()..];
+        gap.matches('\n').count() >= 2
+    }
+
+    /// Starts a new statement or a comment in its own line, keeping
+    /// at most one blank line from the source code.
+    fn start_item(&mut self, offset: usize) {
+        if !self.block_start && self.has_blank_line_before(offset) && !self.output.ends_with("\n\n")
+        {
+            self.newline();
+        }
+        self.block_start = false;
+    }
+
+    /// Writes every comment before the offset. It must be called
+    /// at the start of a line.
+    fn flush_comments(&mut self, offset: usize) {
+        if self.flat {
+            return;
+        }
+        while let Some(comment) = self.comments.get(self.next_comment) {
+            if comment.span.start >= offset {
+                break;
+            }
+            self.next_comment += 1;
+
+            let (text, trailing, start) = (comment.text, comment.trailing, comment.span.start);
+            let previous_line = self.output.trim_end_matches('\n');
+            let at_line_end = self.output.ends_with('\n') && !self.output.ends_with("\n\n");
+            if trailing && at_line_end && !previous_line.is_empty() {
+                // back to the end of the previous line
+                self.output.truncate(previous_line.len());
+                self.output.push(' ');
+                self.output.push_str(text);
+            } else {
+                self.start_item(start);
+                self.write(text);
+            }
+            self.newline();
+        }
+    }
+
+    fn write_list<T>(&mut self, list: &'a [T], mut callback: impl FnMut(&mut Self, &'a T)) {
+        for (id, node) in list.iter().enumerate() {
+            if id > 0 {
+                self.write(", ");
+            }
+            callback(self, node);
+        }
+    }
+
+    /// Writes the list one item in each line.
+    fn write_expanded_list<T>(
+        &mut self,
+        list: &'a [T],
+        trailing_comma: bool,
+        start_of: impl Fn(&T) -> usize,
+        mut callback: impl FnMut(&mut Self, &'a T),
+    ) {
+        self.newline();
+        self.indentation += 1;
+        self.block_start = true;
+        for (id, node) in list.iter().enumerate() {
+            self.flush_comments(start_of(node));
+            self.block_start = false;
+            callback(self, node);
+            if trailing_comma || id + 1 < list.len() {
+                self.write(",");
+            }
+            self.newline();
+        }
+    }
+
+    fn write_exprlist(&mut self, list: &'a [Expr]) {
+        self.write_list(list, |this, expr| this.visit_expr(expr));
+    }
+
+    fn is_block_empty(&self, block: &Block) -> bool {
+        block.stmts().is_empty()
+            && block.last_stmt().is_none()
+            && !self.has_comments_before(block.span().end)
+    }
+
+    fn write_block_end(&mut self, block: &'a Block) {
+        self.write_block_with(block, "end");
+    }
+
+    /// Writes the block and the closing keyword after it. It will
+    /// write into one line if the block has nothing to write.
+    fn write_block_with(&mut self, block: &'a Block, closing: &str) {
+        if self.is_block_empty(block) {
+            self.write(" ");
+        } else {
+            self.newline();
+            self.indentation += 1;
+            self.visit_block(block);
+            self.indentation -= 1;
+        }
+        self.write(closing);
+    }
+
+    /// Writes the block of an `if` statement clause, the next
+    /// clause starts in a new line even if the block is empty.
+    fn write_clause_block(&mut self, block: &'a Block) {
+        self.newline();
+        if !self.is_block_empty(block) {
+            self.indentation += 1;
+            self.visit_block(block);
+            self.indentation -= 1;
+        }
+    }
+
+    fn write_type_parameters(&mut self, params: &'a [TypeParameter]) {
+        self.write("<");
+        self.write_list(params, |this, param| {
+            this.write_token(param.name());
+            if let Some(typ) = param.typ() {
+                this.write(": ");
+                this.visit_type_info(typ);
+            }
+            if let Some(default) = param.default() {
+                this.write(" = ");
+                this.visit_type_info(default);
+            }
+        });
+        self.write(">");
+    }
+
+    fn write_function_body(&mut self, body: &'a FunctionBody) {
+        if let Some(params) = body.type_params() {
+            self.write_type_parameters(params);
+        }
+        self.write("(");
+        self.write_list(body.params(), |this, param| {
+            this.write_token(&param.name);
+            if param.optional {
+                this.write("?");
+            }
+            if let Some(typ) = &param.explicit_type {
+                this.write(": ");
+                this.visit_type_info(typ);
+            }
+            if let Some(default) = &param.default {
+                this.write(" = ");
+                this.visit_expr(default);
+            }
+        });
+        if let Some(varidiac) = body.varidiac() {
+            if !body.params().is_empty() {
+                self.write(", ");
+            }
+            self.write("...");
+            if let Some(typ) = &varidiac.typ {
+                self.write(": ");
+                self.visit_type_info(typ);
+            }
+        }
+        self.write(")");
+        if let Some(return_type) = body.return_type() {
+            self.write(" -> ");
+            self.visit_type_info(return_type);
+        }
+        self.write_block_end(body.block());
+    }
+
+    fn write_function_assign_name(&mut self, name: &'a FunctionAssignName) {
+        match name {
+            FunctionAssignName::Property(base, name) => {
+                self.write_function_assign_name(base);
+                self.write(".");
+                self.write_token(name);
+            }
+            FunctionAssignName::Method(base, name) => {
+                self.write_function_assign_name(base);
+                self.write(":");
+                self.write_token(name);
+            }
+            FunctionAssignName::Name(name) => self.write_token(name),
+        }
+    }
+
+    fn write_table_field(&mut self, field: &'a TableField) {
+        match field {
+            TableField::Array(value) => self.visit_expr(value),
+            TableField::Named { name, value, .. } => {
+                self.write_token(name);
+                self.write(" = ");
+                self.visit_expr(value);
+            }
+            TableField::Expr { index, value, .. } => {
+                self.write("[");
+                self.visit_expr(index);
+                self.write("] = ");
+                self.visit_expr(value);
+            }
+        }
+    }
+
+    fn write_call_args(&mut self, list: &'a [Expr]) {
+        let expand = !self.flat
+            && !list.is_empty()
+            && (self.has_comments_before(list.last().unwrap().span().end) || {
+                let measured = self.measure(|this| {
+                    this.write("(");
+                    this.write_exprlist(list);
+                    this.write(")");
+                });
+                !self.fits(&measured)
+            });
+
+        self.write("(");
+        if expand {
+            self.write_expanded_list(list, false, expr_start, |this, expr| this.visit_expr(expr));
+            self.indentation -= 1;
+        } else {
+            self.write_exprlist(list);
+        }
+        self.write(")");
+    }
+
+    fn write_args(&mut self, args: &'a Args) {
+        match args {
+            Args::ExprList(list) => self.write_call_args(list),
+            Args::Table(table) => {
+                self.write(" ");
+                self.visit_table_ctor_expr(table);
+            }
+            Args::Str(token) => {
+                self.write(" ");
+                self.write_token(token);
+            }
+        }
+    }
+
+    fn write_type_table_field(&mut self, field: &'a TypeTableField) {
+        match field {
+            TypeTableField::Computed { key, value, .. } => {
+                self.write("[");
+                self.visit_type_info(key);
+                self.write("]: ");
+                self.visit_type_info(value);
+            }
+            TypeTableField::Named { name, value, .. } => {
+                self.write_token(name);
+                self.write(": ");
+                self.visit_type_info(value);
+            }
+            TypeTableField::Array(value) => self.visit_type_info(value),
+        }
+    }
+
+    fn write_type_members(&mut self, members: &'a [TypeInfo], separator: &str) {
+        for (id, member) in members.iter().enumerate() {
+            if id > 0 {
+                self.write(separator);
+            }
+            self.visit_type_info(member);
+        }
+    }
+}
+
+impl<'a> AstVisitor<'a> for Formatter<'a> {
+    type BlockOutput = ();
+
+    fn visit_block(&mut self, node: &'a Block) -> Self::BlockOutput {
+        self.block_start = true;
+
+        let mut stmts = node.stmts().iter().peekable();
+        while let Some(stmt) = stmts.next() {
+            let start = stmt_start(stmt);
+            self.flush_comments(start);
+            self.start_item(start);
+            self.visit_stmt(stmt);
+
+            // Lua may treat the parenthesis as a call from the previous statement
+            if stmts.peek().map(|v| stmt_starts_with_paren(v)) == Some(true) {
+                self.write(";");
+            }
+            self.newline();
+            self.block_start = false;
+        }
+        if let Some(last_stmt) = node.last_stmt() {
+            let start = last_stmt.span().start;
+            self.flush_comments(start);
+            self.start_item(start);
+            self.visit_last_stmt(last_stmt);
+            self.newline();
+        }
+
+        // comments before the closing keyword
+        self.flush_comments(node.span().end);
+    }
+}
+
+impl<'a> LastStmtVisitor<'a> for Formatter<'a> {
+    type Output = ();
+
+    fn visit_break_stmt(&mut self, _: &'a Token) -> Self::Output {
+        self.write("break");
+    }
+
+    fn visit_continue_stmt(&mut self, _: &'a Token) -> Self::Output {
+        self.write("continue");
+    }
+
+    fn visit_return_stmt(&mut self, node: &'a ReturnStmt) -> Self::Output {
+        self.write("return");
+        if !node.exprlist().is_empty() {
+            self.write(" ");
+            self.write_exprlist(node.exprlist());
+        }
+    }
+}
+
+impl<'a> StmtVisitor<'a> for Formatter<'a> {
+    type Output = ();
+
+    fn visit_call_stmt(&mut self, node: &'a Expr) -> Self::Output {
+        self.visit_expr(node);
+    }
+
+    fn visit_compound_assign_stmt(&mut self, node: &'a CompoundAssign) -> Self::Output {
+        match node.name() {
+            VarAssignName::Name(token) => self.write_token(token),
+            VarAssignName::Suffixed(node) => self.visit_suffixed_expr(node),
+        }
+        self.write(" ");
+        self.write(binop_str(&node.op().kind));
+        self.write("= ");
+        self.visit_expr(node.expr());
+    }
+
+    fn visit_do_stmt(&mut self, node: &'a DoStmt) -> Self::Output {
+        self.write("do");
+        self.write_block_end(node.block());
+    }
+
+    fn visit_function_assign_stmt(&mut self, node: &'a FunctionAssign) -> Self::Output {
+        self.write("function ");
+        self.write_function_assign_name(node.name());
+        self.write_function_body(node.body());
+    }
+
+    fn visit_generic_for_stmt(&mut self, node: &'a GenericFor) -> Self::Output {
+        self.write("for ");
+        self.write_list(node.names(), |this, name| this.write_token(name));
+        self.write(" in ");
+        self.write_exprlist(node.exprlist());
+        self.write(" do");
+        self.write_block_end(node.block());
+    }
+
+    fn visit_if_stmt(&mut self, node: &'a IfStmt) -> Self::Output {
+        self.write("if ");
+        self.visit_expr(node.condition());
+        self.write(" then");
+        if node.elseifs().is_empty() && node.else_block().is_none() {
+            return self.write_block_end(node.block());
+        }
+
+        self.write_clause_block(node.block());
+        for clause in node.elseifs().iter() {
+            self.write("elseif ");
+            self.visit_expr(clause.condition());
+            self.write(" then");
+            self.write_clause_block(clause.block());
+        }
+        if let Some(block) = node.else_block() {
+            self.write("else");
+            self.write_clause_block(block);
+        }
+        self.write("end");
+    }
+
+    fn visit_local_assign_stmt(&mut self, node: &'a LocalAssign) -> Self::Output {
+        self.write("local ");
+        self.write_list(node.names(), |this, name| {
+            this.write_token(name.name());
+            if let Some(typ) = name.type_info() {
+                this.write(": ");
+                this.visit_type_info(typ);
+            }
+        });
+        if !node.exprlist().is_empty() {
+            self.write(" = ");
+            self.write_exprlist(node.exprlist());
+        }
+    }
+
+    fn visit_local_function_stmt(&mut self, node: &'a LocalFunction) -> Self::Output {
+        self.write("local function ");
+        self.write_token(node.name());
+        self.write_function_body(node.body());
+    }
+
+    fn visit_numeric_for_stmt(&mut self, node: &'a NumericFor) -> Self::Output {
+        self.write("for ");
+        self.write_token(node.name());
+        self.write(" = ");
+        self.visit_expr(node.start());
+        self.write(", ");
+        self.visit_expr(node.end());
+        if let Some(step) = node.step() {
+            self.write(", ");
+            self.visit_expr(step);
+        }
+        self.write(" do");
+        self.write_block_end(node.block());
+    }
+
+    fn visit_repeat_stmt(&mut self, node: &'a RepeatStmt) -> Self::Output {
+        self.write("repeat");
+        self.write_block_with(node.block(), "until ");
+        self.visit_expr(node.condition());
+    }
+
+    fn visit_while_stmt(&mut self, node: &'a WhileStmt) -> Self::Output {
+        self.write("while ");
+        self.visit_expr(node.condition());
+        self.write(" do");
+        self.write_block_end(node.block());
+    }
+
+    fn visit_var_assign_stmt(&mut self, node: &'a VarAssign) -> Self::Output {
+        self.write_list(node.names(), |this, name| match name {
+            VarAssignName::Name(token) => this.write_token(token),
+            VarAssignName::Suffixed(node) => this.visit_suffixed_expr(node),
+        });
+        self.write(" = ");
+        self.write_exprlist(node.exprlist());
+    }
+
+    fn visit_type_declaration_stmt(&mut self, node: &'a TypeDeclaration) -> Self::Output {
+        if *node.exported() {
+            self.write("export ");
+        }
+        self.write("type ");
+        self.write_token(node.name());
+        if let Some(params) = node.params() {
+            self.write_type_parameters(params);
+        }
+        self.write(" = ");
+        self.visit_type_info(node.typ());
+    }
+
+    fn visit_declare_function_stmt(&mut self, node: &'a DeclareFunction) -> Self::Output {
+        self.write("declare function ");
+        self.write_token(node.name());
+        self.visit_type_callback(node.typ());
+    }
+
+    fn visit_declare_local_stmt(&mut self, node: &'a DeclareLocal) -> Self::Output {
+        self.write("declare local ");
+        self.write_token(node.name());
+        self.write(": ");
+        self.visit_type_info(node.typ());
+    }
+
+    fn visit_declare_module_stmt(&mut self, node: &'a DeclareModule) -> Self::Output {
+        self.write("declare module ");
+        self.write_token(node.name());
+        self.write(": ");
+        self.visit_type_info(node.typ());
+    }
+}
+
+impl<'a> ExprVisitor<'a> for Formatter<'a> {
+    type Output = ();
+
+    fn visit_bool_expr(&mut self, node: &'a Token) -> Self::Output {
+        self.write_token(node);
+    }
+
+    fn visit_function_expr(&mut self, node: &'a FunctionExpr) -> Self::Output {
+        self.write("function");
+        self.write_function_body(node.body());
+    }
+
+    fn visit_interpolated_str_expr(&mut self, node: &'a InterpolatedStr) -> Self::Output {
+        let mut exprs = node.exprs().iter();
+        for segment in node.segments().iter() {
+            self.write_token(segment);
+            if let Some(expr) = exprs.next() {
+                self.visit_expr(expr);
+            }
+        }
+    }
+
+    fn visit_name_expr(&mut self, node: &'a Token) -> Self::Output {
+        self.write_token(node);
+    }
+
+    fn visit_number_expr(&mut self, node: &'a Token) -> Self::Output {
+        self.write_token(node);
+    }
+
+    fn visit_nil_expr(&mut self, _: &'a Token) -> Self::Output {
+        self.write("nil");
+    }
+
+    fn visit_str_expr(&mut self, node: &'a Token) -> Self::Output {
+        self.write_token(node);
+    }
+
+    fn visit_table_ctor_expr(&mut self, node: &'a TableCtor) -> Self::Output {
+        let fields = node.fields();
+        if fields.is_empty() && !self.has_comments_before(node.span().end) {
+            return self.write("{}");
+        }
+
+        let expand = !self.flat
+            && (self.has_comments_before(node.span().end) || {
+                let measured = self.measure(|this| this.visit_table_ctor_expr(node));
+                measured.contains('\n') || !self.fits(&measured)
+            });
+
+        if expand {
+            self.write("{");
+            self.write_expanded_list(fields, true, table_field_start, |this, field| {
+                this.write_table_field(field)
+            });
+            self.flush_comments(node.span().end);
+            self.indentation -= 1;
+            self.write("}");
+        } else {
+            self.write("{ ");
+            self.write_list(fields, |this, field| this.write_table_field(field));
+            self.write(" }");
+        }
+    }
+
+    fn visit_varargs_expr(&mut self, _: &'a Token) -> Self::Output {
+        self.write("...");
+    }
+
+    fn visit_binary_expr(&mut self, node: &'a Binary) -> Self::Output {
+        self.visit_expr(node.left());
+        self.write(" ");
+        self.write(binop_str(&node.op().kind));
+        self.write(" ");
+        self.visit_expr(node.right());
+    }
+
+    fn visit_if_else_expr(&mut self, node: &'a IfElseExpr) -> Self::Output {
+        self.write("if ");
+        self.visit_expr(node.condition());
+        self.write(" then ");
+        self.visit_expr(node.value());
+        for clause in node.elseifs().iter() {
+            self.write(" elseif ");
+            self.visit_expr(clause.condition());
+            self.write(" then ");
+            self.visit_expr(clause.value());
+        }
+        self.write(" else ");
+        self.visit_expr(node.else_value());
+    }
+
+    fn visit_paren_expr(&mut self, node: &'a Expr) -> Self::Output {
+        self.write("(");
+        self.visit_expr(node);
+        self.write(")");
+    }
+
+    fn visit_suffixed_expr(&mut self, node: &'a Suffixed) -> Self::Output {
+        self.visit_expr(node.base());
+        self.visit_suffix_kind_expr(node.suffix());
+    }
+
+    fn visit_type_assertion_expr(&mut self, node: &'a TypeAssertion) -> Self::Output {
+        self.visit_expr(node.base());
+        self.write(" :: ");
+        self.visit_type_info(node.cast());
+    }
+
+    fn visit_unary_expr(&mut self, node: &'a Unary) -> Self::Output {
+        let expr: &Expr = node.expr();
+        match node.op().kind {
+            UnopKind::Length => self.write("#"),
+            UnopKind::Not => self.write("not "),
+            UnopKind::Negate if starts_with_dash(expr) => self.write("- "),
+            UnopKind::Negate => self.write("-"),
+        };
+        self.visit_expr(expr);
+    }
+
+    fn visit_suffix_kind_expr(&mut self, node: &'a SuffixKind) -> Self::Output {
+        match node {
+            SuffixKind::Call(args) => self.write_args(args),
+            SuffixKind::Computed(index) => {
+                self.write("[");
+                self.visit_expr(index);
+                self.write("]");
+            }
+            SuffixKind::Method(name) => {
+                self.write(":");
+                self.write_token(name);
+            }
+            SuffixKind::Name(name) => {
+                self.write(".");
+                self.write_token(name);
+            }
+        }
+    }
+}
+
+impl<'a> TypeVisitor<'a> for Formatter<'a> {
+    type Output = ();
+
+    fn visit_type_callback(&mut self, node: &'a TypeCallback) -> Self::Output {
+        if let Some(params) = node.type_params() {
+            self.write_type_parameters(params);
+        }
+        self.write("(");
+        self.write_list(node.parameters(), |this, param| {
+            this.write_token(param.name());
+            if *param.optional() {
+                this.write("?");
+            }
+            this.write(": ");
+            this.visit_type_info(param.type_info());
+        });
+        if let Some(varidiac) = node.varidiac() {
+            if !node.parameters().is_empty() {
+                self.write(", ");
+            }
+            self.write("...");
+            if let Some(typ) = &varidiac.typ {
+                self.write(": ");
+                self.visit_type_info(typ);
+            }
+        }
+        self.write(") -> ");
+        self.visit_type_info(node.return_type());
+    }
+
+    fn visit_type_literal(&mut self, node: &'a Token) -> Self::Output {
+        self.write_token(node);
+    }
+
+    fn visit_type_reference(&mut self, node: &'a TypeReference) -> Self::Output {
+        if let Some(module) = node.module() {
+            self.write_token(module);
+            self.write(".");
+        }
+        self.write_token(node.name());
+        if let Some(arguments) = node.arguments() {
+            self.write("<");
+            self.write_type_members(arguments, ", ");
+            self.write(">");
+        }
+    }
+
+    fn visit_type_table(&mut self, node: &'a TypeTable) -> Self::Output {
+        let fields = node.fields();
+        if fields.is_empty() && !self.has_comments_before(node.span().end) {
+            return self.write("{}");
+        }
+
+        let expand = !self.flat
+            && (self.has_comments_before(node.span().end) || {
+                let measured = self.measure(|this| this.visit_type_table(node));
+                !self.fits(&measured)
+            });
+
+        if expand {
+            self.write("{");
+            self.write_expanded_list(fields, true, type_table_field_start, |this, field| {
+                this.write_type_table_field(field)
+            });
+            self.flush_comments(node.span().end);
+            self.indentation -= 1;
+            self.write("}");
+        } else {
+            self.write("{ ");
+            self.write_list(fields, |this, field| this.write_type_table_field(field));
+            self.write(" }");
+        }
+    }
+
+    fn visit_type_metatable(&mut self, node: &'a TypeMetatable) -> Self::Output {
+        self.write("@metatable ");
+        self.visit_type_table(node.table());
+    }
+
+    fn visit_type_tuple(&mut self, node: &'a TypeTuple) -> Self::Output {
+        self.write("(");
+        self.write_type_members(node.members(), ", ");
+        self.write(")");
+    }
+
+    fn visit_type_typeof(&mut self, node: &'a TypeOf) -> Self::Output {
+        self.write("typeof(");
+        self.visit_expr(node.expr());
+        self.write(")");
+    }
+
+    fn visit_type_intersection(&mut self, node: &'a TypeIntersection) -> Self::Output {
+        self.write_type_members(node.members(), " & ");
+    }
+
+    fn visit_type_union(&mut self, node: &'a TypeUnion) -> Self::Output {
+        // `T?` has `nil` in place of the question mark
+        if let [base, TypeInfo::Reference(nil)] = node.members().as_slice() {
+            if self.cst.text(nil.name()) == "?" {
+                self.visit_type_info(base);
+                return self.write("?");
+            }
+        }
+        self.write_type_members(node.members(), " | ");
+    }
+}
@@ -0,0 +1,16 @@
+mod formatter;
+
+pub use formatter::*;
+
+use salite_ast as ast;
+use salite_common::FormatOptions;
+
+/// Formats a parsed Salite file back into source code.
+///
+/// The file must be parsed from the same concrete syntax tree,
+/// literals and comments are taken from the tree as they are written.
+pub fn format_file(cst: &ast::cst::Cst, file: &ast::File, options: &FormatOptions) -> String {
+    let mut formatter = Formatter::new(cst, options);
+    formatter.format_file(file);
+    formatter.finish()
+}
@@ -36,7 +36,8 @@ parser_struct!(ParseName, ast::Token, |_, state: &ParseState<'a>| {
     if let Some(token) = state.current() {
         if matches!(
             token.ty(),
-            ast::TokenType::Identifier(..) | ast::TokenType::Symbol(ast::SymbolType::Type)
+            ast::TokenType::Identifier(..)
+                | ast::TokenType::Symbol(ast::SymbolType::Type | ast::SymbolType::Continue)
         ) {
             return Ok((state.next(1), token.clone()));
         }
@@ -64,6 +65,48 @@ parser_struct!(ParseStr, ast::Token, |_, state: &ParseState<'a>| {
     no_match!(state)
 });
 
+pub struct ParseInterpolatedPart(pub ast::InterpolatedPart);
+parser_struct!(
+    ParseInterpolatedPart,
+    ast::Token,
+    |this: &ParseInterpolatedPart, state: &ParseState<'a>| {
+        if let Some(token) = state.current() {
+            if matches!(token.ty(), ast::TokenType::InterpolatedStr(part, _) if *part == this.0) {
+                return Ok((state.next(1), token.clone()));
+            }
+        }
+        no_match!(state)
+    }
+);
+
+pub struct ParseInterpolatedStr;
+#[rustfmt::skip]
+parser_struct!(ParseInterpolatedStr, ast::InterpolatedStr, |_, state: &ParseState<'a>| {
+    if let Ok((state, token)) = ParseInterpolatedPart(ast::InterpolatedPart::Simple).parse(state) {
+        let span = token.span();
+        return Ok((state, ast::InterpolatedStr::new(span, vec![token], Vec::new())));
+    }
+
+    let (mut state, begin) = ParseInterpolatedPart(ast::InterpolatedPart::Begin).parse(state)?;
+    let start = begin.span();
+    let mut segments = vec![begin];
+    let mut exprs = Vec::new();
+    loop {
+        let (ns, expr) = expect!(&state, ParseExpr, "<exp>");
+        exprs.push(expr);
+        if let Ok((ns, mid)) = ParseInterpolatedPart(ast::InterpolatedPart::Mid).parse(&ns) {
+            segments.push(mid);
+            state = ns;
+            continue;
+        }
+
+        let (ns, end) = expect!(&ns, ParseInterpolatedPart(ast::InterpolatedPart::End), "}");
+        let span = ast::Span::merge(start, end.span());
+        segments.push(end);
+        return Ok((ns, ast::InterpolatedStr::new(span, segments, exprs)));
+    }
+});
+
 pub struct ParseTableFieldSep;
 parser_struct!(ParseTableFieldSep, (), |_, state: &ParseState<'a>| {
     parse_either!(state, {
@@ -131,6 +174,7 @@ parser_struct!(ParseLiteral, ast::Literal, |_, state: &ParseState<'a>| {
         ParseSymbol(ast::SymbolType::False) => ast::Literal::Bool,
         ParseSymbol(ast::SymbolType::True) => ast::Literal::Bool,
         ParseFunctionExpr => ast::Literal::Function,
+        ParseInterpolatedStr => ast::Literal::InterpolatedStr,
         ParseName => ast::Literal::Name,
         ParseNumber => ast::Literal::Number,
         ParseSymbol(ast::SymbolType::Nil) => ast::Literal::Nil,
@@ -144,13 +188,58 @@ pub struct ParseParen;
 parser_struct!(ParseParen, ast::Expr, |_, state: &ParseState<'a>| {
     let (state, _) = ParseSymbol(ast::SymbolType::OpenParen).parse(state)?;
     let (state, exp) = expect!(&state, ParseExpr, "<exp>");
-    let (state, _) = ParseSymbol(ast::SymbolType::CloseParen).parse(&state)?;
+    let (state, _) = expect!(&state, ParseSymbol(ast::SymbolType::CloseParen), ")");
     Ok((state, exp))
 });
 
+pub struct ParseElseIfExprClause;
+parser_struct!(
+    ParseElseIfExprClause,
+    ast::ElseIfExprClause,
+    |_, state: &ParseState<'a>| {
+        let (state, start) = ParseSymbol(ast::SymbolType::ElseIf).parse(state)?;
+        let (state, condition) = expect!(&state, ParseExpr, "<exp>");
+        let (state, _) = expect!(&state, ParseSymbol(ast::SymbolType::Then), "then");
+        let (state, value) = expect!(&state, ParseExpr, "<exp>");
+        Ok((
+            state,
+            ast::ElseIfExprClause::new(
+                ast::Span::merge(start.span(), value.span()),
+                condition,
+                value,
+            ),
+        ))
+    }
+);
+
+pub struct ParseIfElseExpr;
+#[rustfmt::skip]
+parser_struct!(ParseIfElseExpr, ast::IfElseExpr, |_, state: &ParseState<'a>| {
+    let (state, start) = ParseSymbol(ast::SymbolType::If).parse(state)?;
+    let (state, condition) = expect!(&state, ParseExpr, "<exp>");
+    let (state, _) = expect!(&state, ParseSymbol(ast::SymbolType::Then), "then");
+    let (state, value) = expect!(&state, ParseExpr, "<exp>");
+    let (state, elseifs) = ZeroOrMore(ParseElseIfExprClause).parse(&state)?;
+
+    // `else` is required, the expression has to have a value
+    let (state, _) = expect!(&state, ParseSymbol(ast::SymbolType::Else), "else");
+    let (state, else_value) = expect!(&state, ParseExpr, "<exp>");
+    Ok((
+        state,
+        ast::IfElseExpr::new(
+            ast::Span::merge(start.span(), else_value.span()),
+            Box::new(condition),
+            Box::new(value),
+            elseifs,
+            Box::new(else_value),
+        ),
+    ))
+});
+
 pub struct ParseSimpleExpr;
 parser_struct!(ParseSimpleExpr, ast::Expr, |_, state: &ParseState<'a>| {
     parse_either!(state, {
+        ParseIfElseExpr => ast::Expr::IfElse,
         ParseLiteral => ast::Expr::Literal,
         ParseParen => |e| ast::Expr::Paren(Box::new(e)),
     })
@@ -286,10 +375,10 @@ parser_struct!(
     ast::BinopKind,
     |_, state: &ParseState<'a>| {
         parse_either!(state, {
-            // NilshCoalescing
+            ParseSymbol(ast::SymbolType::DoubleQuestion) => |_| ast::BinopKind::NilshCoalescing,
             ParseSymbol(ast::SymbolType::Caret) => |_| ast::BinopKind::Exponent,
             ParseSymbol(ast::SymbolType::Asterisk) => |_| ast::BinopKind::Multiply,
-            // FloorDivision
+            ParseSymbol(ast::SymbolType::DoubleSlash) => |_| ast::BinopKind::FloorDivision,
             ParseSymbol(ast::SymbolType::Slash) => |_| ast::BinopKind::Divide,
             ParseSymbol(ast::SymbolType::Percent) => |_| ast::BinopKind::Modulo,
             ParseSymbol(ast::SymbolType::Cross) => |_| ast::BinopKind::Add,
@@ -397,10 +486,11 @@ parser_struct!(
                 break;
             }
 
+            // `2 ^ 3 ^ 2` is `2 ^ (3 ^ 2)` but `1 - 2 - 3` is `(1 - 2) - 3`
             let next_pred = if op.kind.is_right_associative() {
-                pred + 1
-            } else {
                 pred
+            } else {
+                pred + 1
             };
 
             let (ns, right) = expect!(&ns, ParseExprWithPrecedence(next_pred), "<exp>");
@@ -491,8 +581,14 @@ parser_struct!(
     ParseFunctionBody,
     ast::FunctionBody,
     |_, state: &ParseState<'a>| {
-        let (state, open) = expect!(state, ParseSymbol(ast::SymbolType::OpenParen), "(");
-        let start_span = open.span().start;
+        let start_span = state.current().map(|v| v.span().start).unwrap_or_default();
+        let (state, type_params) =
+            if let Ok((new_state, params)) = no_match_ignore!(state, ParseTypeParameters) {
+                (new_state, Some(params))
+            } else {
+                (state.next(0), None)
+            };
+        let (state, _) = expect!(&state, ParseSymbol(ast::SymbolType::OpenParen), "(");
 
         // parse names?
         let (mut state, params) =
@@ -520,7 +616,9 @@ parser_struct!(
                 (state, None)
             };
 
-        let (state, block) = ParseBlock.parse(&state)?;
+        // loops outside of the function do not count
+        let (new_state, block) = ParseBlock.parse(&state.with_loop(false))?;
+        let state = new_state.with_loop(state.in_loop);
         let (state, end) = expect!(&state, ParseSymbol(ast::SymbolType::End), "end");
         let end_span = end.span().end;
 
@@ -528,6 +626,7 @@ parser_struct!(
             state,
             ast::FunctionBody::new(
                 ast::Span::new(start_span, end_span),
+                type_params,
                 params,
                 varidiac,
                 block,
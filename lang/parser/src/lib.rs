@@ -16,17 +16,46 @@ pub use types::*;
 mod prelude;
 use prelude::*;
 
+use std::cell::RefCell;
+
 pub use salite_common::errors::parser::*;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct ParseState<'a> {
     pub offset: usize,
     pub tokens: &'a [ast::Token],
+    /// Errors collected while recovering from them. The parser
+    /// stops at the first error if it is not given.
+    pub errors: Option<&'a RefCell<Vec<ParseError>>>,
+    /// Whether the current block is inside of a loop,
+    /// `continue` is only allowed inside of it.
+    pub in_loop: bool,
+    /// Whether the file is a declaration file (`.d.slt`),
+    /// `declare` statements are only allowed inside of it.
+    pub declaration: bool,
 }
 
 impl<'a> ParseState<'a> {
     pub fn new(tokens: &'a [ast::Token]) -> Self {
-        ParseState { offset: 0, tokens }
+        ParseState {
+            offset: 0,
+            tokens,
+            errors: None,
+            in_loop: false,
+            declaration: false,
+        }
+    }
+
+    /// Creates a parse state which recovers from errors
+    /// at statement boundaries and collects them.
+    pub fn with_recovery(tokens: &'a [ast::Token], errors: &'a RefCell<Vec<ParseError>>) -> Self {
+        ParseState {
+            offset: 0,
+            tokens,
+            errors: Some(errors),
+            in_loop: false,
+            declaration: false,
+        }
     }
 
     pub fn current(&self) -> Option<&'a ast::Token> {
@@ -37,8 +66,41 @@ impl<'a> ParseState<'a> {
         ParseState {
             offset: self.offset + offset,
             tokens: self.tokens,
+            errors: self.errors,
+            in_loop: self.in_loop,
+            declaration: self.declaration,
         }
     }
+
+    /// Makes a copy of the state that is inside (or outside) of a loop.
+    pub fn with_loop(&self, in_loop: bool) -> ParseState<'a> {
+        ParseState {
+            in_loop,
+            ..self.clone()
+        }
+    }
+
+    /// Makes a copy of the state that is in a declaration file or not.
+    pub fn with_declaration(&self, declaration: bool) -> ParseState<'a> {
+        ParseState {
+            declaration,
+            ..self.clone()
+        }
+    }
+
+    /// Collects the error if the parser recovers from errors. Only one
+    /// error is collected for each location, backtracking parsers may
+    /// report the same error again.
+    pub fn report(&self, error: ParseError) -> Result<(), ParseError> {
+        let mut errors = match self.errors {
+            Some(errors) => errors.borrow_mut(),
+            None => return Err(error),
+        };
+        if !errors.iter().any(|v| v.span == error.span) {
+            errors.push(error);
+        }
+        Ok(())
+    }
 }
 
 pub type ParseResult<'a, T> = Result<(ParseState<'a>, T), ParseError>;
@@ -49,12 +111,46 @@ pub trait Parser<'a> {
     fn parse(&self, state: &ParseState<'a>) -> ParseResult<'a, Self::Output>;
 }
 
+/// Finds the statements that cannot be in a declaration file, only
+/// declarations, locals without values and `return` are allowed.
+fn declaration_errors(block: &ast::Block) -> Vec<ParseError> {
+    let mut errors = Vec::new();
+    for stmt in block.stmts().iter().chain(block.last_stmt().as_deref()) {
+        let allowed = match stmt {
+            ast::Stmt::DeclareFunction(..)
+            | ast::Stmt::DeclareLocal(..)
+            | ast::Stmt::DeclareModule(..)
+            | ast::Stmt::TypeDeclaration(..)
+            | ast::Stmt::Return(..)
+            // it is already reported
+            | ast::Stmt::Error(..) => true,
+            ast::Stmt::LocalAssign(node) => node.exprlist().is_empty(),
+            _ => false,
+        };
+        if !allowed {
+            errors.push(ParseError {
+                span: stmt.span(),
+                ty: ParseErrorType::ExecutableInDeclaration,
+            });
+        }
+    }
+    errors
+}
+
 /// Parses into an AST file with state required for manual flexibility
 pub fn parse_file_raw(declaration: bool, state: &ParseState<'_>) -> Result<ast::File, ParseError> {
-    match ParseBlock.parse(state) {
-        Ok((_, block)) => Ok(ast::File::new(declaration, block.span(), block)),
-        Err(err) => Err(err),
+    let (_, block) = ParseBlock.parse(&state.with_declaration(declaration))?;
+    if declaration {
+        if let Some(err) = declaration_errors(&block).into_iter().next() {
+            return Err(err);
+        }
     }
+    Ok(ast::File::new(declaration, block.span(), block))
+}
+
+/// Parses into an AST file from the concrete syntax tree
+pub fn parse_cst(declaration: bool, cst: &ast::cst::Cst) -> Result<ast::File, ParseError> {
+    parse_file(declaration, &cst.non_trivia_tokens())
 }
 
 /// Parses into an AST file with tokens required for complete parsing
@@ -62,3 +158,68 @@ pub fn parse_file(declaration: bool, tokens: &[ast::Token]) -> Result<ast::File,
     let state = ParseState::new(tokens);
     parse_file_raw(declaration, &state)
 }
+
+/// Parses into an AST file like [`parse_file`] but it does not stop at
+/// the first error. Statements failed to parse become [`ast::Stmt::Error`]
+/// nodes and every error is given in the order they appear.
+pub fn parse_file_recovered(
+    declaration: bool,
+    tokens: &[ast::Token],
+) -> (ast::File, Vec<ParseError>) {
+    let errors = RefCell::new(Vec::new());
+    let mut state = ParseState::with_recovery(tokens, &errors).with_declaration(declaration);
+
+    let start = state.current().unwrap().span();
+    let mut end = start;
+    let mut stmts = Vec::new();
+    let mut last_stmt = None;
+    let mut stray_span = None;
+
+    loop {
+        let (new_state, block) = match ParseBlock.parse(&state) {
+            Ok(result) => result,
+            Err(err) => {
+                state.report(err).ok();
+                break;
+            }
+        };
+        end = block.span();
+
+        match stray_span {
+            None => {
+                stmts = block.stmts().clone();
+                last_stmt = block.last_stmt().clone();
+            }
+            // statements cannot be after the last statement
+            Some(stray_span) if last_stmt.is_none() => {
+                stmts.push(ast::Stmt::Error(ast::Span::merge(stray_span, block.span())));
+            }
+            Some(..) => {}
+        }
+
+        let stray = new_state.current().unwrap();
+        if matches!(stray.ty(), ast::TokenType::Eof) {
+            break;
+        }
+
+        // a closing keyword without its block, the rest of the
+        // file is parsed again to find more errors.
+        new_state
+            .report(ParseError {
+                span: stray.span(),
+                ty: ParseErrorType::UnexpectedToken(stray.clone()),
+            })
+            .ok();
+        stray_span = Some(stray.span());
+        state = new_state.next(1);
+    }
+
+    let block = ast::Block::new(ast::Span::merge(start, end), stmts, last_stmt);
+    let mut errors = errors.into_inner();
+    if declaration {
+        errors.extend(declaration_errors(&block));
+    }
+    errors.sort_by_key(|v| v.span.start);
+
+    (ast::File::new(declaration, block.span(), block), errors)
+}
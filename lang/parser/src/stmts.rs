@@ -4,11 +4,35 @@ use crate::ast;
 use crate::{expect, no_match_ignore, optional, parse_either, parser_struct};
 use salite_traits::SpannedNode;
 
+/// Gets the current token if it is the name that has a meaning
+/// only in some places like `export` and `declare`.
+fn contextual_keyword<'a>(state: &ParseState<'a>, keyword: &str) -> Option<&'a ast::Token> {
+    state
+        .current()
+        .filter(|token| matches!(token.ty(), ast::TokenType::Identifier(name) if name == keyword))
+}
+
 pub struct ParseBreakStmt;
 parser_struct!(ParseBreakStmt, ast::Token, |_, state: &ParseState<'a>| {
     ParseSymbol(ast::SymbolType::Break).parse(state)
 });
 
+pub struct ParseContinueStmt;
+parser_struct!(
+    ParseContinueStmt,
+    ast::Token,
+    |_, state: &ParseState<'a>| {
+        let (new_state, token) = ParseSymbol(ast::SymbolType::Continue).parse(state)?;
+        if !state.in_loop {
+            new_state.report(ParseError {
+                span: token.span(),
+                ty: ParseErrorType::ContinueOutsideLoop,
+            })?;
+        }
+        Ok((new_state, token))
+    }
+);
+
 pub struct ParseCallStmt;
 parser_struct!(ParseCallStmt, ast::Expr, |_, state: &ParseState<'a>| {
     let (state, suffix) = ParsePresuffixExpr.parse(state)?;
@@ -56,6 +80,53 @@ parser_struct!(
     }
 );
 
+pub struct ParseCompoundOpKind;
+parser_struct!(ParseCompoundOpKind, ast::BinopKind, |_,
+                                                     state: &ParseState<
+    'a,
+>| {
+    parse_either!(state, {
+        ParseSymbol(ast::SymbolType::CrossEqual) => |_| ast::BinopKind::Add,
+        ParseSymbol(ast::SymbolType::DashEqual) => |_| ast::BinopKind::Subtract,
+        ParseSymbol(ast::SymbolType::AsteriskEqual) => |_| ast::BinopKind::Multiply,
+        ParseSymbol(ast::SymbolType::DoubleSlashEqual) => |_| ast::BinopKind::FloorDivision,
+        ParseSymbol(ast::SymbolType::SlashEqual) => |_| ast::BinopKind::Divide,
+        ParseSymbol(ast::SymbolType::PercentEqual) => |_| ast::BinopKind::Modulo,
+        ParseSymbol(ast::SymbolType::CaretEqual) => |_| ast::BinopKind::Exponent,
+        ParseSymbol(ast::SymbolType::DoubleDotEqual) => |_| ast::BinopKind::Concat,
+    })
+});
+
+pub struct ParseCompoundOp;
+parser_struct!(ParseCompoundOp, ast::Binop, |_, state: &ParseState<'a>| {
+    // to preserve the token
+    let token = state.current();
+    let (state, kind) = ParseCompoundOpKind.parse(state)?;
+    let token = token.unwrap();
+    Ok((
+        state,
+        ast::Binop {
+            kind,
+            token: token.clone(),
+        },
+    ))
+});
+
+pub struct ParseCompoundAssign;
+parser_struct!(
+    ParseCompoundAssign,
+    ast::CompoundAssign,
+    |_, state: &ParseState<'a>| {
+        let (state, name) = ParseVarAssignName.parse(state)?;
+        let (state, op) = ParseCompoundOp.parse(&state)?;
+        let (state, expr) = expect!(&state, ParseExpr, "<exp>");
+        Ok((
+            state,
+            ast::CompoundAssign::new(ast::Span::merge(name.span(), expr.span()), name, op, expr),
+        ))
+    }
+);
+
 pub struct ParseVarAssign;
 parser_struct!(
     ParseVarAssign,
@@ -188,7 +259,7 @@ parser_struct!(ParseRepeatStmt, ast::RepeatStmt, |_,
     'a,
 >| {
     let (state, start) = ParseSymbol(ast::SymbolType::Repeat).parse(state)?;
-    let (state, block) = ParseBlock.parse(&state)?;
+    let (state, block) = ParseLoopBlock.parse(&state)?;
     let (state, _) = expect!(&state, ParseSymbol(ast::SymbolType::Until), "until");
     let (state, condition) = expect!(&state, ParseExpr, "<exp>");
     Ok((
@@ -224,7 +295,7 @@ parser_struct!(
         let (state, start) = ParseSymbol(ast::SymbolType::While).parse(state)?;
         let (state, condition) = expect!(&state, ParseExpr, "<exp>");
         let (state, _) = expect!(&state, ParseSymbol(ast::SymbolType::Do), "do");
-        let (state, block) = ParseBlock.parse(&state)?;
+        let (state, block) = ParseLoopBlock.parse(&state)?;
         let (state, end) = expect!(&state, ParseSymbol(ast::SymbolType::End), "end");
         Ok((
             state,
@@ -298,7 +369,7 @@ parser_struct!(ParseGenericFor, ast::GenericFor, |_,
     let (state, _) = ParseSymbol(ast::SymbolType::In).parse(&state)?;
     let (state, exprlist) = ParseExprListRequired.parse(&state)?;
     let (state, _) = expect!(&state, ParseSymbol(ast::SymbolType::Do), "do");
-    let (state, block) = ParseBlock.parse(&state)?;
+    let (state, block) = ParseLoopBlock.parse(&state)?;
     let (state, end) = expect!(&state, ParseSymbol(ast::SymbolType::End), "end");
     Ok((
         state,
@@ -330,7 +401,7 @@ parser_struct!(ParseNumericFor, ast::NumericFor, |_,
             (state, None)
         };
     let (state, _) = expect!(&state, ParseSymbol(ast::SymbolType::Do), "do");
-    let (state, block) = ParseBlock.parse(&state)?;
+    let (state, block) = ParseLoopBlock.parse(&state)?;
     let (state, end) = expect!(&state, ParseSymbol(ast::SymbolType::End), "end");
     Ok((
         state,
@@ -398,8 +469,13 @@ parser_struct!(
     ParseTypeDeclaration,
     ast::TypeDeclaration,
     |_, state: &ParseState<'a>| {
-        let (state, start_tok) = ParseSymbol(ast::SymbolType::Type).parse(state)?;
-        let start = start_tok.span().start;
+        // `export` is not a keyword, it can still be a name of a variable
+        let (state, export_start) = match contextual_keyword(state, "export") {
+            Some(token) => (state.next(1), Some(token.span().start)),
+            None => (state.clone(), None),
+        };
+        let (state, start_tok) = ParseSymbol(ast::SymbolType::Type).parse(&state)?;
+        let start = export_start.unwrap_or(start_tok.span().start);
         let (state, name) = expect!(&state, ParseName, "<id>");
         let (state, params) =
             if let Ok((new_state, params)) = no_match_ignore!(&state, ParseTypeParameters) {
@@ -412,7 +488,100 @@ parser_struct!(
         let end = typ.span().end;
         Ok((
             state,
-            ast::TypeDeclaration::new(ast::Span::new(start, end), name, params, typ),
+            ast::TypeDeclaration::new(
+                ast::Span::new(start, end),
+                export_start.is_some(),
+                name,
+                params,
+                typ,
+            ),
+        ))
+    }
+);
+
+/// Parses `declare` and the keyword after it, the `declare`
+/// token is given back.
+fn parse_declare<'a>(
+    state: &ParseState<'a>,
+    keyword: Option<ast::SymbolType>,
+) -> ParseResult<'a, &'a ast::Token> {
+    // `declare` is not a keyword, it can still be a name of a variable
+    let declare = match contextual_keyword(state, "declare") {
+        Some(token) => token,
+        None => no_match!(state),
+    };
+    let state = state.next(1);
+    let state = match keyword {
+        Some(keyword) => ParseSymbol(keyword).parse(&state)?.0,
+        None => match contextual_keyword(&state, "module") {
+            Some(..) => state.next(1),
+            None => no_match!(state),
+        },
+    };
+    if !state.declaration {
+        state.report(ParseError {
+            span: declare.span(),
+            ty: ParseErrorType::DeclareOutsideDeclaration,
+        })?;
+    }
+    Ok((state, declare))
+}
+
+pub struct ParseDeclareFunction;
+parser_struct!(
+    ParseDeclareFunction,
+    ast::DeclareFunction,
+    |_, state: &ParseState<'a>| {
+        let (state, declare) = parse_declare(state, Some(ast::SymbolType::Function))?;
+        let (state, name) = expect!(&state, ParseName, "<id>");
+        let (state, typ) = expect!(&state, ParseTypeCallback, "(");
+        Ok((
+            state,
+            ast::DeclareFunction::new(
+                ast::Span::new(declare.span().start, typ.span().end),
+                name,
+                typ,
+            ),
+        ))
+    }
+);
+
+pub struct ParseDeclareLocal;
+parser_struct!(
+    ParseDeclareLocal,
+    ast::DeclareLocal,
+    |_, state: &ParseState<'a>| {
+        let (state, declare) = parse_declare(state, Some(ast::SymbolType::Local))?;
+        let (state, name) = expect!(&state, ParseName, "<id>");
+        let (state, _) = expect!(&state, ParseSymbol(ast::SymbolType::Colon), ":");
+        let (state, typ) = expect!(&state, ParseTypeInfo, "<type>");
+        Ok((
+            state,
+            ast::DeclareLocal::new(
+                ast::Span::new(declare.span().start, typ.span().end),
+                name,
+                typ,
+            ),
+        ))
+    }
+);
+
+pub struct ParseDeclareModule;
+parser_struct!(
+    ParseDeclareModule,
+    ast::DeclareModule,
+    |_, state: &ParseState<'a>| {
+        let (state, declare) = parse_declare(state, None)?;
+        let (state, name) = expect!(&state, ParseStr, "<string>");
+        let (state, _) = expect!(&state, ParseSymbol(ast::SymbolType::Colon), ":");
+        let (state, typ) = expect!(&state, ParseTypeInfo, "<type>");
+        Ok((
+            state,
+            ast::DeclareModule::new(
+                ast::Span::new(declare.span().start, typ.span().end),
+                name,
+                typ,
+            ),
         ))
     }
 );
@@ -421,17 +590,22 @@ pub struct ParseStmt;
 parser_struct!(ParseStmt, ast::Stmt, |_, state: &ParseState<'a>| {
     parse_either!(state, {
         ParseFunctionAssign => ast::Stmt::FunctionAssign,
+        // before call statements, `if` would be parsed as an if-expression
+        ParseIfStmt => ast::Stmt::If,
         ParseCallStmt => ast::Stmt::Call,
         ParseDoStmt => ast::Stmt::Do,
         ParseGenericFor => ast::Stmt::GenericFor,
-        ParseIfStmt => ast::Stmt::If,
         ParseLocalAssign => ast::Stmt::LocalAssign,
         ParseLocalFunction => ast::Stmt::LocalFunction,
         ParseNumericFor => ast::Stmt::NumericFor,
         ParseRepeatStmt => ast::Stmt::Repeat,
         ParseWhileStmt => ast::Stmt::While,
         ParseVarAssign => ast::Stmt::VarAssign,
+        ParseCompoundAssign => ast::Stmt::CompoundAssign,
         ParseTypeDeclaration => ast::Stmt::TypeDeclaration,
+        ParseDeclareFunction => ast::Stmt::DeclareFunction,
+        ParseDeclareLocal => ast::Stmt::DeclareLocal,
+        ParseDeclareModule => ast::Stmt::DeclareModule,
     })
 });
 
@@ -439,6 +613,7 @@ pub struct ParseLastStmt;
 parser_struct!(ParseLastStmt, ast::Stmt, |_, state: &ParseState<'a>| {
     parse_either!(state, {
         ParseBreakStmt => ast::Stmt::Break,
+        ParseContinueStmt => ast::Stmt::Continue,
         ParseReturnStmt => ast::Stmt::Return,
     })
 });
@@ -454,6 +629,102 @@ parser_struct!(ParseEndOfBlock, (), |_, state: &ParseState<'a>| {
     })
 });
 
+/// Checks if a statement or a block can start (or end) at the token,
+/// the parser recovers from errors at these tokens.
+fn is_statement_boundary(token: &ast::Token) -> bool {
+    matches!(
+        token.ty(),
+        ast::TokenType::Eof
+            | ast::TokenType::Symbol(
+                ast::SymbolType::Local
+                    | ast::SymbolType::Function
+                    | ast::SymbolType::Return
+                    | ast::SymbolType::Break
+                    | ast::SymbolType::Continue
+                    | ast::SymbolType::If
+                    | ast::SymbolType::While
+                    | ast::SymbolType::For
+                    | ast::SymbolType::Do
+                    | ast::SymbolType::Repeat
+                    | ast::SymbolType::Type
+                    | ast::SymbolType::End
+                    | ast::SymbolType::ElseIf
+                    | ast::SymbolType::Else
+                    | ast::SymbolType::Until
+            )
+    )
+}
+
+/// Reports the error of the statement starting at the current state
+/// and skips every token until the next statement boundary. It gives
+/// the span of the skipped tokens.
+///
+/// The error is given back if the parser does not recover from errors.
+fn recover_stmt<'a>(state: &ParseState<'a>, err: ParseError) -> ParseResult<'a, ast::Span> {
+    let error_start = err.span.start;
+    state.report(err)?;
+
+    // tokens before the error are fine but they belong to the statement
+    let mut offset = state.offset;
+    while let Some(token) = state.tokens.get(offset) {
+        if token.span().start >= error_start || matches!(token.ty(), ast::TokenType::Eof) {
+            break;
+        }
+        offset += 1;
+    }
+
+    // it has to move on at least one token
+    if offset == state.offset && !matches!(state.current().unwrap().ty(), ast::TokenType::Eof) {
+        offset += 1;
+    }
+    // brackets opened in the skipped tokens are never closed, the
+    // expression is unterminated and it is reported at the boundary
+    let mut opened = Vec::new();
+    while let Some(token) = state.tokens.get(offset) {
+        if is_statement_boundary(token) {
+            break;
+        }
+        match token.ty() {
+            ast::TokenType::Symbol(ast::SymbolType::OpenParen) => opened.push(")"),
+            ast::TokenType::Symbol(ast::SymbolType::OpenBracket) => opened.push("]"),
+            ast::TokenType::Symbol(ast::SymbolType::OpenCurly) => opened.push("}"),
+            ast::TokenType::Symbol(
+                ast::SymbolType::CloseParen
+                | ast::SymbolType::CloseBracket
+                | ast::SymbolType::CloseCurly,
+            ) => {
+                opened.pop();
+            }
+            _ => {}
+        }
+        offset += 1;
+    }
+    if let (Some(expected), Some(token)) = (opened.pop(), state.tokens.get(offset)) {
+        state.report(ParseError {
+            span: token.span(),
+            ty: ParseErrorType::Expected {
+                expected: expected.into(),
+                token: token.clone(),
+            },
+        })?;
+    }
+
+    let start = state.current().unwrap().span();
+    let end = state.tokens[offset.max(state.offset + 1) - 1].span();
+    Ok((
+        state.next(offset - state.offset),
+        ast::Span::merge(start, end),
+    ))
+}
+
+/// Parses the block of a loop, `continue` is
+/// allowed inside of the block.
+pub struct ParseLoopBlock;
+parser_struct!(ParseLoopBlock, ast::Block, |_, state: &ParseState<'a>| {
+    let (new_state, block) = ParseBlock.parse(&state.with_loop(true))?;
+    Ok((new_state.with_loop(state.in_loop), block))
+});
+
 pub struct ParseBlock;
 parser_struct!(ParseBlock, ast::Block, |_, state: &ParseState<'a>| {
     let start_position = state.current().unwrap().span();
@@ -463,20 +734,82 @@ parser_struct!(ParseBlock, ast::Block, |_, state: &ParseState<'a>| {
 
     let mut last_stmt = None;
 
-    while let Ok((ns, stmt)) = no_match_ignore!(&state, ParseStmt) {
-        let (ns, _) = optional!(&ns, ParseSymbol(ast::SymbolType::Semicolon));
-        state = ns;
-        stmts.push(stmt);
-    }
+    // statements after the last statement are only
+    // parsed to find more errors
+    let mut past_last_stmt = false;
+
+    loop {
+        if last_stmt.is_none() || past_last_stmt {
+            match ParseStmt.parse(&state) {
+                Ok((ns, stmt)) => {
+                    let (ns, _) = optional!(&ns, ParseSymbol(ast::SymbolType::Semicolon));
+                    state = ns;
+                    if !past_last_stmt {
+                        stmts.push(stmt);
+                    }
+                    continue;
+                }
+                Err(ParseError {
+                    ty: ParseErrorType::Internal(InternalParseError::NoMatch),
+                    ..
+                }) => {}
+                Err(err) => {
+                    let (ns, span) = recover_stmt(&state, err)?;
+                    state = ns;
+                    if !past_last_stmt {
+                        stmts.push(ast::Stmt::Error(span));
+                    }
+                    continue;
+                }
+            }
+        }
+
+        if last_stmt.is_none() {
+            match ParseLastStmt.parse(&state) {
+                Ok((ns, stmt)) => {
+                    let (ns, _) = optional!(&ns, ParseSymbol(ast::SymbolType::Semicolon));
+                    last_stmt = Some(Box::new(stmt));
+                    state = ns;
+                }
+                Err(ParseError {
+                    ty: ParseErrorType::Internal(InternalParseError::NoMatch),
+                    ..
+                }) => {}
+                Err(err) => {
+                    let (ns, span) = recover_stmt(&state, err)?;
+                    state = ns;
+                    stmts.push(ast::Stmt::Error(span));
+                    continue;
+                }
+            }
+        }
 
-    if let Ok((ns, stmt)) = ParseLastStmt.parse(&state) {
-        let (ns, _) = optional!(&ns, ParseSymbol(ast::SymbolType::Semicolon));
-        last_stmt = Some(Box::new(stmt));
+        // checks if it is in eof, end, elseif or else
+        let err = match ParseEndOfBlock.parse(&state) {
+            Ok(..) => break,
+            Err(..) => {
+                let token = state.current().unwrap();
+                ParseError {
+                    span: token.span(),
+                    ty: ParseErrorType::Expected {
+                        expected: "end of block".into(),
+                        token: token.clone(),
+                    },
+                }
+            }
+        };
+        if last_stmt.is_some() && !past_last_stmt {
+            state.report(err)?;
+            past_last_stmt = true;
+            continue;
+        }
+        let (ns, span) = recover_stmt(&state, err)?;
         state = ns;
+        if !past_last_stmt {
+            stmts.push(ast::Stmt::Error(span));
+        }
     }
 
-    // checks if it is in eof, end, elseif or else
-    let _ = expect!(&state, ParseEndOfBlock, "end of block");
     let end_position = state.current().unwrap().span();
     Ok((
         state,
@@ -18,11 +18,16 @@ parser_struct!(
     }
 );
 
-pub struct ParseBaseTypeInfo;
+pub struct ParseSingleTypeInfo;
 #[rustfmt::skip]
-parser_struct!(ParseBaseTypeInfo, ast::TypeInfo, |_, state: &ParseState<'a,>| {
+parser_struct!(ParseSingleTypeInfo, ast::TypeInfo, |_, state: &ParseState<'a,>| {
     parse_either!(state, {
         ParseTypeCallback => ast::TypeInfo::Callback,
+        ParseStr => ast::TypeInfo::Literal,
+        ParseNumber => ast::TypeInfo::Literal,
+        ParseSymbol(ast::SymbolType::True) => ast::TypeInfo::Literal,
+        ParseSymbol(ast::SymbolType::False) => ast::TypeInfo::Literal,
+        ParseTypeOf => ast::TypeInfo::TypeOf,
         ParseTypeReference => ast::TypeInfo::Reference,
         ParseTypeTable => ast::TypeInfo::Table,
         ParseTypeMetatable => ast::TypeInfo::Metatable,
@@ -30,6 +35,32 @@ parser_struct!(ParseBaseTypeInfo, ast::TypeInfo, |_, state: &ParseState<'a,>| {
     })
 });
 
+pub struct ParseBaseTypeInfo;
+#[rustfmt::skip]
+parser_struct!(ParseBaseTypeInfo, ast::TypeInfo, |_, state: &ParseState<'a>| {
+    let (state, base) = ParseSingleTypeInfo.parse(state)?;
+
+    // `T?` is a shorthand of `T | nil`
+    let (state, question) = optional!(&state, ParseSymbol(ast::SymbolType::Question));
+    let question = match question {
+        Some(question) => question,
+        None => return Ok((state, base)),
+    };
+    let nil = ast::TypeReference::new(
+        question.span(),
+        None,
+        None,
+        ast::Token::new(ast::TokenType::Symbol(ast::SymbolType::Nil), question.span()),
+    );
+    Ok((
+        state,
+        ast::TypeInfo::Union(ast::TypeUnion::new(
+            ast::Span::merge(base.span(), question.span()),
+            vec![base, ast::TypeInfo::Reference(nil)],
+        )),
+    ))
+});
+
 pub struct ParseTypeInfo;
 parser_struct!(ParseTypeInfo, ast::TypeInfo, |_, state: &ParseState<'a>| {
     let (mut state, mut base) = ParseBaseTypeInfo.parse(state)?;
@@ -79,6 +110,24 @@ parser_struct!(
     }
 );
 
+pub struct ParseTypeOf;
+parser_struct!(ParseTypeOf, ast::TypeOf, |_, state: &ParseState<'a>| {
+    // `typeof` is not a keyword, it can still be a name of a type
+    let start = match state.current() {
+        Some(token) if matches!(token.ty(), ast::TokenType::Identifier(name) if name == "typeof") => {
+            token.span().start
+        }
+        _ => no_match!(state),
+    };
+    let (state, _) = ParseSymbol(ast::SymbolType::OpenParen).parse(&state.next(1))?;
+    let (state, expr) = expect!(&state, ParseExpr, "<exp>");
+    let (state, end) = expect!(&state, ParseSymbol(ast::SymbolType::CloseParen), ")");
+    Ok((
+        state,
+        ast::TypeOf::new(ast::Span::new(start, end.span().end), Box::new(expr)),
+    ))
+});
+
 pub struct ParseTypeMetatable;
 parser_struct!(
     ParseTypeMetatable,
@@ -232,20 +281,49 @@ parser_struct!(
     ParseTypeCallback,
     ast::TypeCallback,
     |_, state: &ParseState<'a>| {
-        let (state, start_token) = ParseSymbol(ast::SymbolType::OpenParen).parse(state)?;
-        let (state, params) = ZeroOrMorePunctuated(
+        let start = state.current().map(|v| v.span().start).unwrap_or_default();
+        let (state, type_params) =
+            if let Ok((new_state, params)) = no_match_ignore!(state, ParseTypeParameters) {
+                (new_state, Some(params))
+            } else {
+                (state.next(0), None)
+            };
+        let (state, _) = ParseSymbol(ast::SymbolType::OpenParen).parse(&state)?;
+        let (mut state, params) = ZeroOrMorePunctuated(
             ParseTypeCallbackParameter,
             ParseSymbol(ast::SymbolType::Comma),
         )
         .parse(&state)?;
+        let mut varidiac = None;
+
+        if let Ok((new_state, _)) = ParseSymbol(ast::SymbolType::Comma).parse(&state) {
+            if ParseSymbol(ast::SymbolType::TripleDot)
+                .parse(&new_state)
+                .is_ok()
+            {
+                let (new_state, result) = ParseVaridiacParam.parse(&new_state)?;
+                varidiac = Some(Box::new(result));
+                state = new_state;
+            }
+        } else if ParseSymbol(ast::SymbolType::TripleDot)
+            .parse(&state)
+            .is_ok()
+        {
+            let (new_state, result) = ParseVaridiacParam.parse(&state)?;
+            varidiac = Some(Box::new(result));
+            state = new_state;
+        }
+
         let (state, _) = ParseSymbol(ast::SymbolType::CloseParen).parse(&state)?;
         let (state, _) = ParseSymbol(ast::SymbolType::SkinnyArrow).parse(&state)?;
         let (state, return_type) = expect!(&state, ParseTypeInfo, "<type>");
         Ok((
             state,
             ast::TypeCallback::new(
-                ast::Span::new(start_token.span().start, return_type.span().end),
+                ast::Span::new(start, return_type.span().end),
+                type_params,
                 params,
+                varidiac,
                 Box::new(return_type),
             ),
         ))
@@ -257,7 +335,21 @@ parser_struct!(
     ParseTypeReference,
     ast::TypeReference,
     |_, state: &ParseState<'a>| {
-        let (state, name) = ParseName.parse(state)?;
+        // `nil` is a keyword, not a name
+        let (state, name) = match ParseSymbol(ast::SymbolType::Nil).parse(state) {
+            Ok(result) => result,
+            Err(..) => ParseName.parse(state)?,
+        };
+        // `M.Config` refers to a type exported by the module in `M`
+        let (state, module, name) = match ParseSymbol(ast::SymbolType::Dot).parse(&state) {
+            Ok((new_state, _))
+                if !matches!(name.ty(), ast::TokenType::Symbol(ast::SymbolType::Nil)) =>
+            {
+                let (new_state, member) = expect!(&new_state, ParseName, "<id>");
+                (new_state, Some(name), member)
+            }
+            _ => (state, None, name),
+        };
         let (state, arguments, end_span) =
             if let Ok((new_state, _)) = ParseSymbol(ast::SymbolType::LessThan).parse(&state) {
                 let (new_state, collection) = ParseTypeArguments.parse(&new_state)?;
@@ -267,10 +359,10 @@ parser_struct!(
             } else {
                 (state, None, name.span().end)
             };
-        let name_span = name.span();
+        let start = module.as_ref().unwrap_or(&name).span().start;
         Ok((
             state,
-            ast::TypeReference::new(ast::Span::new(name_span.start, end_span), arguments, name),
+            ast::TypeReference::new(ast::Span::new(start, end_span), arguments, module, name),
         ))
     }
 );
@@ -4,9 +4,15 @@ pub use salite_ast as ast;
 #[cfg(any(feature = "checker", feature = "full"))]
 pub use salite_checker as checker;
 
+#[cfg(any(feature = "codegen", feature = "full"))]
+pub use salite_codegen as codegen;
+
 #[cfg(any(feature = "common", feature = "full"))]
 pub use salite_common as common;
 
+#[cfg(any(feature = "formatter", feature = "full"))]
+pub use salite_formatter as formatter;
+
 #[cfg(any(feature = "macros", feature = "full"))]
 pub use salite_macros as macros;
 
@@ -54,3 +60,28 @@ pub fn lazy_parse(declaration: bool, input: &str) -> Result<ast::File, LazyParse
     let tokens = ast::filter_non_trivia_tokens(tokens);
     parser::parse_file(declaration, &tokens).map_err(LazyParseError::ParseError)
 }
+
+/// Parses any file like [`lazy_parse`] but it does not stop at the
+/// first parse error, the partial AST is given with every parse error.
+#[cfg(any(feature = "lazy_parse", feature = "full"))]
+pub fn lazy_parse_recovered(
+    declaration: bool,
+    input: &str,
+) -> Result<(ast::File, Vec<errors::parser::ParseError>), LazyParseError> {
+    let tokens = tokenizer::tokenize(input).map_err(LazyParseError::TokenizeError)?;
+    let tokens = ast::filter_non_trivia_tokens(tokens);
+    Ok(parser::parse_file_recovered(declaration, &tokens))
+}
+
+/// Parses any file into a lossless concrete syntax tree and the AST
+/// derived from it. Printing the tree gives the same source code back.
+#[cfg(any(feature = "lazy_parse", feature = "full"))]
+pub fn lazy_parse_cst(
+    declaration: bool,
+    input: &str,
+) -> Result<(ast::cst::Cst, ast::File), LazyParseError> {
+    let tokens = tokenizer::tokenize(input).map_err(LazyParseError::TokenizeError)?;
+    let cst = ast::cst::Cst::new(input, tokens);
+    let file = parser::parse_cst(declaration, &cst).map_err(LazyParseError::ParseError)?;
+    Ok((cst, file))
+}
@@ -1,4 +1,5 @@
 use logos::{Lexer, Logos};
+use salite_ast::InterpolatedPart;
 
 pub(crate) fn get_equal_brackets(slice: &str) -> Option<usize> {
     if !slice.starts_with('[') {
@@ -48,12 +49,82 @@ fn read_string(lex: &mut Lexer<Atom>, quote: char) -> bool {
             lex.bump(1);
             return true;
         }
-        escape = char == '\\';
+        escape = !escape && char == '\\';
         lex.bump(char.len_utf8());
     }
     false
 }
 
+/// Takes the point after a decimal number without fractional digits
+/// (and the exponent after it), `5.` and `5.e3` are numbers but `5..x`
+/// is a concatenation.
+fn trailing_point(lex: &mut Lexer<Atom>) {
+    if lex.slice().contains(['.', 'e', 'E']) {
+        return;
+    }
+    let remainder = lex.remainder();
+    if !remainder.starts_with('.') || remainder[1..].starts_with('.') {
+        return;
+    }
+    let exponent = match remainder[1..].strip_prefix(['e', 'E']) {
+        Some(rest) => {
+            let sign = rest.starts_with(['+', '-']) as usize;
+            let digits = rest[sign..]
+                .chars()
+                .take_while(|v| v.is_ascii_digit() || *v == '_')
+                .count();
+            1 + sign + digits
+        }
+        None => 0,
+    };
+    lex.bump(1 + exponent);
+}
+
+/// Reads the text of an interpolated string until an expression
+/// starts or the string ends. `begin` is true if the text is
+/// after the opening backtick, otherwise after the closing brace
+/// of an expression.
+fn read_interpolated_str(lex: &mut Lexer<Atom>, begin: bool) -> Option<InterpolatedPart> {
+    let mut escape = false;
+    for char in lex.remainder().chars() {
+        lex.bump(char.len_utf8());
+        match (escape, char) {
+            (false, '`') if begin => return Some(InterpolatedPart::Simple),
+            (false, '`') => {
+                lex.extras.pop();
+                return Some(InterpolatedPart::End);
+            }
+            (false, '{') if begin => {
+                lex.extras.push(0);
+                return Some(InterpolatedPart::Begin);
+            }
+            (false, '{') => return Some(InterpolatedPart::Mid),
+            _ => {}
+        }
+        escape = !escape && char == '\\';
+    }
+    None
+}
+
+fn open_brace(lex: &mut Lexer<Atom>) {
+    if let Some(depth) = lex.extras.last_mut() {
+        *depth += 1;
+    }
+}
+
+/// Closing braces of expressions inside of interpolated strings
+/// continue the string.
+fn close_brace(lex: &mut Lexer<Atom>) -> Option<Option<InterpolatedPart>> {
+    match lex.extras.last_mut() {
+        Some(0) => read_interpolated_str(lex, false).map(Some),
+        Some(depth) => {
+            *depth -= 1;
+            Some(None)
+        }
+        None => Some(None),
+    }
+}
+
 fn parse_comment(lexer: &mut Lexer<Atom>) -> bool {
     if lexer
         .slice()
@@ -73,7 +144,10 @@ fn parse_comment(lexer: &mut Lexer<Atom>) -> bool {
     }
 }
 
+/// Extras are the brace depths of the interpolated strings
+/// which the lexer is inside of, innermost is the last one.
 #[derive(Logos, Debug, Clone, PartialEq)]
+#[logos(extras = Vec<usize>)]
 pub enum Atom {
     #[token("and")]
     And,
@@ -81,6 +155,9 @@ pub enum Atom {
     #[token("break")]
     Break,
 
+    #[token("continue")]
+    Continue,
+
     #[token("do")]
     Do,
 
@@ -180,7 +257,7 @@ pub enum Atom {
     #[token("[")]
     LeftBracket,
 
-    #[token("{")]
+    #[token("{", open_brace)]
     LeftBrace,
 
     #[token("(")]
@@ -201,8 +278,10 @@ pub enum Atom {
     #[token("+")]
     Plus,
 
-    #[token("}")]
-    RightBrace,
+    /// It has the part of an interpolated string if the
+    /// brace closes an expression inside of it.
+    #[token("}", close_brace)]
+    RightBrace(Option<InterpolatedPart>),
 
     #[token("]")]
     RightBracket,
@@ -213,6 +292,9 @@ pub enum Atom {
     #[token(";")]
     Semicolon,
 
+    #[token("//")]
+    DoubleSlash,
+
     #[token("/")]
     Slash,
 
@@ -225,6 +307,33 @@ pub enum Atom {
     #[token("->")]
     SkinnyArrow,
 
+    #[token("+=")]
+    PlusEqual,
+
+    #[token("-=")]
+    MinusEqual,
+
+    #[token("*=")]
+    AsteriskEqual,
+
+    #[token("//=")]
+    DoubleSlashEqual,
+
+    #[token("/=")]
+    SlashEqual,
+
+    #[token("%=")]
+    PercentEqual,
+
+    #[token("^=")]
+    CaretEqual,
+
+    #[token("..=")]
+    TwoDotsEqual,
+
+    #[token("??")]
+    DoubleQuestion,
+
     #[token("?")]
     Question,
 
@@ -240,9 +349,12 @@ pub enum Atom {
     #[regex(r"--((\[=*\[)|\[)?", |l| parse_comment(l))]
     Comment,
 
-    #[regex(r"0[xX][A-Fa-f0-9]+")]
-    #[regex(r"\.[0-9]+([eE][+-]?[0-9]+)?")]
-    #[regex(r"[0-9]+(.[0-9]+)?([eE][-+]?[0-9]+)?")]
+    // malformed numbers (like `0x` and `1e`) are tokenized
+    // as well, they are checked once they are parsed
+    #[regex(r"0[xX][0-9A-Fa-f_]*(\.[0-9A-Fa-f_]*)?([pP][+-]?[0-9_]*)?")]
+    #[regex(r"0[bB][0-9_]*")]
+    #[regex(r"\.[0-9][0-9_]*([eE][+-]?[0-9_]*)?")]
+    #[regex(r"[0-9][0-9_]*(\.[0-9_]+)?([eE][+-]?[0-9_]*)?", trailing_point)]
     Number,
 
     #[regex(r"[a-zA-Z_][a-zA-Z0-9_]*")]
@@ -263,6 +375,9 @@ pub enum Atom {
     #[regex(r"'", |x| read_string(x, '\''))]
     ApostropheString,
 
+    #[regex(r"`", |x| read_interpolated_str(x, true))]
+    InterpolatedStr(InterpolatedPart),
+
     #[error]
     Unknown,
 }
@@ -291,6 +406,25 @@ mod tests {
         tokenize_cmp!("0.31416E1", Atom::Number);
         tokenize_cmp!("0xff", Atom::Number);
         tokenize_cmp!("0X56", Atom::Number);
+        tokenize_cmp!("0x1.8p-3", Atom::Number);
+        tokenize_cmp!("0b1010_0101", Atom::Number);
+        tokenize_cmp!("1_000_000", Atom::Number);
+        tokenize_cmp!(".5e3", Atom::Number);
+
+        let mut lexer = Atom::lexer("5. + 5.e3");
+        assert_eq!(lexer.next(), Some(Atom::Number));
+        assert_eq!(lexer.slice(), "5.");
+        lexer.nth(2);
+        assert_eq!(lexer.next(), Some(Atom::Number));
+        assert_eq!(lexer.slice(), "5.e3");
+
+        let mut lexer = Atom::lexer("1..2");
+        assert_eq!(lexer.next(), Some(Atom::Number));
+        assert_eq!(lexer.slice(), "1");
+
+        let mut lexer = Atom::lexer("0xe-1");
+        assert_eq!(lexer.next(), Some(Atom::Number));
+        assert_eq!(lexer.slice(), "0xe");
     }
 
     #[test]
@@ -307,6 +441,36 @@ mod tests {
         tokenize_cmp!("[=[alo\n123\"]===]", Atom::Unknown);
     }
 
+    #[test]
+    fn tokenize_interpolated_string() {
+        tokenize_cmp!("`Hello`", Atom::InterpolatedStr(InterpolatedPart::Simple));
+        tokenize_cmp!(
+            "`\\{Hello\\``",
+            Atom::InterpolatedStr(InterpolatedPart::Simple)
+        );
+        tokenize_cmp!("`Hello", Atom::Unknown);
+
+        let mut lexer = Atom::lexer("`a{b}c{ {} }d`}");
+        let mut atoms = Vec::new();
+        while let Some(atom) = lexer.next() {
+            atoms.push((atom, lexer.slice()));
+        }
+        assert_eq!(
+            atoms,
+            vec![
+                (Atom::InterpolatedStr(InterpolatedPart::Begin), "`a{"),
+                (Atom::Identifier, "b"),
+                (Atom::RightBrace(Some(InterpolatedPart::Mid)), "}c{"),
+                (Atom::Whitespace, " "),
+                (Atom::LeftBrace, "{"),
+                (Atom::RightBrace(None), "}"),
+                (Atom::Whitespace, " "),
+                (Atom::RightBrace(Some(InterpolatedPart::End)), "}d`"),
+                (Atom::RightBrace(None), "}"),
+            ]
+        );
+    }
+
     #[test]
     fn tokenize_shebang() {
         tokenize_cmp!("#!/usr/bin\n", Atom::Shebang);
@@ -322,6 +486,18 @@ mod tests {
         tokenize_cmp!("\r", Atom::Unknown);
     }
 
+    #[test]
+    fn tokenize_operator() {
+        tokenize_cmp!("//", Atom::DoubleSlash);
+        tokenize_cmp!("/", Atom::Slash);
+        tokenize_cmp!("??", Atom::DoubleQuestion);
+        tokenize_cmp!("?", Atom::Question);
+        tokenize_cmp!("+=", Atom::PlusEqual);
+        tokenize_cmp!("//=", Atom::DoubleSlashEqual);
+        tokenize_cmp!("..=", Atom::TwoDotsEqual);
+        tokenize_cmp!("...", Atom::Ellipse);
+    }
+
     #[test]
     fn tokenize_comment() {
         tokenize_cmp!("--Hello", Atom::Comment);
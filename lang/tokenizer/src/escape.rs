@@ -0,0 +1,159 @@
+use salite_ast::Span;
+
+fn encode_utf8(code: u32, output: &mut Vec<u8>) {
+    // surrogates are allowed in Lua, they can't be a `char`
+    match code {
+        0..=0x7F => output.push(code as u8),
+        0x80..=0x7FF => output.extend([0xC0 | (code >> 6) as u8, 0x80 | (code & 0x3F) as u8]),
+        0x800..=0xFFFF => output.extend([
+            0xE0 | (code >> 12) as u8,
+            0x80 | ((code >> 6) & 0x3F) as u8,
+            0x80 | (code & 0x3F) as u8,
+        ]),
+        _ => output.extend([
+            0xF0 | (code >> 18) as u8,
+            0x80 | ((code >> 12) & 0x3F) as u8,
+            0x80 | ((code >> 6) & 0x3F) as u8,
+            0x80 | (code & 0x3F) as u8,
+        ]),
+    }
+}
+
+/// Decodes the escape sequences of the contents of a quoted string.
+///
+/// `offset` is where the contents start in the source code, the
+/// exact span of the first invalid escape sequence is given back.
+/// Backticks and opening braces can only be escaped if the string
+/// is `interpolated`.
+///
+/// Bytes that are not valid UTF-8 (like `\xFF`) are replaced
+/// with `U+FFFD`.
+pub(crate) fn decode_escapes(
+    contents: &str,
+    offset: usize,
+    interpolated: bool,
+) -> Result<String, Span> {
+    let bytes = contents.as_bytes();
+    let mut value = Vec::with_capacity(bytes.len());
+    let mut idx = 0;
+
+    while idx < bytes.len() {
+        if bytes[idx] != b'\\' {
+            value.push(bytes[idx]);
+            idx += 1;
+            continue;
+        }
+
+        let start = idx;
+        let invalid = |end: usize| Span::new(offset + start, offset + end.min(bytes.len()));
+        let escape = match contents[idx + 1..].chars().next() {
+            Some(escape) => escape,
+            None => return Err(invalid(idx + 1)),
+        };
+        idx += 1 + escape.len_utf8();
+
+        match escape {
+            'a' => value.push(0x07),
+            'b' => value.push(0x08),
+            'f' => value.push(0x0C),
+            'n' => value.push(b'\n'),
+            'r' => value.push(b'\r'),
+            't' => value.push(b'\t'),
+            'v' => value.push(0x0B),
+            '\\' | '"' | '\'' => value.push(escape as u8),
+            '`' | '{' if interpolated => value.push(escape as u8),
+            // escaped line breaks (`\r\n` and `\n\r` are one line break)
+            '\n' | '\r' => {
+                value.push(b'\n');
+                let other = if escape == '\n' { b'\r' } else { b'\n' };
+                if bytes.get(idx) == Some(&other) {
+                    idx += 1;
+                }
+            }
+            // skips the whitespaces after it
+            'z' => {
+                while bytes.get(idx).is_some_and(u8::is_ascii_whitespace) {
+                    idx += 1;
+                }
+            }
+            'x' => {
+                let code = contents
+                    .get(idx..idx + 2)
+                    .filter(|v| v.bytes().all(|v| v.is_ascii_hexdigit()))
+                    .and_then(|v| u8::from_str_radix(v, 16).ok())
+                    .ok_or_else(|| invalid(idx + 2))?;
+                value.push(code);
+                idx += 2;
+            }
+            '0'..='9' => {
+                let end = (idx..(idx + 2).min(bytes.len()))
+                    .find(|v| !bytes[*v].is_ascii_digit())
+                    .unwrap_or((idx + 2).min(bytes.len()));
+                let code = contents[idx - 1..end]
+                    .parse::<u8>()
+                    .map_err(|_| invalid(end))?;
+                value.push(code);
+                idx = end;
+            }
+            'u' => {
+                if bytes.get(idx) != Some(&b'{') {
+                    return Err(invalid(idx));
+                }
+                let digits = bytes[idx + 1..]
+                    .iter()
+                    .take_while(|v| v.is_ascii_hexdigit())
+                    .count();
+                let end = idx + 1 + digits;
+                if digits == 0 || bytes.get(end) != Some(&b'}') {
+                    return Err(invalid(end + 1));
+                }
+                let code = u32::from_str_radix(&contents[idx + 1..end], 16)
+                    .ok()
+                    .filter(|v| *v <= 0x10FFFF)
+                    .ok_or_else(|| invalid(end + 1))?;
+                encode_utf8(code, &mut value);
+                idx = end + 1;
+            }
+            _ => return Err(invalid(idx)),
+        }
+    }
+
+    Ok(String::from_utf8_lossy(&value).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(contents: &str) -> Result<String, (usize, usize)> {
+        decode_escapes(contents, 0, false).map_err(|span| (span.start, span.end))
+    }
+
+    #[test]
+    fn simple_escapes() {
+        assert_eq!(decode("a\\nb\\t\\\\"), Ok("a\nb\t\\".to_string()));
+        assert_eq!(decode("\\\"\\'"), Ok("\"'".to_string()));
+        assert_eq!(decode("a\\\r\nb"), Ok("a\nb".to_string()));
+        assert_eq!(decode("a\\z  \n\t b"), Ok("ab".to_string()));
+    }
+
+    #[test]
+    fn numeric_escapes() {
+        assert_eq!(decode("\\x41\\65\\0672"), Ok("AAC2".to_string()));
+        assert_eq!(decode("\\u{48}\\u{1F600}"), Ok("H\u{1F600}".to_string()));
+        assert_eq!(decode("\\xC3\\xA9"), Ok("é".to_string()));
+    }
+
+    #[test]
+    fn invalid_escapes() {
+        assert_eq!(decode("ab\\q"), Err((2, 4)));
+        assert_eq!(decode("\\x4"), Err((0, 3)));
+        assert_eq!(decode("\\x4g"), Err((0, 4)));
+        assert_eq!(decode("a\\256"), Err((1, 5)));
+        assert_eq!(decode("\\u41"), Err((0, 2)));
+        assert_eq!(decode("\\u{}"), Err((0, 4)));
+        assert_eq!(decode("\\u{110000}"), Err((0, 10)));
+        assert_eq!(decode("\\`"), Err((0, 2)));
+        assert_eq!(decode_escapes("\\`\\{", 0, true), Ok("`{".to_string()));
+    }
+}
@@ -1,4 +1,6 @@
 mod atom;
+mod escape;
+mod number;
 
 use atom::*;
 use logos::Logos;
@@ -8,7 +10,7 @@ use salite_common::errors::tokenizer::*;
 
 type UnknownToken = (Result<TokenType, TokenizeErrorType>, Span);
 
-fn tokenize_atom(atom: Atom, slice: &str) -> Result<TokenType, TokenizeErrorType> {
+fn tokenize_atom(atom: Atom, slice: &str, start: usize) -> Result<TokenType, TokenizeErrorType> {
     match atom {
         Atom::Comment => {
             // check for any multi-comment patterns there
@@ -20,19 +22,38 @@ fn tokenize_atom(atom: Atom, slice: &str) -> Result<TokenType, TokenizeErrorType
                 slice[2 + multiline_len..(len - multiline_len - last_deduct)].into(),
             ))
         }
-        Atom::Number => Ok(TokenType::Number(slice.into())),
+        Atom::Number => {
+            let value = number::parse_number(slice).map_err(TokenizeErrorType::MalformedNumber)?;
+            Ok(TokenType::Number(slice.into(), value))
+        }
         Atom::QuoteString | Atom::ApostropheString => {
-            let len = slice.len();
-            Ok(TokenType::Str(slice[1..len - 1].into()))
+            let contents = &slice[1..slice.len() - 1];
+            let value = escape::decode_escapes(contents, start + 1, false)
+                .map_err(TokenizeErrorType::InvalidEscape)?;
+            Ok(TokenType::Str(contents.into(), value.into()))
+        }
+        Atom::InterpolatedStr(part) | Atom::RightBrace(Some(part)) => {
+            // delimiters are one character long
+            let contents = &slice[1..slice.len() - 1];
+            escape::decode_escapes(contents, start + 1, true)
+                .map_err(TokenizeErrorType::InvalidEscape)?;
+            Ok(TokenType::InterpolatedStr(part, contents.into()))
         }
         Atom::BracketString => {
             let equals = atom::get_equal_brackets(slice).unwrap();
             let len = slice.len();
-            Ok(TokenType::Str(slice[2 + equals..len - (2 + equals)].into()))
+            let contents = &slice[2 + equals..len - (2 + equals)];
+
+            // the first line break is skipped like in Lua
+            let value = contents
+                .strip_prefix("\r\n")
+                .or_else(|| contents.strip_prefix('\n'))
+                .unwrap_or(contents);
+            Ok(TokenType::Str(contents.into(), value.into()))
         }
         Atom::Whitespace => Ok(TokenType::Whitespace(slice.into())),
         Atom::Unknown => Err(match slice.chars().next().unwrap_or('\0') {
-            '\'' | '"' | '[' => TokenizeErrorType::IncompleteString,
+            '\'' | '"' | '[' | '`' | '}' => TokenizeErrorType::IncompleteString,
             '-' => TokenizeErrorType::IncompleteComment,
             '#' => TokenizeErrorType::IncorrectShebang,
             c => TokenizeErrorType::UnexpectedCharacter(c),
@@ -67,7 +88,7 @@ fn tokenize_code(input: &'_ str) -> Vec<UnknownToken> {
 
     while let Some(atom) = lexer.next() {
         tokens.push((
-            tokenize_atom(atom, lexer.slice()),
+            tokenize_atom(atom, lexer.slice(), lexer.span().start),
             Span::new(lexer.span().start, lexer.span().end),
         ));
     }
@@ -80,7 +101,14 @@ pub fn tokenize(input: &'_ str) -> Result<Vec<Token>, TokenizeError> {
     let mut current_span = Span::new(0, 0);
 
     for (raw_token, span) in tokenize_code(input) {
-        let ty = raw_token.map_err(|ty| TokenizeError { ty, span })?;
+        let ty = raw_token.map_err(|ty| TokenizeError {
+            // invalid escapes point to the escape itself
+            span: match ty {
+                TokenizeErrorType::InvalidEscape(span) => span,
+                _ => span,
+            },
+            ty,
+        })?;
         current_span = span;
         tokens.push(Token::new(ty, span));
     }
@@ -91,3 +119,25 @@ pub fn tokenize(input: &'_ str) -> Result<Vec<Token>, TokenizeError> {
     ));
     Ok(tokens)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_escape_span() {
+        let err = tokenize("local s = \"ab\\q\"").unwrap_err();
+        assert_eq!(err.span, Span::new(13, 15));
+        assert_eq!(err.ty, TokenizeErrorType::InvalidEscape(Span::new(13, 15)));
+
+        let err = tokenize("`a{1}b\\x4g`").unwrap_err();
+        assert_eq!(err.span, Span::new(6, 10));
+    }
+
+    #[test]
+    fn decoded_strings() {
+        let tokens = tokenize("'\\x41' [[\nB]]").unwrap();
+        assert_eq!(tokens[0].ty(), &TokenType::Str("\\x41".into(), "A".into()));
+        assert_eq!(tokens[2].ty(), &TokenType::Str("\nB".into(), "B".into()));
+    }
+}
@@ -0,0 +1,196 @@
+use salite_ast::NumberValue;
+
+/// Checks the digits of a number (`_` can only be between digits)
+/// and gives them back without the separators.
+fn digits(group: &str, radix: u32) -> Result<String, String> {
+    if group.is_empty() {
+        return Err("missing digits".to_string());
+    }
+    if group.starts_with('_') || group.ends_with('_') || group.contains("__") {
+        return Err("`_` must be between digits".to_string());
+    }
+    if let Some(c) = group.chars().find(|v| *v != '_' && !v.is_digit(radix)) {
+        return Err(format!("invalid digit `{}`", c));
+    }
+    Ok(group.replace('_', ""))
+}
+
+fn exponent(group: &str) -> Result<i32, String> {
+    let (negative, group) = match group.strip_prefix('-') {
+        Some(group) => (true, group),
+        None => (false, group.strip_prefix('+').unwrap_or(group)),
+    };
+    let value = digits(group, 10)?
+        .parse::<i32>()
+        .map_err(|_| "exponent is out of range".to_string())?;
+    Ok(if negative { -value } else { value })
+}
+
+fn finite(value: f64) -> Result<NumberValue, String> {
+    if value.is_finite() {
+        Ok(NumberValue::Float(value))
+    } else {
+        Err("number is out of range".to_string())
+    }
+}
+
+fn parse_hex(text: &str) -> Result<NumberValue, String> {
+    let (mantissa, exp) = match text.find(['p', 'P']) {
+        Some(idx) => (&text[..idx], Some(&text[idx + 1..])),
+        None => (text, None),
+    };
+    let (int, frac) = match mantissa.split_once('.') {
+        Some((int, frac)) => (int, Some(frac)),
+        None => (mantissa, None),
+    };
+
+    // integers wrap around like in Lua 5.3
+    if frac.is_none() && exp.is_none() {
+        let value = digits(int, 16)?.chars().fold(0u64, |value, digit| {
+            value
+                .wrapping_mul(16)
+                .wrapping_add(digit.to_digit(16).unwrap() as u64)
+        });
+        return Ok(NumberValue::Integer(value as i64));
+    }
+
+    // `0x.8` and `0x1.` are allowed
+    let int = if int.is_empty() {
+        int.to_string()
+    } else {
+        digits(int, 16)?
+    };
+    let frac = match frac {
+        Some(frac) if !frac.is_empty() => digits(frac, 16)?,
+        _ => String::new(),
+    };
+    if int.is_empty() && frac.is_empty() {
+        return Err("missing digits".to_string());
+    }
+
+    let mut value = 0.0;
+    for digit in int.chars().chain(frac.chars()) {
+        value = value * 16.0 + digit.to_digit(16).unwrap() as f64;
+    }
+    let exp = exp.map(exponent).transpose()?.unwrap_or(0);
+    let exp = exp
+        .checked_sub(4 * frac.len() as i32)
+        .ok_or_else(|| "exponent is out of range".to_string())?;
+    finite(value * 2f64.powi(exp))
+}
+
+/// Parses the text of a number literal, the reason is given
+/// back if it is malformed.
+///
+/// Decimal integers that are too large become floats and hexadecimal
+/// integers wrap around like in Lua, binary integers have to fit in
+/// 64 bits.
+pub(crate) fn parse_number(text: &str) -> Result<NumberValue, String> {
+    if let Some(text) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        return parse_hex(text);
+    }
+    if let Some(text) = text.strip_prefix("0b").or_else(|| text.strip_prefix("0B")) {
+        return u64::from_str_radix(&digits(text, 2)?, 2)
+            .map(|v| NumberValue::Integer(v as i64))
+            .map_err(|_| "integer is out of range".to_string());
+    }
+
+    let (mantissa, exp) = match text.find(['e', 'E']) {
+        Some(idx) => (&text[..idx], Some(&text[idx + 1..])),
+        None => (text, None),
+    };
+    let (int, frac) = match mantissa.split_once('.') {
+        Some((int, frac)) => (int, Some(frac)),
+        None => (mantissa, None),
+    };
+
+    // `.5` has no integer part
+    let mut number = if int.is_empty() {
+        String::new()
+    } else {
+        digits(int, 10)?
+    };
+    if frac.is_none() && exp.is_none() {
+        return Ok(match number.parse::<i64>() {
+            Ok(value) => NumberValue::Integer(value),
+            Err(..) => NumberValue::Float(number.parse::<f64>().unwrap()),
+        });
+    }
+    // `5.` has no fractional part
+    match frac {
+        Some("") if !number.is_empty() => {}
+        Some(frac) => {
+            number.push('.');
+            number.push_str(&digits(frac, 10)?);
+        }
+        None => {}
+    }
+    if let Some(exp) = exp {
+        number.push_str(&format!("e{}", exponent(exp)?));
+    }
+    finite(number.parse::<f64>().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers() {
+        assert_eq!(parse_number("42"), Ok(NumberValue::Integer(42)));
+        assert_eq!(parse_number("1_000_000"), Ok(NumberValue::Integer(1000000)));
+        assert_eq!(parse_number("0xff"), Ok(NumberValue::Integer(255)));
+        assert_eq!(parse_number("0XFF_FF"), Ok(NumberValue::Integer(65535)));
+        assert_eq!(parse_number("0b1010"), Ok(NumberValue::Integer(10)));
+        assert_eq!(
+            parse_number("9223372036854775808"),
+            Ok(NumberValue::Float(9223372036854775808.0))
+        );
+    }
+
+    #[test]
+    fn floats() {
+        assert_eq!(parse_number("3.25"), Ok(NumberValue::Float(3.25)));
+        assert_eq!(parse_number(".5"), Ok(NumberValue::Float(0.5)));
+        assert_eq!(parse_number("125e-2"), Ok(NumberValue::Float(1.25)));
+        assert_eq!(parse_number("1E3"), Ok(NumberValue::Float(1000.0)));
+        assert_eq!(parse_number("0x1.8p1"), Ok(NumberValue::Float(3.0)));
+        assert_eq!(parse_number("0x.8"), Ok(NumberValue::Float(0.5)));
+        assert_eq!(parse_number("0xAp-2"), Ok(NumberValue::Float(2.5)));
+        assert_eq!(parse_number("5."), Ok(NumberValue::Float(5.0)));
+        assert_eq!(parse_number("5.e1"), Ok(NumberValue::Float(50.0)));
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(parse_number("0x").is_err());
+        assert!(parse_number("0b102").is_err());
+        assert!(parse_number("1_").is_err());
+        assert!(parse_number("1._5").is_err());
+        assert!(parse_number("1__0").is_err());
+        assert!(parse_number("0x1__0").is_err());
+        assert!(parse_number("1e").is_err());
+        assert!(parse_number("1e400").is_err());
+        assert!(parse_number("0x1p99999999999").is_err());
+        assert!(parse_number(
+            "0b1_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn wrapping() {
+        assert_eq!(
+            parse_number("0x1_0000_0000_0000_0000"),
+            Ok(NumberValue::Integer(0))
+        );
+        assert_eq!(
+            parse_number("0x1FFFFFFFFFFFFFFFF"),
+            Ok(NumberValue::Integer(-1))
+        );
+        assert_eq!(
+            parse_number("0xFFFFFFFFFFFFFFFF"),
+            Ok(NumberValue::Integer(-1))
+        );
+    }
+}
@@ -12,6 +12,7 @@ pub enum TokenKind {
     Eof,
     Comment,
     Identifier,
+    InterpolatedStr,
     Number,
     Shebang,
     Str,
@@ -27,6 +28,7 @@ symbols! {
     pub enum SymbolType {
         And => "and",
         Break => "break",
+        Continue => "continue",
         Do => "do",
         Else => "else",
         ElseIf => "elseif",
@@ -54,6 +56,15 @@ symbols! {
 
         SkinnyArrow => "->",
 
+        CrossEqual => "+=",
+        DashEqual => "-=",
+        AsteriskEqual => "*=",
+        DoubleSlashEqual => "//=",
+        SlashEqual => "/=",
+        PercentEqual => "%=",
+        CaretEqual => "^=",
+        DoubleDotEqual => "..=",
+
         GreaterEqual => ">=",
         LessEqual => "<=",
         DoubleEqual => "==",
@@ -80,12 +91,14 @@ symbols! {
         Cross => "+",
         Dash => "-",
         Asterisk => "*",
+        DoubleSlash => "//",
         Slash => "/",
         Percent => "%",
         Caret => "^",
         Hash => "#",
 
         MetatableTag => "@metatable",
+        DoubleQuestion => "??",
         Question => "?",
 
         VerticalBar => "|",
@@ -93,15 +106,56 @@ symbols! {
     }
 }
 
+/// Which part of an interpolated string the token is,
+/// expressions are tokenized between them.
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterpolatedPart {
+    /// `` `text` ``, the string has no expressions.
+    Simple,
+    /// `` `text{ ``
+    Begin,
+    /// `}text{`
+    Mid,
+    /// `` }text` ``
+    End,
+}
+
+/// Value of a number literal. Integers are kept apart from
+/// floats, some Lua versions have an integer subtype.
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumberValue {
+    Integer(i64),
+    Float(f64),
+}
+
+impl std::fmt::Display for NumberValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NumberValue::Integer(value) => write!(f, "{}", value),
+            // `1.0` is the same number as `1`
+            NumberValue::Float(value) if value.fract() == 0.0 && value.abs() < 1e15 => {
+                write!(f, "{}", *value as i64)
+            }
+            NumberValue::Float(value) => write!(f, "{:?}", value),
+        }
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub enum TokenType {
     Eof,
     Comment(SmolStr),
     Identifier(SmolStr),
-    Number(SmolStr),
+    InterpolatedStr(InterpolatedPart, SmolStr),
+    /// Number literal as it is written in the source code and its value.
+    Number(SmolStr, NumberValue),
     Shebang(SmolStr),
-    Str(SmolStr),
+    /// Contents of the string as it is written in the source code
+    /// and its value after escape sequences are decoded.
+    Str(SmolStr, SmolStr),
     Symbol(SymbolType),
     Whitespace(SmolStr),
 }
@@ -119,9 +173,10 @@ impl TokenType {
             TokenType::Eof => TokenKind::Eof,
             TokenType::Comment(_) => TokenKind::Comment,
             TokenType::Identifier(_) => TokenKind::Identifier,
-            TokenType::Number(_) => TokenKind::Number,
+            TokenType::InterpolatedStr(..) => TokenKind::InterpolatedStr,
+            TokenType::Number(..) => TokenKind::Number,
             TokenType::Shebang(_) => TokenKind::Shebang,
-            TokenType::Str(_) => TokenKind::Str,
+            TokenType::Str(..) => TokenKind::Str,
             TokenType::Symbol(_) => TokenKind::Symbol,
             TokenType::Whitespace(_) => TokenKind::Whitespace,
         }
@@ -190,6 +245,10 @@ mod test {
             "..." => Some(SymbolType::TripleDot),
             "=" => Some(SymbolType::Equal),
             "==" => Some(SymbolType::DoubleEqual),
+            "/" => Some(SymbolType::Slash),
+            "//" => Some(SymbolType::DoubleSlash),
+            "?" => Some(SymbolType::Question),
+            "??" => Some(SymbolType::DoubleQuestion),
             "" => None,
         };
     }
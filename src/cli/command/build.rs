@@ -7,7 +7,11 @@ use salite::{
     common::*,
 };
 
-pub fn command_build(path: PathBuf, current_dir: bool) -> Result<(), anyhow::Error> {
+pub fn command_build(
+    path: PathBuf,
+    current_dir: bool,
+    bundle: Option<PathBuf>,
+) -> Result<(), anyhow::Error> {
     log::debug!("Initial directory: {}", path.to_string_lossy());
     log::info!("Initializing project");
 
@@ -69,6 +73,30 @@ pub fn command_build(path: PathBuf, current_dir: bool) -> Result<(), anyhow::Err
             .with_context(|| format!("Failed to check {}", file_path.to_string_lossy()))?;
     }
 
+    log::info!("Emitting files");
+    project
+        .emit(&env, &files)
+        .with_context(|| "Failed to emit files")?;
+    project
+        .emit_declarations(&env)
+        .with_context(|| "Failed to emit declaration files")?;
+
+    // bundle path from the command line overrides the config
+    let bundle = bundle.or_else(|| {
+        project
+            .config()
+            .get()
+            .bundle
+            .as_ref()
+            .map(|v| project.root().join(v))
+    });
+    if let Some(bundle) = bundle {
+        log::info!("Bundling files");
+        project
+            .bundle(&env, &files, &bundle)
+            .with_context(|| "Failed to bundle files")?;
+    }
+
     log::info!("Done");
 
     Ok(())
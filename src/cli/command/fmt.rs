@@ -0,0 +1,50 @@
+use anyhow::Context;
+
+use super::*;
+
+pub fn command_fmt(path: PathBuf, current_dir: bool, check: bool) -> Result<(), anyhow::Error> {
+    log::debug!("Initial directory: {}", path.to_string_lossy());
+
+    let mut project = salite::env::project::from_dir(&path).with_context(|| {
+        format!(
+            "Failed to load project from the {}",
+            if current_dir {
+                "current directory".to_string()
+            } else {
+                path.to_string_lossy().to_string()
+            }
+        )
+    })?;
+
+    project
+        .reload()
+        .with_context(|| "Failed to reload project")?;
+
+    let changed = project.format().map_err(|e| {
+        anyhow::anyhow!("There are parse errors in the following:\n{}", {
+            let mut list = Vec::new();
+            for err in e.iter() {
+                list.push(err.to_string());
+            }
+            list.join("\n")
+        })
+    })?;
+
+    if check {
+        if changed.is_empty() {
+            return Ok(());
+        }
+        for (file_path, ..) in changed.iter() {
+            println!("{}", file_path.to_string_lossy());
+        }
+        anyhow::bail!("{} file(s) are not formatted", changed.len());
+    }
+
+    for (file_path, contents) in changed.iter() {
+        log::info!("Formatting {}", file_path.to_string_lossy());
+        std::fs::write(file_path, contents)
+            .with_context(|| format!("Failed to write {}", file_path.to_string_lossy()))?;
+    }
+
+    Ok(())
+}
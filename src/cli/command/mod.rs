@@ -3,8 +3,12 @@ use clap::Parser;
 use std::path::PathBuf;
 
 mod build;
+mod fmt;
+mod traceback;
 
 pub use build::*;
+pub use fmt::*;
+pub use traceback::*;
 
 //const VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -15,6 +19,19 @@ pub enum Command {
         path: Option<PathBuf>,
         #[clap(long, short)]
         verbose: bool,
+        /// Bundles every emitted Lua file into this file
+        #[clap(long)]
+        bundle: Option<PathBuf>,
+    },
+    Fmt {
+        path: Option<PathBuf>,
+        /// Checks if every source file is formatted without writing
+        /// them, it fails if any of them is not formatted
+        #[clap(long)]
+        check: bool,
+    },
+    Traceback {
+        file: Option<PathBuf>,
     },
 }
 
@@ -22,6 +39,7 @@ impl Command {
     pub fn is_verbose_enabled(&self) -> bool {
         match self {
             Command::Build { verbose, .. } => *verbose,
+            Command::Fmt { .. } | Command::Traceback { .. } => false,
         }
     }
 }
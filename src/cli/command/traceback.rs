@@ -0,0 +1,20 @@
+use anyhow::Context;
+use std::io::Read;
+
+use super::*;
+
+pub fn command_traceback(file: Option<PathBuf>) -> Result<(), anyhow::Error> {
+    let traceback = match file {
+        Some(file) => std::fs::read_to_string(&file)
+            .with_context(|| format!("Failed to read {}", file.to_string_lossy()))?,
+        None => {
+            let mut contents = String::new();
+            std::io::stdin()
+                .read_to_string(&mut contents)
+                .with_context(|| "Failed to read from stdin")?;
+            contents
+        }
+    };
+    print!("{}", salite::env::traceback::rewrite_traceback(&traceback));
+    Ok(())
+}
@@ -11,14 +11,22 @@ fn run_process() -> anyhow::Result<()> {
     let cmd = command::Command::parse();
     preqs::set_max_level(cmd.is_verbose_enabled());
     match cmd {
-        command::Command::Build { path, .. } => {
+        command::Command::Build { path, bundle, .. } => {
             // maybe set the current directory as the default
             let is_current_dir = path.is_none();
             let path = path
                 .unwrap_or(std::env::current_dir().expect("Failed to get the current directory"));
 
-            command::command_build(path, is_current_dir)
+            command::command_build(path, is_current_dir, bundle)
         }
+        command::Command::Fmt { path, check } => {
+            let is_current_dir = path.is_none();
+            let path = path
+                .unwrap_or(std::env::current_dir().expect("Failed to get the current directory"));
+
+            command::command_fmt(path, is_current_dir, check)
+        }
+        command::Command::Traceback { file } => command::command_traceback(file),
     }
 }
 
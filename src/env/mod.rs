@@ -5,10 +5,16 @@ pub use file::*;
 /// Project bundle module
 pub mod project;
 
+/// Lua traceback rewriting from emitted source maps
+pub mod traceback;
+
 use rayon::ThreadPoolBuilder;
 use std::sync::{Arc, Mutex};
 
-use salitescript::common::errors::{SaliteError, TextSpanOutOfBounds};
+use salitescript::{
+    common::errors::{SaliteError, TextSpanOutOfBounds},
+    LazyParseError,
+};
 use std::{collections::HashMap, path::PathBuf};
 use thiserror::Error;
 
@@ -104,25 +110,29 @@ pub fn parse_project(
             let collection = Arc::clone(&collection);
 
             s.spawn(move |_| {
-                let ast = match salitescript::lazy_parse(file.declaration(), file.contents()) {
-                    Ok(ast) => ast,
-                    Err(err) => {
-                        let mut errors = errors.lock().unwrap();
-                        errors.push(ParseProjectError::ParseError {
+                let parse_errors: Vec<LazyParseError> =
+                    match salitescript::lazy_parse_recovered(file.declaration(), file.contents()) {
+                        Ok((ast, errors)) if errors.is_empty() => {
+                            collection.lock().unwrap().insert(file.path().clone(), ast);
+                            return;
+                        }
+                        Ok((_, errors)) => {
+                            errors.into_iter().map(LazyParseError::ParseError).collect()
+                        }
+                        Err(err) => vec![err],
+                    };
+
+                let mut errors = errors.lock().unwrap();
+                for err in parse_errors {
+                    match err.message(file.contents()) {
+                        Ok(message) => errors.push(ParseProjectError::ParseError {
                             path: file.path().clone(),
-                            message: match err.message(file.contents()) {
-                                Ok(output) => output,
-                                Err(err) => {
-                                    errors.push(ParseProjectError::TextSpanOutOfBounds(err));
-                                    return;
-                                }
-                            },
+                            message,
                             span: err.span(),
-                        });
-                        return;
+                        }),
+                        Err(err) => errors.push(ParseProjectError::TextSpanOutOfBounds(err)),
                     }
-                };
-                collection.lock().unwrap().insert(file.path().clone(), ast);
+                }
             });
         }
     });
@@ -9,7 +9,7 @@ use walkdir::WalkDir;
 
 use salitescript::{
     checker::EnvContext,
-    common::{Config, ConfigError},
+    common::{errors::SaliteError, Config, ConfigError},
 };
 
 /// Errors given when loading or doing something with Salite project.
@@ -59,7 +59,15 @@ impl Project {
         &self,
         parsed: &'a HashMap<FilePath, salitescript::ast::File>,
     ) -> EnvContext<'_, 'a> {
-        let env = EnvContext::new(self.config());
+        let mut env = EnvContext::new(self.config());
+
+        // modules are transformed once they are required
+        for (file_path, file) in parsed.iter() {
+            if let Some(path) = file_path.to_buf() {
+                env.add_source(path, file);
+            }
+        }
+
         let env_arc = Arc::new(Mutex::new(env));
         let env = Arc::clone(&env_arc);
 
@@ -96,6 +104,229 @@ impl Project {
         Arc::try_unwrap(env).unwrap().into_inner().unwrap()
     }
 
+    /// Emits Lua source files from every parsed non-declaration
+    /// source files into 'outDir' entry, keeping the same layout
+    /// as from the 'sourceDir' entry.
+    pub fn emit(
+        &self,
+        env: &EnvContext,
+        parsed: &HashMap<FilePath, salitescript::ast::File>,
+    ) -> Result<(), ProjectError> {
+        let names = self.module_names(env);
+        for (file_path, file) in parsed.iter() {
+            let path = match file_path.to_buf() {
+                Some(path) => path,
+                None => continue,
+            };
+            if *file.declaration() {
+                continue;
+            }
+
+            let source = self.get_source_code(&path).unwrap();
+            let output_path = self.output_path(&path);
+            log::debug!(
+                "Emitting {} to {}",
+                path.to_string_lossy(),
+                output_path.to_string_lossy()
+            );
+
+            let output_dir = output_path.parent().unwrap();
+            std::fs::create_dir_all(output_dir).map_err(ProjectError::IO)?;
+
+            let output_name = output_path
+                .file_name()
+                .unwrap()
+                .to_string_lossy()
+                .to_string();
+            let map_name = format!("{}.map", output_name);
+
+            let (mut code, map) = salitescript::codegen::emit_file_with_source_map(
+                file,
+                &source,
+                self.config.get().target,
+                output_name,
+                relative_path(output_dir, &path),
+                // `require("./util")` is `require("util")` from the output directory
+                |name| self.required_module_name(env, &names, &path, name),
+            );
+            code.push_str(&format!("--# sourceMappingURL={}\n", map_name));
+
+            std::fs::write(&output_path, code).map_err(ProjectError::IO)?;
+            std::fs::write(output_dir.join(map_name), map.to_json()).map_err(ProjectError::IO)?;
+        }
+        Ok(())
+    }
+
+    /// Emits declaration files (`.d.slt`) next to the emitted Lua
+    /// files from every checked non-declaration module.
+    pub fn emit_declarations(&self, env: &EnvContext) -> Result<(), ProjectError> {
+        for (path, module) in env.modules().iter() {
+            if module.ctx.declaration() {
+                continue;
+            }
+
+            let output_path = self.declaration_path(path);
+            log::debug!(
+                "Emitting declaration of {} to {}",
+                path.to_string_lossy(),
+                output_path.to_string_lossy()
+            );
+
+            let contents = salitescript::checker::emit_declaration(&module.ctx, &module.file);
+            std::fs::create_dir_all(output_path.parent().unwrap()).map_err(ProjectError::IO)?;
+            std::fs::write(&output_path, contents).map_err(ProjectError::IO)?;
+        }
+        Ok(())
+    }
+
+    /// Links every checked non-declaration module into one Lua file.
+    /// Modules are registered by their path from 'sourceDir' entry
+    /// (like `require("nested.mod")`) and the `init` module is the
+    /// entry point of the bundle.
+    pub fn bundle(
+        &self,
+        env: &EnvContext,
+        parsed: &HashMap<FilePath, salitescript::ast::File>,
+        output_path: &Path,
+    ) -> Result<(), ProjectError> {
+        let names = self.module_names(env);
+
+        let mut bundle = salitescript::codegen::Bundle::new();
+        for (path, module) in env.modules().iter() {
+            if module.ctx.declaration() {
+                continue;
+            }
+            let file = match parsed.get(&FilePath::Filesystem(path.clone())) {
+                Some(file) => file,
+                None => continue,
+            };
+            let source = self.get_source_code(path).unwrap();
+
+            // `require("./util")` is registered as `util` in the bundle
+            let code = salitescript::codegen::emit_bundle_module(
+                file,
+                &source,
+                self.config.get().target,
+                |name| self.required_module_name(env, &names, path, name),
+            );
+            bundle.add_module(self.module_name(path), code);
+        }
+
+        log::debug!("Bundling files to {}", output_path.to_string_lossy());
+        if let Some(output_dir) = output_path.parent() {
+            std::fs::create_dir_all(output_dir).map_err(ProjectError::IO)?;
+        }
+        std::fs::write(output_path, bundle.finish("init")).map_err(ProjectError::IO)
+    }
+
+    /// Gets the module name of every checked non-declaration module.
+    fn module_names(&self, env: &EnvContext) -> Vec<(PathBuf, String)> {
+        // `require` gives the path of the module as it is found,
+        // which may not be the same as the path of the source file
+        env.modules()
+            .iter()
+            .filter(|(_, module)| !module.ctx.declaration())
+            .map(|(path, _)| {
+                let real_path = std::fs::canonicalize(path).unwrap_or(path.clone());
+                (real_path, self.module_name(path))
+            })
+            .collect()
+    }
+
+    /// Gets the module name of what `require(name)` requires from the
+    /// source file at `from`, modules that aren't checked give nothing.
+    fn required_module_name(
+        &self,
+        env: &EnvContext,
+        names: &[(PathBuf, String)],
+        from: &Path,
+        name: &str,
+    ) -> Option<String> {
+        let required = env.find_module_path(Some(from), name)?;
+        names
+            .iter()
+            .find(|(path, _)| *path == required)
+            .map(|(_, name)| name.to_string())
+    }
+
+    /// Formats every source file with the options from 'format'
+    /// entry and gives the files that are changed by the formatter
+    /// with their formatted source code.
+    pub fn format(&self) -> Result<Vec<(PathBuf, String)>, Vec<ParseProjectError>> {
+        let options = &self.config.get().format;
+        let mut errors = Vec::new();
+        let mut changed = Vec::new();
+
+        for file in self.files() {
+            let path = match file.path().to_buf() {
+                Some(path) => path,
+                None => continue,
+            };
+            let (cst, ast) = match salitescript::lazy_parse_cst(file.declaration(), file.contents())
+            {
+                Ok(result) => result,
+                Err(err) => {
+                    errors.push(match err.message(file.contents()) {
+                        Ok(message) => ParseProjectError::ParseError {
+                            path: file.path().clone(),
+                            message,
+                            span: err.span(),
+                        },
+                        Err(err) => ParseProjectError::TextSpanOutOfBounds(err),
+                    });
+                    continue;
+                }
+            };
+
+            let output = salitescript::formatter::format_file(&cst, &ast, options);
+            if &output != file.contents() {
+                changed.push((path, output));
+            }
+        }
+
+        if errors.is_empty() {
+            changed.sort_by(|a, b| a.0.cmp(&b.0));
+            Ok(changed)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Gets the module name of a source file for `require`, which is
+    /// the path from 'sourceDir' entry joined with dots.
+    pub fn module_name(&self, path: &Path) -> String {
+        let src_dir = self.root.join(&self.config.get().source_dir);
+        let relative = path.strip_prefix(&src_dir).unwrap_or(path);
+        let mut components = relative
+            .with_extension("")
+            .components()
+            .map(|v| v.as_os_str().to_string_lossy().to_string())
+            .collect::<Vec<String>>();
+
+        // `nested/init.slt` is `nested` unless it is the main one
+        if components.len() > 1 && components.last().map(|v| v == "init").unwrap_or(false) {
+            components.pop();
+        }
+        components.join(".")
+    }
+
+    /// Gets the path of the declaration file of a source file
+    /// in 'outDir' entry.
+    pub fn declaration_path(&self, path: &Path) -> PathBuf {
+        self.output_path(path).with_extension("d.slt")
+    }
+
+    /// Gets the path of the emitted Lua file of a source file
+    /// in 'outDir' entry.
+    pub fn output_path(&self, path: &Path) -> PathBuf {
+        let src_dir = self.root.join(&self.config.get().source_dir);
+        let relative = path.strip_prefix(&src_dir).unwrap_or(path);
+        self.root
+            .join(&self.config.get().output_dir)
+            .join(relative)
+            .with_extension("lua")
+    }
+
     fn gather_source_file_paths(&self) -> Result<Vec<(FilePath, bool)>, ProjectError> {
         // output of source files
         let mut results = Vec::new();
@@ -124,7 +355,7 @@ impl Project {
                             .unwrap()
                             .to_str()
                             .unwrap()
-                            .ends_with("d.slt"),
+                            .ends_with(".d.slt"),
                     )
                 } else {
                     (false, false)
@@ -210,6 +441,27 @@ impl Project {
     }
 }
 
+/// Makes a relative path with forward slashes from a directory
+/// to a path, both of them must be in the same root.
+fn relative_path(from: &Path, to: &Path) -> String {
+    let from = from.components().collect::<Vec<_>>();
+    let to = to.components().collect::<Vec<_>>();
+    let common = from
+        .iter()
+        .zip(to.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut segments = Vec::new();
+    for _ in common..from.len() {
+        segments.push("..".to_string());
+    }
+    for component in to[common..].iter() {
+        segments.push(component.as_os_str().to_string_lossy().to_string());
+    }
+    segments.join("/")
+}
+
 /// Loads Salite project from the config file path
 pub fn from_file<T: AsRef<path::Path>>(file: T) -> Result<Project, ProjectError> {
     // load the config file
@@ -0,0 +1,182 @@
+use std::{
+    collections::HashMap,
+    path::{Component, Path, PathBuf},
+};
+
+use salitescript::{
+    ast::Position,
+    codegen::{Mapping, SourceMap},
+};
+
+/// Source map of an emitted Lua file loaded from the filesystem
+struct LoadedSourceMap {
+    source: PathBuf,
+    mappings: Vec<Mapping>,
+}
+
+impl LoadedSourceMap {
+    fn load(lua_path: &str) -> Option<LoadedSourceMap> {
+        let map_path = PathBuf::from(format!("{}.map", lua_path));
+        let contents = std::fs::read_to_string(&map_path).ok()?;
+        let map = SourceMap::from_json(&contents)
+            .map_err(|e| log::debug!("Failed to load {}: {}", map_path.to_string_lossy(), e))
+            .ok()?;
+
+        let source = map
+            .sources
+            .first()
+            .map(|v| Path::new(&map.source_root).join(v))?;
+        let source = normalize_path(&map_path.parent().unwrap_or(Path::new("")).join(source));
+        let mappings = map
+            .decode_mappings()
+            .map_err(|e| log::debug!("Failed to load {}: {}", map_path.to_string_lossy(), e))
+            .ok()?;
+
+        Some(LoadedSourceMap { source, mappings })
+    }
+
+    /// Looks for the original position of a generated line, it will
+    /// look for the nearest line before if that line has no mappings.
+    fn lookup(&self, line: usize) -> Option<Position> {
+        let mut found: Option<&Mapping> = None;
+        for mapping in self.mappings.iter() {
+            if mapping.generated_line > line {
+                break;
+            }
+            found = match found {
+                Some(v) if v.generated_line == mapping.generated_line => Some(v),
+                _ => Some(mapping),
+            };
+        }
+        found.map(|v| Position::new(v.source_line + 1, v.source_column + 1))
+    }
+}
+
+/// Resolves `.` and `..` of the path without touching the filesystem.
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut output = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if matches!(output.components().next_back(), Some(Component::Normal(..))) {
+                    output.pop();
+                } else {
+                    output.push("..");
+                }
+            }
+            component => output.push(component),
+        }
+    }
+    output
+}
+
+fn rewrite_line(line: &str, maps: &mut HashMap<String, Option<LoadedSourceMap>>) -> String {
+    let mut output = String::new();
+    let mut rest = line;
+
+    while let Some(index) = rest.find(".lua:") {
+        let path_end = index + ".lua".len();
+        let path_start = rest[..index]
+            .rfind(|c: char| c.is_whitespace() || matches!(c, '"' | '\'' | '<' | '('))
+            .map(|v| v + 1)
+            .unwrap_or(0);
+
+        let digits = rest[path_end + 1..]
+            .chars()
+            .take_while(|c| c.is_ascii_digit())
+            .count();
+
+        let line_end = path_end + 1 + digits;
+        let position = match rest[path_end + 1..line_end].parse::<usize>() {
+            Ok(line) => {
+                let lua_path = &rest[path_start..path_end];
+                maps.entry(lua_path.to_string())
+                    .or_insert_with(|| LoadedSourceMap::load(lua_path))
+                    .as_ref()
+                    .and_then(|map| {
+                        map.lookup(line.saturating_sub(1))
+                            .map(|position| (map.source.to_string_lossy().to_string(), position))
+                    })
+            }
+            Err(..) => None,
+        };
+
+        match position {
+            Some((source, position)) => {
+                output.push_str(&rest[..path_start]);
+                output.push_str(&format!("{}:{}", source, position));
+            }
+            None => output.push_str(&rest[..line_end]),
+        }
+        rest = &rest[line_end..];
+    }
+
+    output.push_str(rest);
+    output
+}
+
+/// Rewrites every emitted Lua file location in a Lua traceback into
+/// Salite source file locations (`file:line:column`) using the source
+/// maps written next to the emitted Lua files.
+///
+/// Locations without a source map are kept as is.
+pub fn rewrite_traceback(traceback: &str) -> String {
+    let mut maps = HashMap::new();
+    traceback
+        .split('\n')
+        .map(|line| rewrite_line(line, &mut maps))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Writes `out/util.lua.map` pointing to `src/util.slt` into a
+    /// new directory, generated line 1 is from line 3, column 5.
+    fn project_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("salite_traceback_{}", name));
+        std::fs::create_dir_all(dir.join("out")).unwrap();
+        let map = SourceMap::new(
+            "util.lua".to_string(),
+            "../src/util.slt".to_string(),
+            &[Mapping {
+                generated_line: 0,
+                generated_column: 0,
+                source_line: 2,
+                source_column: 4,
+            }],
+        );
+        std::fs::write(dir.join("out/util.lua.map"), map.to_json()).unwrap();
+        dir
+    }
+
+    #[test]
+    fn rewrites_locations() {
+        let dir = project_dir("locations");
+        let lua = dir.join("out/util.lua").to_string_lossy().to_string();
+        let source = dir.join("src/util.slt").to_string_lossy().to_string();
+
+        let traceback = format!(
+            "lua: {lua}:1: oops\n\t{lua}:1: in function <{lua}:1>\n\t({lua}:1)",
+            lua = lua
+        );
+        assert_eq!(
+            rewrite_traceback(&traceback),
+            format!(
+                "lua: {src}:3:5: oops\n\t{src}:3:5: in function <{src}:3:5>\n\t({src}:3:5)",
+                src = source
+            )
+        );
+    }
+
+    #[test]
+    fn keeps_unmapped_locations() {
+        let dir = project_dir("unmapped");
+        let other = dir.join("out/other.lua").to_string_lossy().to_string();
+        let traceback = format!("\t[C]: in ?\n\t{}:4: in main chunk", other);
+        assert_eq!(rewrite_traceback(&traceback), traceback);
+    }
+}
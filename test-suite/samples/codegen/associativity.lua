@@ -0,0 +1,8 @@
+local sub = 1 - 2 - 3
+local div = 8 / 4 / 2
+local pow = 2 ^ 3 ^ 2
+local concat = "a" .. "b" .. "c"
+local grouped = 1 - (2 - 3)
+local pow_grouped = (2 ^ 3) ^ 2
+local concat_grouped = ("a" .. "b") .. "c"
+local mixed = 1 - 2 * 3 - 4 / 2 ^ 2
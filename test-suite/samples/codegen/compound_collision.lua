@@ -0,0 +1,11 @@
+local __base = 5
+local __key = "x"
+local t = {{x = 1}}
+do
+	local __base1 = t[1]
+	__base1.x = __base1.x + __base
+end
+do
+	local __base1, __key1 = t[1], __key .. ""
+	__base1[__key1] = __base1[__key1] - __base
+end
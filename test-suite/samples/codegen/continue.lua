@@ -0,0 +1,27 @@
+for i = 1, 10 do
+	if i % 2 == 0 then
+		goto continue
+	end
+	while i > 5 do
+		if i == 7 then
+			break
+		end
+		i = i - 1
+		goto continue
+		::continue::
+	end
+	print(i)
+	::continue::
+end
+local __until = false
+repeat
+	do
+		local done = true
+		if not done then
+			__until = false
+			goto continue
+		end
+		return
+	end
+	::continue::
+until __until
@@ -0,0 +1,8 @@
+local function greet(name, times)
+	if name == nil then name = "world" end
+	if times == nil then times = 1 end
+	return name
+end
+local noop = function(value)
+	if value == nil then value = {} end
+end
@@ -0,0 +1,21 @@
+local count = 0
+local list = {1}
+count = count + 1
+count = count - (2 - 1)
+count = count ^ 2 ^ 3
+count = math.floor(count / 2)
+list[1] = list[1] * (count + 1)
+list.size = 0
+list.size = list.size % 3
+do
+	local __base = get().items
+	__base[key] = __base[key] .. "a" .. "b"
+end
+do
+	local __key = next()
+	list[__key] = list[__key] / 2
+end
+do
+	local __base = get()
+	__base[1] = math.floor(__base[1] / 2)
+end
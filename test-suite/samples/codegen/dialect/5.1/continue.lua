@@ -0,0 +1,31 @@
+for i = 1, 10 do
+	repeat
+		if i % 2 == 0 then
+			break
+		end
+		while i > 5 do
+			local __continue = false
+			repeat
+				if i == 7 then
+					break
+				end
+				i = i - 1
+				__continue = true
+				break
+			until true
+			if not __continue then break end
+		end
+		print(i)
+	until true
+end
+local __until = false
+repeat
+	repeat
+		local done = true
+		if not done then
+			__until = false
+			break
+		end
+		return
+	until true
+until __until
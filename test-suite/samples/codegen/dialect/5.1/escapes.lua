@@ -0,0 +1,7 @@
+local hex = "\065\066C"
+local unicode = '\072i \195\169 \240\159\152\128'
+local skipped = "ab"
+local kept = "\\x41 \\u{48} \\z \065"
+local digits = "\0491"
+local interpolated = string.format("%s\065!", tostring(hex))
+local long = [[\x41\z]]
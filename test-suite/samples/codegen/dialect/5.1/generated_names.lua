@@ -0,0 +1,51 @@
+local __continue = 0
+local __until = false
+for i = 1, 10 do
+	local __continue1 = false
+	repeat
+		for j = 1, 10 do
+			local __continue2 = false
+			repeat
+				if j == i then
+					__continue2 = true
+					break
+				elseif j > 5 then
+					break
+				end
+				__continue = __continue + 1
+				__continue2 = true
+			until true
+			if not __continue2 then break end
+		end
+		if i == 5 then
+			break
+		elseif __until then
+			__continue1 = true
+			break
+		end
+		__continue1 = true
+	until true
+	if not __continue1 then break end
+end
+local __until1 = false
+repeat
+	repeat
+		local __until2 = false
+		repeat
+			repeat
+				local done = __until
+				if done then
+					__until2 = done
+					break
+				end
+				__until2 = done
+			until true
+		until __until2
+		if __continue > 3 then
+			__until1 = __until
+			break
+		end
+		__until = true
+		__until1 = __until
+	until true
+until __until1
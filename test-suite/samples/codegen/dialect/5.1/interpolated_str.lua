@@ -0,0 +1,9 @@
+local name = "world"
+local count = 3
+print(string.format("Hello, %s!", tostring(name)))
+print(string.format("%s of \"%s\" is 100%%", tostring(count), tostring(name)))
+print("escaped `backticks` and {braces}\n")
+print(string.format("outer %s", tostring(string.format("inner %s", tostring(count)))))
+local function join(...)
+	return string.format("%s", tostring((...)))
+end
@@ -0,0 +1,5 @@
+local million = 1000000
+local mask = 240
+local half = 0.5
+local precise = 3.0
+local plain = 0xFF + .5e2
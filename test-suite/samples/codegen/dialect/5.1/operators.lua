@@ -0,0 +1,8 @@
+local a = 7
+local b = math.floor(a / 2)
+local c = (function(l, r) if l == nil then return (r()) end return l end)(b, function() return a end)
+local d = (function(l, r) if l == nil then return (r()) end return l end)(a, function() return (math.floor(b / c)) end)
+print(math.floor(a / 2) + b, (function(l, r) if l == nil then return (r()) end return l end)(c, function() return 0 end), math.floor(((function(l, r) if l == nil then return (r()) end return l end)(a, function() return b end)) / 3))
+local function first(...)
+	return (function(l, r, ...) if l == nil then return (r(...)) end return l end)(a, function(...) return select(2, ...) end, ...), (function(l, r) if l == nil then return (r()) end return l end)(..., function() return first() end)
+end
@@ -0,0 +1,14 @@
+local count = 0
+local __until = false
+repeat
+	repeat
+		count = count + 1
+		local done = count >= 10
+		if count % 2 == 0 then
+			__until = done
+			break
+		end
+		print(count)
+		__until = done
+	until true
+until __until
@@ -0,0 +1,20 @@
+for i = 1, 10 do
+	if i % 2 == 0 then
+		continue
+	end
+	while i > 5 do
+		if i == 7 then
+			break
+		end
+		i = i - 1
+		continue
+	end
+	print(i)
+end
+repeat
+	local done = true
+	if not done then
+		continue
+	end
+	return
+until false
@@ -0,0 +1,11 @@
+local flag = true
+local a = if flag then 1 else 2
+local b = if flag then "a" elseif a then "b" else "c"
+print(if flag then a else b, b)
+local c = 1 + (if flag then a else 2)
+local function first(...)
+	return if flag then ... else nil
+end
+local function call()
+	return if flag then first(1, 2) else first()
+end
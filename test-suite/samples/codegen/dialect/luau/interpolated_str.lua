@@ -0,0 +1,9 @@
+local name = "world"
+local count = 3
+print(`Hello, {name}!`)
+print(`{count} of "{name}" is 100%`)
+print(`escaped \`backticks\` and \{braces}\n`)
+print(`outer {`inner {count}`}`)
+local function join(...)
+	return `{...}`
+end
@@ -0,0 +1,5 @@
+local million = 1_000_000
+local mask = 0b1111_0000
+local half = 0.5
+local precise = 3.0
+local plain = 0xFF + .5e2
@@ -0,0 +1,8 @@
+local a = 7
+local b = a // 2
+local c = (if b == nil then a else b)
+local d = (if a == nil then (b // c) else a)
+print(a // 2 + b, (if c == nil then 0 else c), ((if a == nil then b else a)) // 3)
+local function first(...)
+	return (if a == nil then select(2, ...) else a), (function(l, r) if l == nil then return (r()) end return l end)(..., function() return first() end)
+end
@@ -0,0 +1,9 @@
+local count = 0
+repeat
+	count = count + 1
+	local done = count >= 10
+	if count % 2 == 0 then
+		continue
+	end
+	print(count)
+until done
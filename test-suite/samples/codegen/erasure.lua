@@ -0,0 +1,9 @@
+local count = 0
+local name = "salite"
+local function add(a, b)
+	return a + b
+end
+local callback = function(value)
+	count = add(count, value)
+end
+callback(1)
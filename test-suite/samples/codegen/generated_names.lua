@@ -0,0 +1,43 @@
+local __continue = 0
+local __until = false
+for i = 1, 10 do
+	for j = 1, 10 do
+		if j == i then
+			goto continue
+		elseif j > 5 then
+			break
+		end
+		__continue = __continue + 1
+		::continue::
+	end
+	if i == 5 then
+		break
+	elseif __until then
+		goto continue
+	end
+	::continue::
+end
+local __until1 = false
+repeat
+	do
+		local __until2 = false
+		repeat
+			do
+				local done = __until
+				if done then
+					__until2 = done
+					goto continue
+				end
+				__until2 = done
+			end
+			::continue::
+		until __until2
+		if __continue > 3 then
+			__until1 = __until
+			goto continue
+		end
+		__until = true
+		__until1 = __until
+	end
+	::continue::
+until __until1
@@ -0,0 +1,7 @@
+local function id(value)
+	return value
+end
+local pair = function(key, value)
+	return key, value
+end
+print(id(1), pair("key", true))
@@ -0,0 +1,11 @@
+local flag = true
+local a = (function() if flag then return 1 else return 2 end end)()
+local b = (function() if flag then return "a" elseif a then return "b" else return "c" end end)()
+print((function() if flag then return a else return b end end)(), b)
+local c = 1 + ((function() if flag then return a else return 2 end end)())
+local function first(...)
+	return (function(...) if flag then return (...) else return nil end end)(...)
+end
+local function call()
+	return (function() if flag then return (first(1, 2)) else return (first()) end end)()
+end
@@ -0,0 +1,9 @@
+local name = "world"
+local count = 3
+print(string.format("Hello, %s!", name))
+print(string.format("%s of \"%s\" is 100%%", count, name))
+print("escaped `backticks` and {braces}\n")
+print(string.format("outer %s", string.format("inner %s", count)))
+local function join(...)
+	return string.format("%s", (...))
+end
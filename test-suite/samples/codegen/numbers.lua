@@ -0,0 +1,6 @@
+local million = 1000000
+local mask = 240
+local half = 0x.8
+local precise = 0x1.8p1
+local plain = 0xFF + .5e2
+local whole = 5. + 5.e1
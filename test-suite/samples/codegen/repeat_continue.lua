@@ -0,0 +1,15 @@
+local count = 0
+local __until = false
+repeat
+	do
+		count = count + 1
+		local done = count >= 10
+		if count % 2 == 0 then
+			__until = done
+			goto continue
+		end
+		print(count)
+		__until = done
+	end
+	::continue::
+until __until
@@ -0,0 +1,29 @@
+local list = {1, 2, 3, name = 'list', ["key"] = [[raw]]}
+for i = 1, #list do
+	print(list[i])
+end
+for key, value in pairs(list) do
+	if key == 1 then
+		break
+	elseif key == 2 then
+		print(value)
+	else end
+end
+while false do end
+repeat
+	local x = 1
+until true
+do
+	local object = {}
+	function object.method(self, ...)
+		return ...
+	end
+	function object:call()
+		self.method(self)
+	end
+	object:call()
+end
+local a, b = -((1 + 2) * 3), not (true and false)
+local c = ("abc"):upper()
+print(c)
+print(a, b)
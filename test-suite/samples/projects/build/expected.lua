@@ -0,0 +1,38 @@
+local total = 0
+for i = 1, 10 do
+	if i % 2 == 0 then
+		total = total + i
+	elseif i == 5 then
+		total = total - 1
+	else
+		goto continue
+	end
+	::continue::
+end
+local names = {"a", "b"}
+for index, name in ipairs(names) do
+	print(index, name)
+end
+x = 1
+local count = nil
+count = 2
+while total > 0 do
+	total = total - 1
+end
+repeat
+	local done = true
+until done
+do
+	local Stack = {}
+	function Stack.new()
+		return setmetatable({items = {}}, {__index = Stack})
+	end
+	function Stack:size()
+		return #self.items
+	end
+	print(Stack.new():size())
+end
+print(-total, not x, count)
+local util = require("sub.util")
+print(util.double(total))
+--# sourceMappingURL=init.lua.map
@@ -0,0 +1,34 @@
+local __modules = {}
+local __loaded = {}
+local __require = require
+local function require(name)
+	if __loaded[name] == nil then
+		local loader = __modules[name]
+		if loader == nil then
+			return __require(name)
+		end
+		local value = loader(name)
+		__loaded[name] = value == nil and true or value
+	end
+	return __loaded[name]
+end
+
+__modules["init"] = function(...)
+local util = require("util")
+local shapes = require("shapes")
+return util.describe(shapes.center)
+end
+
+__modules["shapes"] = function(...)
+local util = require("util")
+return {center = {x = 0, y = 0}, label = util.describe(nil)}
+end
+
+__modules["util"] = function(...)
+local function describe(point)
+	return "point"
+end
+return {describe = describe}
+end
+
+return require("init")
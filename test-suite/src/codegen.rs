@@ -0,0 +1,89 @@
+use super::*;
+use crate::typeck::run_scripts_folder;
+use salite::common::{errors::SaliteError, LuaTarget};
+use std::path::Path;
+
+pub struct CodegenCase;
+
+impl CodegenCase {
+    pub fn sample_path(&self, env: &TestEnv) -> PathBuf {
+        env.sample_path().join("codegen")
+    }
+
+    pub fn deal_error<T>(&self, result: Result<T, String>, env: &mut TestEnv) {
+        if let Err(err) = &result {
+            env.fail(err);
+        }
+    }
+
+    /// Samples inside of a folder named after a Lua target (like
+    /// `dialect/5.1`) are emitted for that target.
+    pub fn target_of(path: &Path) -> LuaTarget {
+        path.parent()
+            .and_then(|v| v.file_name())
+            .and_then(|v| v.to_str())
+            .and_then(|v| v.parse().ok())
+            .unwrap_or_default()
+    }
+
+    pub fn emit_script(&self, path: &Path) -> Result<String, String> {
+        let source = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        let file = match salite::lazy_parse(false, &source) {
+            Ok(file) => file,
+            Err(err) => {
+                return Err(match err.message(&source).map_err(|e| e.to_string()) {
+                    Ok(err) => err,
+                    Err(err) => err,
+                })
+            }
+        };
+        Ok(salite::codegen::emit_file(
+            &file,
+            &source,
+            Self::target_of(path),
+        ))
+    }
+
+    /// Compares the emitted code with the expected Lua file
+    /// right next to the sample file.
+    pub fn compare_script(&self, path: &Path) -> Result<(), String> {
+        let output = self.emit_script(path)?;
+        let expected_path = path.with_extension("lua");
+        let expected = std::fs::read_to_string(&expected_path).map_err(|e| {
+            format!(
+                "couldn't read expected file {}: {}",
+                expected_path.to_string_lossy(),
+                e
+            )
+        })?;
+        if output != expected {
+            return Err(format!(
+                "emitted code does not match with the expected one:\n{}",
+                output
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl TestCase for CodegenCase {
+    fn name(&self) -> &'static str {
+        "codegen"
+    }
+
+    fn on_run(&self, env: &mut TestEnv) {
+        env.describe("lua emission");
+        self.deal_error(
+            run_scripts_folder(self.sample_path(env), &mut |file, buf| {
+                let result = self.compare_script(file);
+                env.it(
+                    format!("should match on file {}", buf.to_string_lossy()).as_str(),
+                    || result.clone(),
+                );
+                drop(result);
+            }),
+            env,
+        );
+        env.describe_end();
+    }
+}
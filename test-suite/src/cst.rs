@@ -0,0 +1,69 @@
+use super::*;
+use crate::typeck::{is_declaration, run_scripts_folder};
+use std::path::Path;
+
+pub struct CstCase;
+
+impl CstCase {
+    pub fn deal_error<T>(&self, result: Result<T, String>, env: &mut TestEnv) {
+        if let Err(err) = &result {
+            env.fail(err);
+        }
+    }
+
+    /// Makes sure that the printed tree is the same as the source
+    /// code and the AST from the tree is the same as the AST parsed
+    /// without trivia tokens.
+    pub fn roundtrip_script(&self, path: &Path) -> Result<(), String> {
+        let source = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        let tokens = match salite::tokenizer::tokenize(&source) {
+            Ok(tokens) => tokens,
+            // nothing to print back
+            Err(..) => return Ok(()),
+        };
+
+        let cst = salite::ast::cst::Cst::new(&source, tokens);
+        let output = cst.print();
+        if output != source {
+            return Err(format!(
+                "printed tree does not match with the source code:\n{}",
+                output
+            ));
+        }
+
+        let declaration = is_declaration(path);
+        let expected = salite::lazy_parse(declaration, &source).map_err(|e| format!("{:?}", e));
+        let file = salite::parser::parse_cst(declaration, &cst).map_err(|e| format!("{:?}", e));
+        match (file, expected) {
+            (Ok(file), Ok(expected)) if file != expected => {
+                Err("AST from the tree does not match with the parsed AST".to_string())
+            }
+            (Ok(..), Err(..)) | (Err(..), Ok(..)) => {
+                Err("tree and the source code are not parsed the same way".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl TestCase for CstCase {
+    fn name(&self) -> &'static str {
+        "cst"
+    }
+
+    fn on_run(&self, env: &mut TestEnv) {
+        env.describe("lossless roundtrip");
+        self.deal_error(
+            run_scripts_folder(env.sample_path(), &mut |file, buf| {
+                let result = self.roundtrip_script(file);
+                env.it(
+                    format!("should roundtrip on file {}", buf.to_string_lossy()).as_str(),
+                    || result.clone(),
+                );
+                drop(result);
+            }),
+            env,
+        );
+        env.describe_end();
+    }
+}
@@ -0,0 +1,155 @@
+use super::*;
+use crate::typeck::run_scripts_folder;
+use salite::{
+    checker::{Analyzer, EnvContext, Resolver},
+    common::{errors::SaliteError, memory::SafePtr},
+};
+use std::path::Path;
+
+pub struct DeclarationCase;
+
+impl DeclarationCase {
+    pub fn sample_path(&self, env: &TestEnv) -> PathBuf {
+        env.sample_path().join("declaration")
+    }
+
+    pub fn deal_error<T>(&self, result: Result<T, String>, env: &mut TestEnv) {
+        if let Err(err) = &result {
+            env.fail(err);
+        }
+    }
+
+    /// Checks the source code and generates the declaration file of it.
+    pub fn emit_source(
+        &self,
+        path: &Path,
+        source: &str,
+        declaration: bool,
+    ) -> Result<String, String> {
+        let file = match salite::lazy_parse(declaration, source) {
+            Ok(file) => file,
+            Err(err) => {
+                return Err(match err.message(source).map_err(|e| e.to_string()) {
+                    Ok(err) => err,
+                    Err(err) => err,
+                })
+            }
+        };
+        let cfg = salite::common::Config::default();
+
+        let mut env_ctx = EnvContext::new(&cfg);
+        let env_ptr = SafePtr::from_ptr(&mut env_ctx as *mut EnvContext);
+
+        env_ctx.add_module(path.to_path_buf(), &file);
+
+        let result = env_ctx.get_module_result_mut(&path.to_path_buf()).unwrap();
+
+        Resolver::from_result(result, env_ptr).map_err(|e| e.to_string())?;
+        Analyzer::analyze(result.ctx.clone(), &result.file).map_err(|e| e.to_string())?;
+
+        Ok(salite::checker::emit_declaration(&result.ctx, &result.file))
+    }
+
+    /// Compares the generated declaration file with the expected
+    /// one right next to the sample file, then makes sure that
+    /// the generated one is a valid declaration file.
+    pub fn compare_script(&self, path: &Path) -> Result<(), String> {
+        let source = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        let output = self.emit_source(path, &source, false)?;
+
+        let expected_path = path.with_extension("d.slt");
+        let expected = std::fs::read_to_string(&expected_path).map_err(|e| {
+            format!(
+                "couldn't read expected file {}: {}",
+                expected_path.to_string_lossy(),
+                e
+            )
+        })?;
+        if output != expected {
+            return Err(format!(
+                "generated declaration does not match with the expected one:\n{}",
+                output
+            ));
+        }
+
+        self.emit_source(&expected_path, &output, true)
+            .map_err(|e| format!("generated declaration is invalid: {}", e))?;
+
+        let consumer_path = path.with_extension("use.slt");
+        if consumer_path.exists() {
+            self.check_consumer(path, &output, &consumer_path)
+                .map_err(|e| format!("generated declaration cannot be required: {}", e))?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks the script right next to the sample file (`<name>.use.slt`)
+    /// against the generated declaration file. It is written together with
+    /// the script into a temporary folder so `require("./<name>")` gets the
+    /// declaration file instead of the sample file.
+    pub fn check_consumer(
+        &self,
+        path: &Path,
+        declaration: &str,
+        consumer_path: &Path,
+    ) -> Result<(), String> {
+        let name = path
+            .file_stem()
+            .map(|v| v.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let dir = std::env::temp_dir().join("salite-declaration").join(&name);
+        std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+
+        let declaration_path = dir.join(format!("{}.d.slt", name));
+        let main_path = dir.join("main.slt");
+        let source = std::fs::read_to_string(consumer_path).map_err(|e| e.to_string())?;
+        std::fs::write(&declaration_path, declaration).map_err(|e| e.to_string())?;
+        std::fs::write(&main_path, &source).map_err(|e| e.to_string())?;
+
+        let declaration_file =
+            salite::lazy_parse(true, declaration).map_err(|e| format!("{:?}", e))?;
+        let file = salite::lazy_parse(false, &source).map_err(|e| format!("{:?}", e))?;
+        let cfg = salite::common::Config::default();
+
+        let mut env_ctx = EnvContext::new(&cfg);
+        let env_ptr = SafePtr::from_ptr(&mut env_ctx as *mut EnvContext);
+
+        env_ctx.add_source(declaration_path, &declaration_file);
+        env_ctx.add_module(main_path.clone(), &file);
+
+        let result = env_ctx.get_module_result_mut(&main_path).unwrap();
+
+        Resolver::from_result(result, env_ptr).map_err(|e| e.to_string())?;
+        Analyzer::analyze(result.ctx.clone(), &result.file).map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+}
+
+impl TestCase for DeclarationCase {
+    fn name(&self) -> &'static str {
+        "declaration"
+    }
+
+    fn on_run(&self, env: &mut TestEnv) {
+        env.describe("declaration files");
+        self.deal_error(
+            run_scripts_folder(self.sample_path(env), &mut |file, buf| {
+                // expected declaration files and scripts that require them
+                let file_name = file.to_string_lossy();
+                if file_name.ends_with(".d.slt") || file_name.ends_with(".use.slt") {
+                    return;
+                }
+                let result = self.compare_script(file);
+                env.it(
+                    format!("should match on file {}", buf.to_string_lossy()).as_str(),
+                    || result.clone(),
+                );
+                drop(result);
+            }),
+            env,
+        );
+        env.describe_end();
+    }
+}
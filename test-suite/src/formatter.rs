@@ -0,0 +1,129 @@
+use super::*;
+use crate::typeck::{is_declaration, run_scripts_folder};
+use salite::common::{errors::SaliteError, FormatOptions, LuaTarget};
+use std::path::Path;
+
+pub struct FormatterCase;
+
+impl FormatterCase {
+    pub fn sample_path(&self, env: &TestEnv) -> PathBuf {
+        env.sample_path().join("formatter")
+    }
+
+    pub fn deal_error<T>(&self, result: Result<T, String>, env: &mut TestEnv) {
+        if let Err(err) = &result {
+            env.fail(err);
+        }
+    }
+
+    pub fn format_source(&self, source: &str, declaration: bool) -> Result<String, String> {
+        let (cst, file) = match salite::lazy_parse_cst(declaration, source) {
+            Ok(result) => result,
+            Err(err) => {
+                return Err(match err.message(source).map_err(|e| e.to_string()) {
+                    Ok(err) => err,
+                    Err(err) => err,
+                })
+            }
+        };
+        Ok(salite::formatter::format_file(
+            &cst,
+            &file,
+            &FormatOptions::default(),
+        ))
+    }
+
+    /// Compares the formatted code with the expected file
+    /// right next to the sample file.
+    pub fn compare_script(&self, path: &Path) -> Result<(), String> {
+        let source = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        let output = self.format_source(&source, is_declaration(path))?;
+
+        let expected_path = path.with_extension("expected.slt");
+        let expected = std::fs::read_to_string(&expected_path).map_err(|e| {
+            format!(
+                "couldn't read expected file {}: {}",
+                expected_path.to_string_lossy(),
+                e
+            )
+        })?;
+        if output != expected {
+            return Err(format!(
+                "formatted code does not match with the expected one:\n{}",
+                output
+            ));
+        }
+        Ok(())
+    }
+
+    /// Makes sure that formatting the formatted code changes nothing
+    /// and the formatted code emits the same Lua code.
+    pub fn stable_script(&self, path: &Path) -> Result<(), String> {
+        let source = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        let declaration = is_declaration(path);
+        let output = match self.format_source(&source, declaration) {
+            Ok(output) => output,
+            // nothing to format
+            Err(..) => return Ok(()),
+        };
+
+        let again = self
+            .format_source(&output, declaration)
+            .map_err(|e| format!("formatted code cannot be parsed: {}", e))?;
+        if again != output {
+            return Err(format!("formatting is not idempotent:\n{}", again));
+        }
+
+        let emit = |source: &str| {
+            salite::lazy_parse(declaration, source)
+                .map(|file| salite::codegen::emit_file(&file, source, LuaTarget::default()))
+                .map_err(|e| format!("{:?}", e))
+        };
+        if emit(&source)? != emit(&output)? {
+            return Err(format!(
+                "formatted code does not emit the same Lua code:\n{}",
+                output
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl TestCase for FormatterCase {
+    fn name(&self) -> &'static str {
+        "formatter"
+    }
+
+    fn on_run(&self, env: &mut TestEnv) {
+        env.describe("source formatting");
+        self.deal_error(
+            run_scripts_folder(self.sample_path(env), &mut |file, buf| {
+                if file.to_string_lossy().ends_with(".expected.slt") {
+                    return;
+                }
+                let result = self.compare_script(file);
+                env.it(
+                    format!("should match on file {}", buf.to_string_lossy()).as_str(),
+                    || result.clone(),
+                );
+                drop(result);
+            }),
+            env,
+        );
+        env.describe_end();
+
+        env.describe("stability");
+        self.deal_error(
+            run_scripts_folder(env.sample_path(), &mut |file, buf| {
+                let result = self.stable_script(file);
+                env.it(
+                    format!("should be stable on file {}", buf.to_string_lossy()).as_str(),
+                    || result.clone(),
+                );
+                drop(result);
+            }),
+            env,
+        );
+        env.describe_end();
+    }
+}
@@ -3,6 +3,10 @@
 use ansi_term::Style;
 use std::{env::args, path::PathBuf};
 
+mod codegen;
+mod cst;
+mod declaration;
+mod formatter;
 mod parser;
 mod project;
 mod typeck;
@@ -213,8 +217,15 @@ fn main() {
         }
     }
 
-    let tasks: Vec<Box<dyn TestCase>> =
-        tasks![project::ProjectCase, parser::ParserCase, typeck::TypeckCase];
+    let tasks: Vec<Box<dyn TestCase>> = tasks![
+        project::ProjectCase,
+        parser::ParserCase,
+        typeck::TypeckCase,
+        codegen::CodegenCase,
+        declaration::DeclarationCase,
+        cst::CstCase,
+        formatter::FormatterCase
+    ];
 
     let mut env = TestEnv {
         before_each_calls: Vec::new(),
@@ -1,5 +1,5 @@
 use super::*;
-use crate::typeck::run_scripts_folder;
+use crate::typeck::{is_declaration, run_scripts_folder};
 use salite::common::errors::SaliteError;
 use std::path::Path;
 
@@ -26,6 +26,36 @@ impl ParserCase {
         let tokens = salite::ast::filter_non_trivia_tokens(tokens);
         Ok((tokens, input))
     }
+
+    /// Compares every error from the recovered parser with
+    /// the expected errors file right next to the sample file.
+    pub fn recover_script(&self, path: &Path) -> Result<(), String> {
+        let (tokens, input) = self.tokenize(path)?;
+        let (_, errors) = salite::parser::parse_file_recovered(is_declaration(path), &tokens);
+
+        let mut output = String::new();
+        for err in errors.iter() {
+            let message = err.message(&input).map_err(|e| e.to_string())?;
+            let position = salite::ast::Position::from_offset(err.span.start, &input);
+            output.push_str(&format!("{}: {}\n", position, message));
+        }
+
+        let expected_path = path.with_extension("errors");
+        let expected = std::fs::read_to_string(&expected_path).map_err(|e| {
+            format!(
+                "couldn't read expected file {}: {}",
+                expected_path.to_string_lossy(),
+                e
+            )
+        })?;
+        if output != expected {
+            return Err(format!(
+                "parse errors do not match with the expected ones:\n{}",
+                output
+            ));
+        }
+        Ok(())
+    }
 }
 
 impl TestCase for ParserCase {
@@ -67,7 +97,8 @@ impl TestCase for ParserCase {
                     run_scripts_folder(join!($location), &mut |file, buf| {
                         let result: Result<$ty, String> = (|| {
                             let (tokens, input) = self.tokenize(file)?;
-                            let state = salite::parser::ParseState::new(&tokens);
+                            let state = salite::parser::ParseState::new(&tokens)
+                                .with_declaration(is_declaration(file));
                             let (_, result) = result_mattering!(
                                 pass = salite::parser::$name.parse(&state),
                                 input
@@ -110,7 +141,8 @@ impl TestCase for ParserCase {
                     run_scripts_folder(join!($location), &mut |file, buf| {
                         let result: Result<(), String> = (|| {
                             let (tokens, ..) = self.tokenize(file)?;
-                            let state = salite::parser::ParseState::new(&tokens);
+                            let state = salite::parser::ParseState::new(&tokens)
+                                .with_declaration(is_declaration(file));
                             #[cfg(feature = "no-out")]
                             result_mattering!(fail = salite::parser::$name.parse(&state));
 
@@ -156,5 +188,18 @@ impl TestCase for ParserCase {
         parser_boilers!(pass = ParseBlock, "scripts", ast::Block);
         parser_boilers!(pass = ParseTypeInfo, "types", ast::TypeInfo);
         parser_boilers!(fail = ParseBlock, "confusables");
+
+        env.describe("recovery");
+        self.deal_error(
+            run_scripts_folder(join!("recovery"), &mut |file, buf| {
+                let result = self.recover_script(file);
+                env.it(
+                    format!("should recover on file {}", buf.to_string_lossy()).as_str(),
+                    || result.clone(),
+                );
+            }),
+            env,
+        );
+        env.describe_end();
     }
 }
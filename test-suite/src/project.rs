@@ -1,6 +1,10 @@
 use std::path::Path;
 
 use super::*;
+use salite::{
+    checker::{Analyzer, EnvContext, Resolver},
+    common::memory::SafePtr,
+};
 
 pub struct ProjectCase;
 
@@ -21,6 +25,114 @@ impl ProjectCase {
         salite::env::project::from_dir(project_dir).map_err(|e| e.to_string())?;
         Ok(())
     }
+
+    /// Checks every source file of the project like `salite build` does.
+    pub fn check_project<T: AsRef<Path>>(&self, project_dir: T) -> TestResult {
+        let mut project = salite::env::project::from_dir(project_dir).map_err(|e| e.to_string())?;
+        project.reload().map_err(|e| e.to_string())?;
+
+        let files = salite::env::parse_project(&project).map_err(|e| {
+            e.iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<String>>()
+                .join("\n")
+        })?;
+        let mut env = project.check(&files);
+        let env_ptr = SafePtr::from_ptr(&mut env as *mut EnvContext);
+
+        for (_, module) in env.modules_mut().iter_mut() {
+            Resolver::from_result(module, env_ptr.clone()).map_err(|e| e.to_string())?;
+        }
+        for (_, module) in env.modules().iter() {
+            Analyzer::analyze(module.ctx.clone(), &module.file).map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    }
+
+    /// Checks every source file of the project, then bundles them and
+    /// compares the bundle with the expected one (`bundle.lua`).
+    pub fn compare_bundle<T: AsRef<Path>>(&self, project_dir: T) -> TestResult {
+        let project_dir = project_dir.as_ref();
+        let mut project = salite::env::project::from_dir(project_dir).map_err(|e| e.to_string())?;
+        project.reload().map_err(|e| e.to_string())?;
+
+        let files = salite::env::parse_project(&project).map_err(|e| {
+            e.iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<String>>()
+                .join("\n")
+        })?;
+        let mut env = project.check(&files);
+        let env_ptr = SafePtr::from_ptr(&mut env as *mut EnvContext);
+
+        for (_, module) in env.modules_mut().iter_mut() {
+            Resolver::from_result(module, env_ptr.clone()).map_err(|e| e.to_string())?;
+        }
+
+        let output_path = std::env::temp_dir()
+            .join("salite-bundle")
+            .join(project_dir.file_name().unwrap_or_default())
+            .join("bundle.lua");
+        project
+            .bundle(&env, &files, &output_path)
+            .map_err(|e| e.to_string())?;
+
+        let output = std::fs::read_to_string(&output_path).map_err(|e| e.to_string())?;
+        let expected =
+            std::fs::read_to_string(project_dir.join("bundle.lua")).map_err(|e| e.to_string())?;
+        if output != expected {
+            return Err(format!(
+                "bundle does not match with the expected one:\n{}",
+                output
+            ));
+        }
+        Ok(())
+    }
+
+    /// Checks and emits every source file of the project like `salite build`
+    /// does, then compares the emitted main file with `expected.lua`.
+    pub fn build_project<T: AsRef<Path>>(&self, project_dir: T) -> TestResult {
+        let project_dir = project_dir.as_ref();
+        let mut project = salite::env::project::from_dir(project_dir).map_err(|e| e.to_string())?;
+        project.reload().map_err(|e| e.to_string())?;
+
+        let files = salite::env::parse_project(&project).map_err(|e| {
+            e.iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<String>>()
+                .join("\n")
+        })?;
+        let mut env = project.check(&files);
+        let env_ptr = SafePtr::from_ptr(&mut env as *mut EnvContext);
+
+        for (_, module) in env.modules_mut().iter_mut() {
+            Resolver::from_result(module, env_ptr.clone()).map_err(|e| e.to_string())?;
+        }
+        for (_, module) in env.modules().iter() {
+            Analyzer::analyze(module.ctx.clone(), &module.file).map_err(|e| e.to_string())?;
+        }
+        project.emit(&env, &files).map_err(|e| e.to_string())?;
+
+        let main_path = project.output_path(&project.root().join("src").join("init.slt"));
+        let output = std::fs::read_to_string(main_path).map_err(|e| e.to_string())?;
+        let expected =
+            std::fs::read_to_string(project_dir.join("expected.lua")).map_err(|e| e.to_string())?;
+        if output != expected {
+            return Err(format!(
+                "emitted file does not match with the expected one:\n{}",
+                output
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn expect_check_fail<T: AsRef<Path>>(&self, project_dir: T, message: &str) -> TestResult {
+        match self.check_project(project_dir) {
+            Ok(..) => Err("Expected check failed!".to_string()),
+            Err(err) if err.contains(message) => Ok(()),
+            Err(err) => Err(format!("Expected error with {:?}, got {:?}", message, err)),
+        }
+    }
 }
 
 impl TestCase for ProjectCase {
@@ -48,5 +160,63 @@ impl TestCase for ProjectCase {
         it_should!(fail "should fail if it has no config file", "no_config");
         it_should!(fail "should fail if its config failed to parse", "cfg_parse_fail");
         it_should!(pass "should pass if it pass its requirements", "sample");
+        env.describe_end();
+
+        env.describe("project check");
+        env.it("should type modules from require", || {
+            self.check_project(sample_path.join("require"))
+        });
+        env.it("should fail if the required module is missing", || {
+            self.expect_check_fail(sample_path.join("require_missing"), "Cannot find module")
+        });
+        env.it("should fail if modules require each other", || {
+            self.expect_check_fail(sample_path.join("require_cycle"), "Cyclic require")
+        });
+        env.it("should check values against exported types", || {
+            self.expect_check_fail(
+                sample_path.join("require_type_mismatch"),
+                "is not extendable from number",
+            )
+        });
+        env.it(
+            "should fail if types are exported outside of the module scope",
+            || {
+                self.expect_check_fail(
+                    sample_path.join("require_nested_export"),
+                    "cannot be exported outside of the module scope",
+                )
+            },
+        );
+        env.it("should declare globals from declaration files", || {
+            self.check_project(sample_path.join("declarations"))
+        });
+        env.it("should check values against declared globals", || {
+            self.expect_check_fail(
+                sample_path.join("declarations_mismatch"),
+                "is not extendable from { host: string, port: number }",
+            )
+        });
+        env.it(
+            "should fail if declaration files have executable statements",
+            || {
+                self.expect_check_fail(
+                    sample_path.join("declarations_executable"),
+                    "declaration files cannot have executable statements",
+                )
+            },
+        );
+        env.describe_end();
+
+        env.describe("project build");
+        env.it("should check and emit statements of every kind", || {
+            self.build_project(sample_path.join("build"))
+        });
+        env.describe_end();
+
+        env.describe("project bundle");
+        env.it("should name required modules by their bundle names", || {
+            self.compare_bundle(sample_path.join("bundle"))
+        });
+        env.describe_end();
     }
 }
@@ -1,7 +1,8 @@
 use super::*;
+use crate::codegen::CodegenCase;
 use salite::{
     checker::{Analyzer, EnvContext, Resolver},
-    common::{errors::SaliteError, memory::SafePtr},
+    common::{errors::SaliteError, memory::SafePtr, ConfigInfo},
 };
 use std::path::Path;
 
@@ -45,6 +46,11 @@ pub fn run_scripts_folder<P: AsRef<Path>>(
     Ok(())
 }
 
+/// Declaration files (`.d.slt`) are parsed differently.
+pub fn is_declaration(path: &Path) -> bool {
+    path.to_string_lossy().ends_with(".d.slt")
+}
+
 impl TypeckCase {
     pub fn sample_path(&self, env: &TestEnv) -> PathBuf {
         env.sample_path().join("typeckr")
@@ -80,7 +86,10 @@ impl TypeckCase {
 
     pub fn evaluate_script(&self, path: &Path) -> Result<(), String> {
         let file = self.parse_script(path)?;
-        let cfg = salite::common::Config::default();
+        let cfg = salite::common::Config::no_file(ConfigInfo {
+            target: CodegenCase::target_of(path),
+            ..Default::default()
+        });
 
         let mut env_ctx = EnvContext::new(&cfg);
         let env_ptr = SafePtr::from_ptr(&mut env_ctx as *mut EnvContext);